@@ -0,0 +1,220 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    hint::black_box,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use aoc_common::{ParseError, Solver};
+use serde::Serialize;
+
+/// The system allocator, counting what passes through it so phases can report
+/// their allocations next to their timings.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::PartOne => write!(f, "part 1"),
+            Phase::PartTwo => write!(f, "part 2"),
+        }
+    }
+}
+
+/// One run of one phase.
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    time: Duration,
+    allocations: u64,
+    allocated_bytes: u64,
+}
+
+fn take_sample<T>(phase: impl FnOnce() -> T) -> (T, Sample) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = black_box(phase());
+    let time = start.elapsed();
+    let sample = Sample {
+        time,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+    };
+    (result, sample)
+}
+
+/// Timings are in nanoseconds, allocations are those of the median run.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PhaseReport {
+    pub phase: Phase,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl PhaseReport {
+    fn from_samples(phase: Phase, mut samples: Vec<Sample>) -> Self {
+        samples.sort_by_key(|sample| sample.time);
+        let nanos = |sample: &Sample| sample.time.as_nanos() as u64;
+        let median = &samples[samples.len() / 2];
+        Self {
+            phase,
+            min_ns: nanos(&samples[0]),
+            median_ns: nanos(median),
+            max_ns: nanos(&samples[samples.len() - 1]),
+            allocations: median.allocations,
+            allocated_bytes: median.allocated_bytes,
+        }
+    }
+}
+
+impl fmt::Display for PhaseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let time = |nanos| Duration::from_nanos(nanos);
+        write!(
+            f,
+            "{:<6}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}  {} allocations, {} bytes",
+            self.phase.to_string(),
+            time(self.min_ns),
+            time(self.median_ns),
+            time(self.max_ns),
+            self.allocations,
+            self.allocated_bytes
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub runs: usize,
+    pub phases: Vec<PhaseReport>,
+}
+
+impl fmt::Display for DayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}, {} runs", self.day, self.runs)?;
+        for phase in &self.phases {
+            write!(f, "\n  {phase}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs every phase of `S` on `input` `runs` times.
+///
+/// The parts run on the input parsed in the same run, so they are measured
+/// without the parsing, just like the runner calls them.
+pub fn measure<S: Solver>(input: &str, runs: usize) -> Result<DayReport, ParseError> {
+    assert!(runs > 0, "There has to be at least one run");
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_one = Vec::with_capacity(runs);
+    let mut part_two = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let (parsed, sample) = take_sample(|| S::parse(input));
+        let parsed = parsed?;
+        parse.push(sample);
+
+        let (answer, sample) = take_sample(|| S::part_one(&parsed));
+        answer?;
+        part_one.push(sample);
+
+        let (answer, sample) = take_sample(|| S::part_two(&parsed));
+        answer?;
+        part_two.push(sample);
+    }
+
+    Ok(DayReport {
+        day: S::DAY,
+        runs,
+        phases: vec![
+            PhaseReport::from_samples(Phase::Parse, parse),
+            PhaseReport::from_samples(Phase::PartOne, part_one),
+            PhaseReport::from_samples(Phase::PartTwo, part_two),
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(time: u64) -> Sample {
+        Sample {
+            time: Duration::from_millis(time),
+            allocations: time,
+            allocated_bytes: time * 8,
+        }
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let samples = vec![millis(5), millis(1), millis(9), millis(2), millis(3)];
+        assert_eq!(
+            PhaseReport::from_samples(Phase::Parse, samples),
+            PhaseReport {
+                phase: Phase::Parse,
+                min_ns: 1_000_000,
+                median_ns: 3_000_000,
+                max_ns: 9_000_000,
+                allocations: 3,
+                allocated_bytes: 24,
+            }
+        );
+    }
+
+    #[test]
+    fn measures_every_phase() {
+        let report = measure::<day_09::Day09>("0 3 6 9 12 15\n1 3 6 10 15 21", 3).unwrap();
+        assert_eq!(report.day, 9);
+        assert_eq!(report.runs, 3);
+
+        let phases = report.phases.iter().map(|phase| phase.phase);
+        assert!(phases.eq([Phase::Parse, Phase::PartOne, Phase::PartTwo]));
+        assert!(report.phases[0].allocations > 0);
+        assert!(report
+            .phases
+            .iter()
+            .all(|phase| phase.min_ns <= phase.median_ns && phase.median_ns <= phase.max_ns));
+
+        let error = measure::<day_09::Day09>("0 3 x", 3).unwrap_err();
+        assert_eq!(error.column, 5);
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_common::{Answer, ParseError, Solver};
+
+use crate::bench::{measure, DayReport};
+
+/// Calls `$function::<DayNN>($args)` for the solver of `$day`.
+macro_rules! with_solver {
+    ($day:expr, $function:ident($($arg:expr),*)) => {
+        match $day {
+            1 => $function::<day_01::Day01>($($arg),*),
+            2 => $function::<day_02::Day02>($($arg),*),
+            3 => $function::<day_03::Day03>($($arg),*),
+            4 => $function::<day_04::Day04>($($arg),*),
+            5 => $function::<day_05::Day05>($($arg),*),
+            6 => $function::<day_06::Day06>($($arg),*),
+            7 => $function::<day_07::Day07>($($arg),*),
+            8 => $function::<day_08::Day08>($($arg),*),
+            9 => $function::<day_09::Day09>($($arg),*),
+            10 => $function::<day_10::Day10>($($arg),*),
+            11 => $function::<day_11::Day11>($($arg),*),
+            12 => $function::<day_12::Day12>($($arg),*),
+            13 => $function::<day_13::Day13>($($arg),*),
+            14 => $function::<day_14::Day14>($($arg),*),
+            15 => $function::<day_15::Day15>($($arg),*),
+            16 => $function::<day_16::Day16>($($arg),*),
+            17 => $function::<day_17::Day17>($($arg),*),
+            18 => $function::<day_18::Day18>($($arg),*),
+            19 => $function::<day_19::Day19>($($arg),*),
+            20 => $function::<day_20::Day20>($($arg),*),
+            21 => $function::<day_21::Day21>($($arg),*),
+            22 => $function::<day_22::Day22>($($arg),*),
+            23 => $function::<day_23::Day23>($($arg),*),
+            24 => $function::<day_24::Day24>($($arg),*),
+            25 => $function::<day_25::Day25>($($arg),*),
+            _ => panic!("There are only 25 days"),
+        }
+    };
+}
+
+pub fn solve(day: u8, part: u8, input: &str) -> Result<Option<Answer>, ParseError> {
+    with_solver!(day, solve_part(part, input))
+}
+
+fn solve_part<S: Solver>(part: u8, input: &str) -> Result<Option<Answer>, ParseError> {
+    S::solve(part, input)
+}
+
+/// Only parses the input of `day`, for checking parsers on their own.
+#[cfg(test)]
+pub fn parse(day: u8, input: &str) -> Result<(), ParseError> {
+    with_solver!(day, parse_only(input))
+}
+
+#[cfg(test)]
+fn parse_only<S: Solver>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(|_| ())
+}
+
+pub fn bench(day: u8, input: &str, runs: usize) -> Result<DayReport, ParseError> {
+    with_solver!(day, measure(input, runs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_selected_day_and_part() {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+        assert_eq!(solve(1, 1, input), Ok(Some(Answer::Number(142))));
+        assert_eq!(
+            solve(9, 2, "10 13 16 21 30 45"),
+            Ok(Some(Answer::Number(5)))
+        );
+        assert_eq!(solve(25, 2, ""), Ok(None));
+    }
+
+    #[test]
+    fn passes_parse_errors_on() {
+        let error = solve(9, 1, "10 13 16\n21 x 45").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
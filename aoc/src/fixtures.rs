@@ -0,0 +1,245 @@
+//! Runs the puzzle examples under `fixtures/` through every day's solver.
+//!
+//! Each `fixtures/day-NN/` holds `example-K.txt` inputs and an `answers.toml`
+//! with the expected answers per example, as in
+//!
+//! ```toml
+//! [example-1]
+//! part1 = "142"
+//! ```
+//!
+//! A part without an expected answer is not run, since some examples only
+//! make sense for one part.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use proptest::prelude::*;
+use serde::Deserialize;
+
+use crate::days;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+#[derive(Debug)]
+struct Fixture {
+    day: u8,
+    path: PathBuf,
+    expected: Expected,
+}
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures")
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|error| panic!("{} should be readable: {error}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    entries
+}
+
+/// Pairs every example of every day with its expected answers, complaining
+/// about examples without answers and answers without examples.
+fn discover(dir: &Path) -> Result<Vec<Fixture>, Vec<String>> {
+    let mut fixtures = Vec::new();
+    let mut problems = Vec::new();
+
+    for day_dir in sorted_entries(dir) {
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day-"))
+            .and_then(|day| day.parse::<u8>().ok())
+            .filter(|day| (1..=25).contains(day))
+        else {
+            problems.push(format!("{}: not a day directory", day_dir.display()));
+            continue;
+        };
+
+        let answers_path = day_dir.join("answers.toml");
+        let mut answers: BTreeMap<String, Expected> = match fs::read_to_string(&answers_path)
+            .map_err(|error| error.to_string())
+            .and_then(|answers| toml::from_str(&answers).map_err(|error| error.to_string()))
+        {
+            Ok(answers) => answers,
+            Err(error) => {
+                problems.push(format!("{}: {error}", answers_path.display()));
+                continue;
+            }
+        };
+
+        for path in sorted_entries(&day_dir) {
+            if path == answers_path {
+                continue;
+            }
+            let name = path.file_stem().and_then(|name| name.to_str());
+            match name.and_then(|name| answers.remove(name)) {
+                Some(expected) => fixtures.push(Fixture {
+                    day,
+                    path,
+                    expected,
+                }),
+                None => problems.push(format!("{}: no expected answers", path.display())),
+            }
+        }
+        for name in answers.keys() {
+            problems.push(format!("{}: no input for {name}", answers_path.display()));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(fixtures)
+    } else {
+        Err(problems)
+    }
+}
+
+fn check(fixture: &Fixture) -> Vec<String> {
+    let input = fs::read_to_string(&fixture.path).unwrap();
+    let parts = [(1, &fixture.expected.part1), (2, &fixture.expected.part2)];
+
+    parts
+        .into_iter()
+        .filter_map(|(part, expected)| {
+            let expected = expected.as_deref()?;
+            let found = match days::solve(fixture.day, part, &input) {
+                Ok(Some(answer)) => answer.to_string(),
+                Ok(None) => "no answer".to_string(),
+                Err(error) => format!("invalid input, {error}"),
+            };
+            (found != expected).then(|| {
+                format!(
+                    "{} part {part}: expected {expected}, found {found}",
+                    fixture.path.display()
+                )
+            })
+        })
+        .collect()
+}
+
+fn examples_of(day: u8) -> Vec<String> {
+    let day_dir = fixtures_dir().join(format!("day-{day:02}"));
+    sorted_entries(&day_dir)
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .map(|path| fs::read_to_string(path).unwrap())
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Insert(usize, char),
+    Remove(usize),
+    Truncate(usize),
+}
+
+impl Edit {
+    fn apply(&self, input: &mut String) {
+        let at = |index: usize| {
+            let boundaries = input.char_indices().count() + 1;
+            input
+                .char_indices()
+                .map(|(at, _)| at)
+                .chain([input.len()])
+                .nth(index % boundaries)
+                .unwrap()
+        };
+        match *self {
+            Edit::Insert(index, char) => input.insert(at(index), char),
+            Edit::Remove(index) => {
+                let at = at(index);
+                if at < input.len() {
+                    input.remove(at);
+                }
+            }
+            Edit::Truncate(index) => input.truncate(at(index)),
+        }
+    }
+}
+
+/// Characters that show up in puzzle inputs, so edits are likely to keep
+/// parsers going past the first token.
+fn puzzle_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        4 => proptest::sample::select(" \n\n.#0123456789,:;-=>@|~{}()[]%&?/\\SOLJF7".chars().collect::<Vec<_>>()),
+        1 => any::<char>(),
+    ]
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        4 => (any::<usize>(), puzzle_char()).prop_map(|(index, char)| Edit::Insert(index, char)),
+        4 => any::<usize>().prop_map(Edit::Remove),
+        1 => any::<usize>().prop_map(Edit::Truncate),
+    ]
+}
+
+/// An example of some day, with a few edits that mostly make it invalid.
+fn mutated_example() -> impl Strategy<Value = (u8, String)> {
+    (1..=25u8)
+        .prop_flat_map(|day| {
+            let examples = examples_of(day);
+            (Just(day), proptest::sample::select(examples))
+        })
+        .prop_flat_map(|(day, example)| {
+            (
+                Just(day),
+                Just(example),
+                proptest::collection::vec(edit(), 1..4),
+            )
+        })
+        .prop_map(|(day, mut example, edits)| {
+            edits.iter().for_each(|edit| edit.apply(&mut example));
+            (day, example)
+        })
+}
+
+proptest! {
+    #[test]
+    fn parsers_survive_mutated_examples((day, input) in mutated_example()) {
+        let _ = days::parse(day, &input);
+    }
+
+    #[test]
+    fn parsers_survive_arbitrary_text(day in 1..=25u8, input in ".*") {
+        let _ = days::parse(day, &input);
+    }
+}
+
+#[test]
+fn solves_every_example() {
+    let fixtures = discover(&fixtures_dir()).unwrap_or_else(|problems| {
+        panic!("Malformed fixtures:\n{}", problems.join("\n"));
+    });
+    assert!(!fixtures.is_empty());
+
+    let failures: Vec<String> = fixtures.iter().flat_map(check).collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn reports_unpaired_fixtures() {
+    let dir = std::env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+    let day = dir.join("day-03");
+    fs::create_dir_all(&day).unwrap();
+    fs::write(day.join("example-1.txt"), "467..114..").unwrap();
+    fs::write(day.join("answers.toml"), "[example-2]\npart1 = \"0\"").unwrap();
+
+    let problems = discover(&dir).unwrap_err();
+    assert_eq!(problems.len(), 2);
+    assert!(problems[0].ends_with("example-1.txt: no expected answers"));
+    assert!(problems[1].ends_with("answers.toml: no input for example-2"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
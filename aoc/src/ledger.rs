@@ -0,0 +1,317 @@
+use std::{
+    cmp::Ordering,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+
+/// What is known about the answers of one part, as submitted to the website.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartRecord {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub too_high: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub too_low: Vec<String>,
+    /// Rejected without a hint which way it was off.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DayRecord {
+    #[serde(skip_serializing_if = "PartRecord::is_empty")]
+    pub part1: PartRecord,
+    #[serde(skip_serializing_if = "PartRecord::is_empty")]
+    pub part2: PartRecord,
+}
+
+impl DayRecord {
+    pub fn part(&self, part: u8) -> &PartRecord {
+        match part {
+            1 => &self.part1,
+            2 => &self.part2,
+            _ => panic!("There are only 2 parts"),
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut PartRecord {
+        match part {
+            1 => &mut self.part1,
+            2 => &mut self.part2,
+            _ => panic!("There are only 2 parts"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// Matches the recorded correct answer.
+    Verified,
+    /// A correct answer is recorded, but this one differs from it.
+    Regression {
+        expected: String,
+    },
+    AlreadyRejected(Feedback),
+    /// At or above an answer that was already too high.
+    TooHigh {
+        bound: String,
+    },
+    /// At or below an answer that was already too low.
+    TooLow {
+        bound: String,
+    },
+    /// Nothing speaks against it, it simply hasn't been submitted yet.
+    Unchecked,
+}
+
+impl Verdict {
+    pub fn is_flagged(&self) -> bool {
+        !matches!(self, Verdict::Verified | Verdict::Unchecked)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Verified => write!(f, "verified"),
+            Verdict::Regression { expected } => write!(f, "regression, expected {expected}"),
+            Verdict::AlreadyRejected(Feedback::TooHigh) => {
+                write!(f, "already rejected as too high")
+            }
+            Verdict::AlreadyRejected(Feedback::TooLow) => write!(f, "already rejected as too low"),
+            Verdict::AlreadyRejected(_) => write!(f, "already rejected"),
+            Verdict::TooHigh { bound } => write!(f, "too high, {bound} already was"),
+            Verdict::TooLow { bound } => write!(f, "too low, {bound} already was"),
+            Verdict::Unchecked => write!(f, "unchecked"),
+        }
+    }
+}
+
+impl PartRecord {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn check(&self, answer: &Answer) -> Verdict {
+        let answer = answer.to_string();
+
+        if let Some(correct) = &self.correct {
+            return if *correct == answer {
+                Verdict::Verified
+            } else {
+                Verdict::Regression {
+                    expected: correct.clone(),
+                }
+            };
+        }
+
+        for (rejected, feedback) in [
+            (&self.too_high, Feedback::TooHigh),
+            (&self.too_low, Feedback::TooLow),
+            (&self.wrong, Feedback::Wrong),
+        ] {
+            if rejected.contains(&answer) {
+                return Verdict::AlreadyRejected(feedback);
+            }
+        }
+
+        if let Some(bound) = self
+            .too_high
+            .iter()
+            .filter(|bound| compare(&answer, bound).is_some_and(Ordering::is_ge))
+            .min_by(|a, b| compare(a, b).unwrap_or(Ordering::Equal))
+        {
+            return Verdict::TooHigh {
+                bound: bound.clone(),
+            };
+        }
+
+        if let Some(bound) = self
+            .too_low
+            .iter()
+            .filter(|bound| compare(&answer, bound).is_some_and(Ordering::is_le))
+            .max_by(|a, b| compare(a, b).unwrap_or(Ordering::Equal))
+        {
+            return Verdict::TooLow {
+                bound: bound.clone(),
+            };
+        }
+
+        Verdict::Unchecked
+    }
+
+    pub fn record(&mut self, answer: &str, feedback: Feedback) {
+        let answer = answer.to_string();
+        match feedback {
+            Feedback::Correct => self.correct = Some(answer),
+            Feedback::TooHigh => push_new(&mut self.too_high, answer),
+            Feedback::TooLow => push_new(&mut self.too_low, answer),
+            Feedback::Wrong => push_new(&mut self.wrong, answer),
+        }
+    }
+}
+
+/// Only numeric answers have bounds, text answers can just be equal or not.
+fn compare(a: &str, b: &str) -> Option<Ordering> {
+    Some(a.parse::<i128>().ok()?.cmp(&b.parse::<i128>().ok()?))
+}
+
+fn push_new(answers: &mut Vec<String>, answer: String) {
+    if !answers.contains(&answer) {
+        answers.push(answer);
+    }
+}
+
+#[derive(Debug)]
+pub enum LedgerError {
+    Io(io::Error),
+    Read(toml::de::Error),
+    Write(toml::ser::Error),
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerError::Io(error) => write!(f, "{error}"),
+            LedgerError::Read(error) => write!(f, "invalid ledger: {error}"),
+            LedgerError::Write(error) => write!(f, "unable to write ledger: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+impl From<io::Error> for LedgerError {
+    fn from(error: io::Error) -> Self {
+        LedgerError::Io(error)
+    }
+}
+
+/// Directory with one `day-NN.toml` per day.
+pub struct Ledger {
+    dir: PathBuf,
+}
+
+impl Ledger {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day-{day:02}.toml"))
+    }
+
+    /// A day without a file yet has nothing recorded.
+    pub fn load(&self, day: u8) -> Result<DayRecord, LedgerError> {
+        match fs::read_to_string(self.path(day)) {
+            Ok(content) => toml::from_str(&content).map_err(LedgerError::Read),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(DayRecord::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, day: u8, record: &DayRecord) -> Result<(), LedgerError> {
+        let content = toml::to_string(record).map_err(LedgerError::Write)?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(day), content)?;
+        Ok(())
+    }
+
+    pub fn record(
+        &self,
+        day: u8,
+        part: u8,
+        answer: &str,
+        feedback: Feedback,
+    ) -> Result<(), LedgerError> {
+        let mut record = self.load(day)?;
+        record.part_mut(part).record(answer, feedback);
+        self.save(day, &record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day_17() -> PartRecord {
+        PartRecord {
+            too_high: vec!["1244".to_string()],
+            too_low: vec!["1050".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn checks_against_bounds() {
+        let record = day_17();
+        assert_eq!(
+            record.check(&1300u64.into()),
+            Verdict::TooHigh {
+                bound: "1244".to_string()
+            }
+        );
+        assert_eq!(
+            record.check(&1000u64.into()),
+            Verdict::TooLow {
+                bound: "1050".to_string()
+            }
+        );
+        assert_eq!(
+            record.check(&1244u64.into()),
+            Verdict::AlreadyRejected(Feedback::TooHigh)
+        );
+        assert_eq!(record.check(&1238u64.into()), Verdict::Unchecked);
+        assert_eq!(record.check(&"CMZ".into()), Verdict::Unchecked);
+    }
+
+    #[test]
+    fn verifies_correct_answer() {
+        let mut record = day_17();
+        record.record("1238", Feedback::Correct);
+        assert_eq!(record.check(&1238u64.into()), Verdict::Verified);
+        assert_eq!(
+            record.check(&1239u64.into()),
+            Verdict::Regression {
+                expected: "1238".to_string()
+            }
+        );
+        assert!(!Verdict::Verified.is_flagged());
+        assert!(Verdict::AlreadyRejected(Feedback::Wrong).is_flagged());
+    }
+
+    #[test]
+    fn reads_and_writes_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-ledger-{}", std::process::id()));
+        let ledger = Ledger::new(&dir);
+
+        assert_eq!(ledger.load(17).unwrap(), DayRecord::default());
+
+        ledger.record(17, 1, "1244", Feedback::TooHigh).unwrap();
+        ledger.record(17, 1, "1244", Feedback::TooHigh).unwrap();
+        ledger.record(17, 1, "1050", Feedback::TooLow).unwrap();
+        ledger.record(17, 2, "1416", Feedback::Correct).unwrap();
+
+        let record = ledger.load(17).unwrap();
+        assert_eq!(record.part(1), &day_17());
+        assert_eq!(record.part(2).correct.as_deref(), Some("1416"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    process::ExitCode,
+    time::Instant,
+};
+
+use clap::{ArgAction, Parser, Subcommand};
+use ledger::{Feedback, Ledger};
+use output::{AnswerRecord, Format};
+
+mod bench;
+mod days;
+#[cfg(test)]
+mod fixtures;
+mod ledger;
+mod output;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Directory with the answers already submitted for each day
+    #[arg(long, global = true, default_value = "answers")]
+    ledger: PathBuf,
+    /// Lets the solvers write what they are doing to stderr
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a single day
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Solves both parts when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Reads the puzzle input from stdin when omitted
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Records the website's feedback on a submitted answer
+    Record {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long)]
+        answer: String,
+        #[arg(long, value_enum)]
+        feedback: Feedback,
+    },
+    /// Times the parse, part 1 and part 2 phases of a single day
+    Bench {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Reads the puzzle input from stdin when omitted
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Also writes the report as JSON to this file
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let ledger = Ledger::new(&cli.ledger);
+    aoc_common::set_verbosity(cli.verbose);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => {
+            let input = read_input(input).expect("Puzzle input should be readable");
+            let record = ledger.load(day).expect("Answer ledger should be readable");
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let mut is_flagged = false;
+            for part in parts {
+                let start = Instant::now();
+                let solved = days::solve(day, part, &input);
+                let elapsed = start.elapsed();
+                match solved {
+                    Ok(answer) => {
+                        let verdict = answer
+                            .as_ref()
+                            .map(|answer| record.part(part).check(answer));
+                        is_flagged |= verdict.as_ref().is_some_and(|verdict| verdict.is_flagged());
+                        match (format, &answer, verdict) {
+                            (Format::Text, Some(answer), Some(verdict)) => {
+                                println!("day {day} part {part}: {answer} ({verdict})")
+                            }
+                            (Format::Text, _, _) => println!("day {day} part {part}: unsolved"),
+                            (Format::Json, _, verdict) => {
+                                // The verdict is left to stderr, where only the flagged ones matter
+                                if let Some(verdict) =
+                                    verdict.filter(|verdict| verdict.is_flagged())
+                                {
+                                    eprintln!("day {day} part {part}: {verdict}");
+                                }
+                                let record = AnswerRecord::new(day, part, answer.as_ref(), elapsed);
+                                let json = serde_json::to_string(&record)
+                                    .expect("Answer record should serialize");
+                                println!("{json}");
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        eprintln!("day {day} part {part}: invalid input, {error}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            if is_flagged {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Record {
+            day,
+            part,
+            answer,
+            feedback,
+        } => {
+            ledger
+                .record(day, part, &answer, feedback)
+                .expect("Answer ledger should be writable");
+            ExitCode::SUCCESS
+        }
+        Command::Bench {
+            day,
+            input,
+            runs,
+            output,
+        } => {
+            let input = read_input(input).expect("Puzzle input should be readable");
+            let report = match days::bench(day, &input, runs as usize) {
+                Ok(report) => report,
+                Err(error) => {
+                    eprintln!("day {day}: invalid input, {error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            println!("{report}");
+            if let Some(output) = output {
+                let json = serde_json::to_string_pretty(&report).expect("Report should serialize");
+                fs::write(output, json).expect("Report should be writable");
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn read_input(path: Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
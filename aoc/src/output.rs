@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use aoc_common::Answer;
+use serde::Serialize;
+
+/// How `run` writes the answers to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// A line per part, with its verdict from the ledger
+    Text,
+    /// A JSON object per part and line, and nothing else
+    Json,
+}
+
+/// One part's answer as written by `--format json`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AnswerRecord {
+    pub day: u8,
+    pub part: u8,
+    /// `null` for a part that isn't solved.
+    pub answer: Option<JsonAnswer>,
+    /// Parsing included, as the part can't run without it.
+    pub elapsed_ms: f64,
+}
+
+/// Numbers stay numbers, so dashboards don't have to parse them.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JsonAnswer {
+    Number(i128),
+    Text(String),
+}
+
+impl AnswerRecord {
+    pub fn new(day: u8, part: u8, answer: Option<&Answer>, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.map(|answer| match answer {
+                Answer::Number(number) => JsonAnswer::Number(*number),
+                Answer::Text(text) => JsonAnswer::Text(text.clone()),
+            }),
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_answer_records() {
+        let json = |answer: Option<Answer>| {
+            let record = AnswerRecord::new(5, 2, answer.as_ref(), Duration::from_micros(1500));
+            serde_json::to_string(&record).unwrap()
+        };
+        assert_eq!(
+            json(Some(Answer::from(-46i64))),
+            r#"{"day":5,"part":2,"answer":-46,"elapsed_ms":1.5}"#
+        );
+        assert_eq!(
+            json(Some(Answer::from("MCD"))),
+            r#"{"day":5,"part":2,"answer":"MCD","elapsed_ms":1.5}"#
+        );
+        assert_eq!(
+            json(None),
+            r#"{"day":5,"part":2,"answer":null,"elapsed_ms":1.5}"#
+        );
+    }
+}
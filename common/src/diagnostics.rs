@@ -0,0 +1,23 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// How much the solvers tell about their work, nothing at 0.
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+/// Like `eprintln!`, but only once the verbosity is raised, so stdout stays
+/// reserved for the answers.
+#[macro_export]
+macro_rules! diagnostic {
+    ($($arg:tt)*) => {
+        if $crate::verbosity() > 0 {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+mod diagnostics;
+mod parse;
+pub use diagnostics::{set_verbosity, verbosity};
+pub use parse::{column_of, parse_lines, parse_number, ParseError};
+
+/// A puzzle answer as printed by the runner.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Common entry point of every day, so the runner can call them all the same way.
+///
+/// Parsing is split from solving so both parts share the parsed input, and so
+/// each phase can be timed on its own. A part that the day doesn't solve (yet)
+/// returns `None`.
+pub trait Solver {
+    const DAY: u8;
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    /// Parts return a `ParseError` too, for days that only read some of the
+    /// input once they know which part they solve.
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError>;
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError>;
+
+    fn solve(part: u8, input: &str) -> Result<Option<Answer>, ParseError> {
+        let parsed = Self::parse(input)?;
+        match part {
+            1 => Self::part_one(&parsed),
+            2 => Self::part_two(&parsed),
+            _ => panic!("There are only 2 parts"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_answers() {
+        assert_eq!(Answer::from(1238u64).to_string(), "1238");
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+}
@@ -0,0 +1,114 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Malformed puzzle input, pointing at the spot where parsing gave up.
+///
+/// Line and column are both counted from 1. A line of 0 means the error came
+/// from a parser of a single line which doesn't know where that line sits,
+/// `on_line` fills it in afterwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self {
+            line: 0,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Error about `token`, which has to be a slice of `line`.
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let found = if token.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("\"{token}\"")
+        };
+        Self::new(column_of(line, token), expected, found)
+    }
+
+    /// Error about a line that ended before `expected` showed up.
+    pub fn end_of(line: &str, expected: impl Into<String>) -> Self {
+        Self::new(line.chars().count() + 1, expected, "end of line")
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Column where `token` starts, `token` has to be a slice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(line.as_ptr() as usize)
+        .filter(|offset| *offset <= line.len())
+        .expect("Token should be a slice of the line");
+    line[..offset].chars().count() + 1
+}
+
+/// Parses `token`, a slice of `line`, reporting its column when it isn't a number.
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line, token, "a number"))
+}
+
+/// Runs `parse_line` on every line, stamping errors with the line they came from.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse_line: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|error| error.on_line(index + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_token() {
+        let line = "Game 1: 3 blue, x red";
+        let token = &line[16..17];
+        let error = parse_number::<u8>(line, token).unwrap_err().on_line(4);
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 17: expected a number, found \"x\""
+        );
+        assert_eq!(
+            ParseError::end_of("abc", "':'").to_string(),
+            "line 0, column 4: expected ':', found end of line"
+        );
+    }
+
+    #[test]
+    fn stamps_line_numbers() {
+        let parsed = parse_lines("1\n2\n3", |line| parse_number::<u8>(line, line));
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+
+        let error = parse_lines("1\n2\nthree", |line| parse_number::<u8>(line, line)).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
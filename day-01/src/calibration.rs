@@ -0,0 +1,70 @@
+use aoc_common::ParseError;
+
+use crate::Token;
+
+/// The first and last numbers of a line, which make up its calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Token,
+    pub last: Token,
+}
+
+impl Calibration {
+    /// The leading digit of the first number followed by the last digit of the last one.
+    pub fn value(&self) -> u32 {
+        let mut first = self.first.value;
+        while first >= 10 {
+            first /= 10;
+        }
+        first * 10 + self.last.value % 10
+    }
+}
+
+/// The sum of the lines with a calibration value, along with what was wrong with the others.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LenientSum {
+    pub sum: u64,
+    pub skipped: Vec<ParseError>,
+}
+
+/// `line` with its calibration value, the first and last numbers marked below it. A line
+/// without one gets its error instead.
+pub fn explain(line: &str, calibration: &Result<Calibration, ParseError>) -> String {
+    let calibration = match calibration {
+        Ok(calibration) => calibration,
+        Err(error) => return format!("{line}\n{error}"),
+    };
+    let mut marks = vec![' '; line.chars().count()];
+    for token in [calibration.first, calibration.last] {
+        let column = line[..token.start].chars().count();
+        let width = line[token.start..token.end].chars().count();
+        marks[column..column + width].fill('^');
+    }
+    let marks: String = marks.into_iter().collect();
+    format!("{line}  = {}\n{}", calibration.value(), marks.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DigitParser, Parser, WordParser};
+
+    #[test]
+    fn marks_first_and_last_numbers() {
+        let line = "xtwone3four";
+        let explained = explain(line, &WordParser::default().calibrate(line));
+        assert_eq!(explained, "xtwone3four  = 24\n ^^^   ^^^^");
+
+        let line = "treb7uchet";
+        let explained = explain(line, &DigitParser {}.calibrate(line));
+        assert_eq!(explained, "treb7uchet  = 77\n    ^");
+
+        let line = "ČTYŘI";
+        let calibration = WordParser::default().calibrate(line);
+        let explained = explain(line, &calibration.map_err(|error| error.on_line(3)));
+        assert_eq!(
+            explained,
+            "ČTYŘI\nline 3, column 6: expected a digit, spelled out or not, found end of line"
+        );
+    }
+}
@@ -1,28 +1,30 @@
-use crate::Parser;
+use aoc_common::ParseError;
 
-pub(crate) struct DigitParser {}
+use crate::{Calibration, Parser, Token};
 
-impl Parser for DigitParser {
-    fn parse_line(line: &str) -> u32 {
-        let mut digits = String::new();
-        for char in line.chars() {
-            match char.to_digit(10) {
-                Some(_) => digits.push(char),
-                None => (),
-            }
-        }
-        let mut last_first = String::new();
-        last_first.push(digits.chars().nth(0).unwrap());
-        last_first.push(digits.chars().nth_back(0).unwrap());
-        last_first.parse::<u32>().unwrap()
-    }
+const EXPECTED: &str = "a digit";
+
+pub struct DigitParser {}
 
-    fn sum_lines(input: &str) -> u32 {
-        let mut values = Vec::new();
-        for line in input.lines() {
-            values.push(Self::parse_line(line));
-        }
-        values.iter().sum()
+impl Parser for DigitParser {
+    fn calibrate(&self, line: &str) -> Result<Calibration, ParseError> {
+        let token = |start: usize| Token {
+            start,
+            end: start + 1,
+            value: u32::from(line.as_bytes()[start] - b'0'),
+        };
+        let first = line
+            .bytes()
+            .position(|byte| byte.is_ascii_digit())
+            .ok_or_else(|| ParseError::end_of(line, EXPECTED))?;
+        let last = line
+            .bytes()
+            .rposition(|byte| byte.is_ascii_digit())
+            .unwrap();
+        Ok(Calibration {
+            first: token(first),
+            last: token(last),
+        })
     }
 }
 
@@ -31,24 +33,45 @@ mod tests {
     use super::*;
     #[test]
     fn parse_lines() {
-        let output = DigitParser::parse_line("1abc2");
+        let output = DigitParser {}.parse_line("1abc2").unwrap();
         assert_eq!(output, 12);
-        let output = DigitParser::parse_line("pqr3stu8vwx");
+        let output = DigitParser {}.parse_line("pqr3stu8vwx").unwrap();
         assert_eq!(output, 38);
-        let output = DigitParser::parse_line("a1b2c3d4e5f");
+        let output = DigitParser {}.parse_line("a1b2c3d4e5f").unwrap();
         assert_eq!(output, 15);
-        let output = DigitParser::parse_line("treb7uchet");
+        let output = DigitParser {}.parse_line("treb7uchet").unwrap();
         assert_eq!(output, 77);
     }
 
+    #[test]
+    fn rejects_line_without_digits() {
+        let error = DigitParser {}.sum_lines("1abc2\ntrebuchet").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 10: expected a digit, found end of line"
+        );
+    }
+
     #[test]
     fn sum() {
-        let output = DigitParser::sum_lines(
-            "1abc2
+        let output = DigitParser {}
+            .sum_lines(
+                "1abc2
         pqr3stu8vwx
         a1b2c3d4e5f
         treb7uchet",
-        );
+            )
+            .unwrap();
         assert_eq!(output, 142);
     }
+
+    #[test]
+    fn skips_lines_without_digits() {
+        let lenient = DigitParser {}
+            .sum_lenient("1abc2\ntrebuchet\ntreb7uchet\n")
+            .unwrap();
+        assert_eq!(lenient.sum, 12 + 77);
+        assert_eq!(lenient.skipped.len(), 1);
+        assert_eq!(lenient.skipped[0].line, 2);
+    }
 }
@@ -0,0 +1,147 @@
+use aoc_common::{parse_lines, Answer, ParseError, Solver};
+
+mod digit_parser;
+pub use digit_parser::DigitParser;
+
+mod word_parser;
+pub use word_parser::WordParser;
+
+mod vocabulary;
+pub use vocabulary::Vocabulary;
+
+mod scanner;
+pub use scanner::{Scan, Scanner, Token};
+
+mod calibration;
+pub use calibration::{explain, Calibration, LenientSum};
+
+mod stream;
+pub use stream::{sum_parallel, sum_reader, SumError};
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(parse(input))
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed)?.into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed)?.into()))
+    }
+}
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(lines: &[&str]) -> Result<u64, ParseError> {
+    sum_with(&DigitParser {}, lines)
+}
+
+pub fn part2(lines: &[&str]) -> Result<u64, ParseError> {
+    sum_with(&WordParser::default(), lines)
+}
+
+fn sum_with(parser: &impl Parser, lines: &[&str]) -> Result<u64, ParseError> {
+    let mut sum = 0;
+    for (index, line) in lines.iter().enumerate() {
+        let value = parser
+            .parse_line(line)
+            .map_err(|error| error.on_line(index + 1))?;
+        sum = stream::add(sum, value.into()).map_err(|_| {
+            ParseError::new(1, "a value keeping the sum within a u64", value.to_string())
+                .on_line(index + 1)
+        })?;
+    }
+    Ok(sum)
+}
+
+/// Reads the calibration value of a line, a line without any digit is malformed.
+pub trait Parser {
+    fn calibrate(&self, line: &str) -> Result<Calibration, ParseError>;
+
+    fn parse_line(&self, line: &str) -> Result<u32, ParseError> {
+        self.calibrate(line).map(|calibration| calibration.value())
+    }
+
+    fn sum_lines(&self, input: &str) -> Result<u64, SumError> {
+        let values = parse_lines(input, |line| self.parse_line(line))?;
+        values
+            .into_iter()
+            .try_fold(0, |sum, value| stream::add(sum, value.into()))
+    }
+
+    /// Sums the lines with a calibration value, skipping the malformed ones.
+    fn sum_lenient(&self, input: &str) -> Result<LenientSum, SumError> {
+        let mut lenient = LenientSum::default();
+        for (index, line) in input.lines().enumerate() {
+            match self.parse_line(line) {
+                Ok(value) => lenient.sum = stream::add(lenient.sum, value.into())?,
+                Err(error) => lenient.skipped.push(error.on_line(index + 1)),
+            }
+        }
+        Ok(lenient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    /// Letters that can't make up a spelled digit, even next to one.
+    const FILLER: &str = "[abcdjklmpqyz]{0,3}";
+
+    /// A calibration line, along with its digits in order and whether they are spelled out.
+    fn calibration_line() -> impl Strategy<Value = (String, Vec<(u32, bool)>)> {
+        let digit = (FILLER, 1..=9u32, any::<bool>());
+        (proptest::collection::vec(digit, 1..6), FILLER).prop_map(|(digits, end)| {
+            let mut line = String::new();
+            for (filler, digit, spelled) in &digits {
+                line.push_str(filler);
+                match spelled {
+                    true => line.push_str(WORDS[*digit as usize - 1]),
+                    false => line.push_str(&digit.to_string()),
+                }
+            }
+            line.push_str(&end);
+            let digits = digits
+                .into_iter()
+                .map(|(_, digit, spelled)| (digit, spelled));
+            (line, digits.collect())
+        })
+    }
+
+    fn calibration_value(digits: &[&(u32, bool)]) -> Option<u32> {
+        Some(digits.first()?.0 * 10 + digits.last()?.0)
+    }
+
+    proptest! {
+        #[test]
+        fn reads_generated_lines((line, digits) in calibration_line()) {
+            let plain: Vec<_> = digits.iter().filter(|(_, spelled)| !spelled).collect();
+            let all: Vec<_> = digits.iter().collect();
+
+            prop_assert_eq!(DigitParser {}.parse_line(&line).ok(), calibration_value(&plain));
+            prop_assert_eq!(WordParser::default().parse_line(&line).ok(), calibration_value(&all));
+        }
+
+        #[test]
+        fn round_trips(lines in proptest::collection::vec(calibration_line(), 1..10)) {
+            let input = lines.iter().map(|(line, _)| line.as_str()).collect::<Vec<_>>().join("\n");
+            prop_assert_eq!(parse(&input).join("\n"), input);
+        }
+    }
+}
@@ -1,21 +1,61 @@
-use std::fs;
+use std::{
+    env,
+    error::Error,
+    fs::{self, File},
+    io::BufReader,
+};
 
-mod digit_parser;
-use digit_parser::DigitParser;
+use advent_2023_01::{explain, sum_parallel, sum_reader, DigitParser, Parser, WordParser};
 
-mod word_parser;
-use word_parser::WordParser;
+/// Solves `data.txt`, or the file given, streaming it through `--threads N` threads if asked
+/// to. `--explain` shows what every line reads as, `--lenient` skips the lines without a
+/// calibration value and reports them.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut explaining = false;
+    let mut lenient = false;
+    let mut threads = None;
+    let mut path = "data.txt".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--explain" => explaining = true,
+            "--lenient" => lenient = true,
+            "--threads" => threads = Some(args.next().ok_or("--threads needs a number")?.parse()?),
+            _ => path = arg,
+        }
+    }
 
-fn main() {
-    let input = fs::read_to_string("data.txt").unwrap();
-    let sum_with_words = WordParser::sum_lines(&input);
-    println!("Sum of all lines with Word Parser: {sum_with_words}");
-    let sum = DigitParser::sum_lines(&input);
-    println!("Sum of all lines with Digit Parser: {sum}");
-}
-
-trait Parser {
-    fn parse_line(line: &str) -> u32;
-
-    fn sum_lines(input: &str) -> u32;
+    let parsers: [(&str, &(dyn Parser + Sync)); 2] = [
+        ("Word Parser", &WordParser::default()),
+        ("Digit Parser", &DigitParser {}),
+    ];
+    for (name, parser) in parsers {
+        let sum = if explaining || lenient {
+            let input = fs::read_to_string(&path)?;
+            if explaining {
+                println!("{name}:");
+                for (index, line) in input.lines().enumerate() {
+                    let calibration = parser.calibrate(line);
+                    let calibration = calibration.map_err(|error| error.on_line(index + 1));
+                    println!("{}", explain(line, &calibration));
+                }
+            }
+            if lenient {
+                let sum = parser.sum_lenient(&input)?;
+                for error in &sum.skipped {
+                    eprintln!("Skipped {error}");
+                }
+                sum.sum
+            } else {
+                parser.sum_lines(&input)?
+            }
+        } else {
+            match threads {
+                Some(threads) => sum_parallel(parser, File::open(&path)?, threads)?,
+                None => sum_reader(parser, BufReader::new(File::open(&path)?))?,
+            }
+        };
+        println!("Sum of all lines with {name}: {sum}");
+    }
+    Ok(())
 }
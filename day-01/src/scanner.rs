@@ -0,0 +1,309 @@
+use std::{collections::VecDeque, str::CharIndices};
+
+use crate::Vocabulary;
+
+/// A digit or word found in a line, spanning the bytes `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+const NONE: u32 = u32::MAX;
+
+#[derive(Debug, Clone)]
+struct State {
+    /// Next state for every ASCII char, failures already followed.
+    ascii: [u32; 128],
+    /// Trie edges for the other chars, failures left to the scan.
+    others: Vec<(char, u32)>,
+    fail: u32,
+    /// Patterns ending here, including those that end in a suffix of this state.
+    outputs: Vec<usize>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            ascii: [NONE; 128],
+            others: Vec::new(),
+            fail: 0,
+            outputs: Vec::new(),
+        }
+    }
+
+    fn edge(&self, char: char) -> u32 {
+        if char.is_ascii() {
+            self.ascii[char as usize]
+        } else {
+            self.others
+                .iter()
+                .find(|(other, _)| *other == char)
+                .map_or(NONE, |(_, next)| *next)
+        }
+    }
+}
+
+/// Finds every digit and vocabulary word of a line in a single pass, overlapping ones
+/// included, ignoring case.
+///
+/// An Aho–Corasick automaton over the lower case chars of the patterns.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    states: Vec<State>,
+    /// Values and lengths in chars of the patterns.
+    patterns: Vec<(u32, usize)>,
+    longest: usize,
+}
+
+impl Scanner {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let digits = (0..10).map(|digit| (char::from_digit(digit, 10).unwrap().to_string(), digit));
+        let words = vocabulary
+            .words()
+            .map(|(word, value)| (word.to_string(), value));
+
+        let mut scanner = Scanner {
+            states: vec![State::new()],
+            patterns: Vec::new(),
+            longest: 0,
+        };
+        for (pattern, value) in digits.chain(words) {
+            scanner.insert(&pattern, value);
+        }
+        scanner.link();
+        scanner
+    }
+
+    fn insert(&mut self, pattern: &str, value: u32) {
+        let mut state = 0;
+        for char in pattern.chars() {
+            state = match self.states[state].edge(char) {
+                NONE => {
+                    let next = self.states.len() as u32;
+                    self.states.push(State::new());
+                    if char.is_ascii() {
+                        self.states[state].ascii[char as usize] = next;
+                    } else {
+                        self.states[state].others.push((char, next));
+                    }
+                    next as usize
+                }
+                next => next as usize,
+            };
+        }
+        let len = pattern.chars().count();
+        self.longest = self.longest.max(len);
+        self.states[state].outputs.push(self.patterns.len());
+        self.patterns.push((value, len));
+    }
+
+    /// Sets the failure links breadth first, filling in the ASCII transitions along the way.
+    fn link(&mut self) {
+        let mut queue = VecDeque::new();
+        for byte in 0..128 {
+            match self.states[0].ascii[byte] {
+                NONE => self.states[0].ascii[byte] = 0,
+                child => queue.push_back(child as usize),
+            }
+        }
+        queue.extend(
+            self.states[0]
+                .others
+                .iter()
+                .map(|(_, child)| *child as usize),
+        );
+
+        while let Some(state) = queue.pop_front() {
+            let fail = self.states[state].fail as usize;
+            let inherited = self.states[fail].outputs.clone();
+            self.states[state].outputs.extend(inherited);
+
+            for byte in 0..128 {
+                match self.states[state].ascii[byte] {
+                    NONE => self.states[state].ascii[byte] = self.states[fail].ascii[byte],
+                    child => {
+                        self.states[child as usize].fail = self.states[fail].ascii[byte];
+                        queue.push_back(child as usize);
+                    }
+                }
+            }
+            for (char, child) in self.states[state].others.clone() {
+                self.states[child as usize].fail = self.step(fail as u32, char);
+                queue.push_back(child as usize);
+            }
+        }
+    }
+
+    fn step(&self, mut state: u32, char: char) -> u32 {
+        loop {
+            match self.states[state as usize].edge(char) {
+                NONE if state == 0 => return 0,
+                NONE => state = self.states[state as usize].fail,
+                next => return next,
+            }
+        }
+    }
+
+    /// Every match in `line`, in the order they end.
+    pub fn scan<'a>(&'a self, line: &'a str) -> Scan<'a> {
+        Scan {
+            scanner: self,
+            chars: line.char_indices(),
+            state: 0,
+            sources: VecDeque::with_capacity(self.longest),
+            end: 0,
+            pending: &[],
+        }
+    }
+}
+
+/// The matches of a [`Scanner`] in a line.
+#[derive(Debug, Clone)]
+pub struct Scan<'a> {
+    scanner: &'a Scanner,
+    chars: CharIndices<'a>,
+    state: u32,
+    /// Where the last few lower case chars came from, to find where a match starts.
+    sources: VecDeque<usize>,
+    end: usize,
+    pending: &'a [usize],
+}
+
+impl Scan<'_> {
+    fn feed(&mut self, start: usize, char: char) {
+        let scanner = self.scanner;
+        let mut lower = char.to_lowercase().peekable();
+        while let Some(lower_char) = lower.next() {
+            self.state = scanner.step(self.state, lower_char);
+            if self.sources.len() == scanner.longest {
+                self.sources.pop_front();
+            }
+            self.sources.push_back(start);
+            // A match has to end with a whole char of the line
+            if lower.peek().is_none() {
+                self.pending = &scanner.states[self.state as usize].outputs;
+            }
+        }
+        self.end = start + char.len_utf8();
+    }
+}
+
+impl Iterator for Scan<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.pending.is_empty() {
+            let (start, char) = self.chars.next()?;
+            self.feed(start, char);
+        }
+        let (value, len) = self.scanner.patterns[self.pending[0]];
+        self.pending = &self.pending[1..];
+        Some(Token {
+            start: self.sources[self.sources.len() - len],
+            end: self.end,
+            value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Length in bytes of `word` at the start of `text`, ignoring case.
+    fn prefix_len(text: &str, word: &str) -> Option<usize> {
+        let mut expected = word.chars().peekable();
+        for (index, char) in text.char_indices() {
+            for lower in char.to_lowercase() {
+                if expected.next() != Some(lower) {
+                    return None;
+                }
+            }
+            if expected.peek().is_none() {
+                return Some(index + char.len_utf8());
+            }
+        }
+        None
+    }
+
+    /// Tries every pattern at every char of `line`.
+    fn scan_slowly(vocabulary: &Vocabulary, line: &str) -> Vec<Token> {
+        let digits: Vec<(String, u32)> = (0..10).map(|digit| (digit.to_string(), digit)).collect();
+        let patterns: Vec<(&str, u32)> = digits
+            .iter()
+            .map(|(digit, value)| (digit.as_str(), *value))
+            .chain(vocabulary.words())
+            .collect();
+        let mut tokens: Vec<Token> = line
+            .char_indices()
+            .flat_map(|(start, _)| {
+                patterns.iter().filter_map(move |(pattern, value)| {
+                    let len = prefix_len(&line[start..], pattern)?;
+                    Some(Token {
+                        start,
+                        end: start + len,
+                        value: *value,
+                    })
+                })
+            })
+            .collect();
+        tokens.sort();
+        tokens
+    }
+
+    fn scan(vocabulary: &Vocabulary, line: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = Scanner::new(vocabulary).scan(line).collect();
+        tokens.sort();
+        tokens
+    }
+
+    #[test]
+    fn finds_overlapping_words() {
+        let tokens: Vec<Token> = Scanner::new(&Vocabulary::english())
+            .scan("xtwone3")
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token {
+                    start: 1,
+                    end: 4,
+                    value: 2
+                },
+                Token {
+                    start: 3,
+                    end: 6,
+                    value: 1
+                },
+                Token {
+                    start: 6,
+                    end: 7,
+                    value: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_words_in_other_scripts() {
+        let vocabulary = Vocabulary::czech();
+        let line = "ČTYŘItřidvě";
+        let tokens: Vec<(&str, u32)> = Scanner::new(&vocabulary)
+            .scan(line)
+            .map(|token| (&line[token.start..token.end], token.value))
+            .collect();
+        assert_eq!(tokens, vec![("ČTYŘI", 4), ("tři", 3), ("dvě", 2)]);
+    }
+
+    proptest! {
+        #[test]
+        fn finds_what_trying_everywhere_finds(line in "[onetwhrfuivsxgEIGHTNO0-9řčťŘ]{0,40}") {
+            let vocabulary = Vocabulary::english().with(Vocabulary::czech());
+            prop_assert_eq!(scan(&vocabulary, &line), scan_slowly(&vocabulary, &line));
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead, Read},
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Mutex,
+    },
+    thread,
+};
+
+use aoc_common::ParseError;
+
+use crate::Parser;
+
+/// Bytes a thread gets at a time, give or take a line.
+const CHUNK_SIZE: usize = 1 << 20;
+
+#[derive(Debug)]
+pub enum SumError {
+    Io(io::Error),
+    Parse(ParseError),
+    /// The sum doesn't fit in a `u64`.
+    Overflow,
+}
+
+impl fmt::Display for SumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SumError::Io(error) => write!(f, "{error}"),
+            SumError::Parse(error) => write!(f, "{error}"),
+            SumError::Overflow => write!(f, "the sum is too large"),
+        }
+    }
+}
+
+impl Error for SumError {}
+
+impl From<io::Error> for SumError {
+    fn from(error: io::Error) -> Self {
+        SumError::Io(error)
+    }
+}
+
+impl From<ParseError> for SumError {
+    fn from(error: ParseError) -> Self {
+        SumError::Parse(error)
+    }
+}
+
+pub(crate) fn add(sum: u64, value: u64) -> Result<u64, SumError> {
+    sum.checked_add(value).ok_or(SumError::Overflow)
+}
+
+/// Sums the calibration values of `reader` a line at a time.
+pub fn sum_reader<P: Parser + ?Sized>(
+    parser: &P,
+    mut reader: impl BufRead,
+) -> Result<u64, SumError> {
+    let mut line = String::new();
+    let mut sum = 0;
+    for number in 1.. {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.strip_suffix('\n').unwrap_or(&line);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let value = parser
+            .parse_line(line)
+            .map_err(|error| error.on_line(number))?;
+        sum = add(sum, value.into())?;
+    }
+    Ok(sum)
+}
+
+/// Sums the calibration values of `reader` on `threads` threads, handing them chunks of whole
+/// lines. Only a few chunks are in memory at any time.
+pub fn sum_parallel<P: Parser + Sync + ?Sized>(
+    parser: &P,
+    reader: impl Read,
+    threads: NonZeroUsize,
+) -> Result<u64, SumError> {
+    sum_chunks(parser, reader, threads, CHUNK_SIZE)
+}
+
+fn sum_chunks<P: Parser + Sync + ?Sized>(
+    parser: &P,
+    mut reader: impl Read,
+    threads: NonZeroUsize,
+    chunk_size: usize,
+) -> Result<u64, SumError> {
+    let (sender, receiver) = mpsc::sync_channel::<(usize, Vec<u8>)>(threads.get());
+    let receiver = Mutex::new(receiver);
+    let failed = AtomicBool::new(false);
+
+    // Sums by the line their chunk starts on, errors included
+    let mut sums: Vec<(usize, Result<u64, SumError>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.get())
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut sums = Vec::new();
+                    loop {
+                        let next = receiver.lock().unwrap().recv();
+                        let Ok((first_line, chunk)) = next else {
+                            return sums;
+                        };
+                        let sum = sum_chunk(parser, &chunk, first_line);
+                        if sum.is_err() {
+                            failed.store(true, Ordering::Relaxed);
+                        }
+                        sums.push((first_line, sum));
+                    }
+                })
+            })
+            .collect();
+
+        let read = read_chunks(&mut reader, chunk_size, &failed, |first_line, chunk| {
+            sender.send((first_line, chunk)).unwrap();
+        });
+        drop(sender);
+
+        let mut sums: Vec<_> = workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect();
+        if let Err(error) = read {
+            sums.push((usize::MAX, Err(error.into())));
+        }
+        sums
+    });
+
+    sums.sort_by_key(|(first_line, _)| *first_line);
+    sums.into_iter()
+        .try_fold(0, |total, (_, sum)| add(total, sum?))
+}
+
+/// Splits `reader` into chunks of at least `chunk_size` bytes ending on a line break, along with
+/// the line each starts on. Stops early once `failed` is set.
+fn read_chunks(
+    reader: &mut impl Read,
+    chunk_size: usize,
+    failed: &AtomicBool,
+    mut chunk: impl FnMut(usize, Vec<u8>),
+) -> io::Result<()> {
+    let mut buffer = Vec::new();
+    let mut first_line = 1;
+    while !failed.load(Ordering::Relaxed) {
+        // Bytes before this read hold no line break yet, even when a line outgrows a chunk
+        let scanned = buffer.len();
+        let read = reader
+            .by_ref()
+            .take(chunk_size.max(1) as u64)
+            .read_to_end(&mut buffer)?;
+        if read == 0 {
+            if !buffer.is_empty() {
+                chunk(first_line, buffer);
+            }
+            break;
+        }
+        let Some(end) = buffer[scanned..].iter().rposition(|byte| *byte == b'\n') else {
+            continue;
+        };
+        let rest = buffer.split_off(scanned + end + 1);
+        let lines = buffer.iter().filter(|byte| **byte == b'\n').count();
+        chunk(first_line, std::mem::replace(&mut buffer, rest));
+        first_line += lines;
+    }
+    Ok(())
+}
+
+fn sum_chunk<P: Parser + ?Sized>(
+    parser: &P,
+    chunk: &[u8],
+    first_line: usize,
+) -> Result<u64, SumError> {
+    let chunk = std::str::from_utf8(chunk)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    chunk.lines().enumerate().try_fold(0, |sum, (index, line)| {
+        let value = parser
+            .parse_line(line)
+            .map_err(|error| error.on_line(first_line + index))?;
+        add(sum, value.into())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{DigitParser, WordParser};
+
+    fn threads(threads: usize) -> NonZeroUsize {
+        NonZeroUsize::new(threads).unwrap()
+    }
+
+    const EXAMPLE: &str = "two1nine\r\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+
+    #[test]
+    fn sums_streamed_lines() {
+        let sum = sum_reader(&WordParser::default(), EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(sum, 281);
+
+        let error = sum_reader(&DigitParser {}, EXAMPLE.as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 13: expected a digit, found end of line"
+        );
+    }
+
+    #[test]
+    fn reports_the_first_bad_line_in_parallel() {
+        let input = format!("{EXAMPLE}\n").repeat(100) + "nothing\n" + &EXAMPLE.repeat(100);
+        let error =
+            sum_chunks(&WordParser::default(), input.as_bytes(), threads(4), 64).unwrap_err();
+        let SumError::Parse(error) = error else {
+            panic!("Expected a parse error, found {error:?}");
+        };
+        assert_eq!(error.line, 701);
+    }
+
+    #[test]
+    fn reads_long_lines_a_chunk_at_a_time() {
+        struct Counting<'a> {
+            bytes: &'a [u8],
+            reads: usize,
+        }
+
+        impl Read for Counting<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.reads += 1;
+                self.bytes.read(buf)
+            }
+        }
+
+        let input = format!("1{}2\n3\n", "x".repeat(100_000));
+        let mut reader = Counting {
+            bytes: input.as_bytes(),
+            reads: 0,
+        };
+        let mut chunks = Vec::new();
+        read_chunks(
+            &mut reader,
+            1000,
+            &AtomicBool::new(false),
+            |first_line, chunk| {
+                chunks.push((first_line, chunk.len()));
+            },
+        )
+        .unwrap();
+        assert_eq!(chunks, [(1, input.len())]);
+        assert!(reader.reads < 1000, "{} reads", reader.reads);
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let input = Cursor::new(b"1abc2\n\xff3\n".to_vec());
+        let error = sum_parallel(&DigitParser {}, input, threads(2)).unwrap_err();
+        assert!(matches!(error, SumError::Io(error) if error.kind() == io::ErrorKind::InvalidData));
+    }
+
+    proptest! {
+        #[test]
+        fn sums_the_same_in_parallel(
+            lines in proptest::collection::vec("[a-z]{0,12}[0-9][a-z0-9]{0,12}", 0..200),
+            thread_count in 1..5usize,
+            chunk_size in 1..100usize,
+        ) {
+            let input = lines.join("\n");
+            let parser = WordParser::default();
+            let expected = sum_reader(&parser, input.as_bytes()).unwrap();
+            prop_assert_eq!(sum_chunks(&parser, input.as_bytes(), threads(thread_count), chunk_size).unwrap(), expected);
+            prop_assert_eq!(parser.sum_lines(&input).unwrap(), expected);
+        }
+    }
+}
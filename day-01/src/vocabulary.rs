@@ -0,0 +1,174 @@
+use std::{fs, io, path::Path};
+
+use aoc_common::{parse_lines, parse_number, ParseError};
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const CZECH: [(&str, u32); 12] = [
+    ("jedna", 1),
+    ("jeden", 1),
+    ("dva", 2),
+    ("dvě", 2),
+    ("tři", 3),
+    ("čtyři", 4),
+    ("pět", 5),
+    ("šest", 6),
+    ("sedm", 7),
+    ("osm", 8),
+    ("devět", 9),
+    ("deset", 10),
+];
+
+const GERMAN: [(&str, u32); 9] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+/// Words that spell out numbers, matched regardless of case.
+///
+/// A word may stand for a number of more than one digit, like "twelve", which
+/// reads as its first digit at the start of a line and its last one at the end.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// "one" to "nine", what the puzzle spells out.
+    pub fn english() -> Self {
+        Self::from_words(&ENGLISH)
+    }
+
+    pub fn czech() -> Self {
+        Self::from_words(&CZECH)
+    }
+
+    pub fn german() -> Self {
+        Self::from_words(&GERMAN)
+    }
+
+    fn from_words(words: &[(&str, u32)]) -> Self {
+        let mut vocabulary = Self::new();
+        for (word, value) in words {
+            vocabulary.add(word, *value);
+        }
+        vocabulary
+    }
+
+    /// Adds `word`, or gives it a new value when it's already there.
+    pub fn add(&mut self, word: &str, value: u32) -> &mut Self {
+        assert!(!word.is_empty(), "Only a word with letters can be matched");
+        let word = word.to_lowercase();
+        match self.words.iter_mut().find(|(known, _)| *known == word) {
+            Some(known) => known.1 = value,
+            None => self.words.push((word, value)),
+        }
+        self
+    }
+
+    /// Both vocabularies in one, so lines can mix their languages.
+    pub fn with(mut self, other: Vocabulary) -> Self {
+        for (word, value) in other.words {
+            self.add(&word, value);
+        }
+        self
+    }
+
+    /// Words in lower case with their values.
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    /// Reads a line like `twelve = 12` per word, skipping empty lines and `#` comments.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut vocabulary = Self::new();
+        for (word, value) in parse_lines(input, parse_word)?.into_iter().flatten() {
+            vocabulary.add(word, value);
+        }
+        Ok(vocabulary)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let input = fs::read_to_string(path)?;
+        Self::parse(&input).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
+
+fn parse_word(line: &str) -> Result<Option<(&str, u32)>, ParseError> {
+    if line.trim().is_empty() || line.trim_start().starts_with('#') {
+        return Ok(None);
+    }
+    let (word, value) = line
+        .split_once('=')
+        .ok_or_else(|| ParseError::end_of(line, "'='"))?;
+    let word = word.trim();
+    if word.is_empty() {
+        return Err(ParseError::at(line, word, "a word"));
+    }
+    let value = parse_number(line, value.trim())?;
+    Ok(Some((word, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_vocabulary() {
+        let input = "# English teens
+ten = 10
+Twelve = 12
+
+eleven=11
+ten = 1";
+        let vocabulary = Vocabulary::parse(input).unwrap();
+        let words: Vec<_> = vocabulary.words().collect();
+        assert_eq!(words, vec![("ten", 1), ("twelve", 12), ("eleven", 11)]);
+    }
+
+    #[test]
+    fn reports_malformed_words() {
+        let error = Vocabulary::parse("ten = 10\ntwelve 12").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 10: expected '=', found end of line"
+        );
+        let error = Vocabulary::parse("ten = x").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+        let error = Vocabulary::parse(" = 3").unwrap_err();
+        assert_eq!(error.expected, "a word");
+    }
+
+    #[test]
+    fn combines_languages() {
+        let vocabulary = Vocabulary::english().with(Vocabulary::czech());
+        assert_eq!(vocabulary.words().count(), 21);
+        let mut vocabulary = Vocabulary::german();
+        vocabulary.add("FÜNF", 50);
+        assert!(vocabulary.words().any(|word| word == ("fünf", 50)));
+        assert_eq!(vocabulary.words().count(), 9);
+    }
+}
@@ -1,66 +1,45 @@
-use std::collections::HashMap;
+use std::cmp::{self, Reverse};
 
-use crate::Parser;
+use aoc_common::ParseError;
 
-pub(crate) struct WordParser {}
+use crate::{Calibration, Parser, Scanner, Vocabulary};
 
-impl Parser for WordParser {
-    fn parse_line(line: &str) -> u32 {
-        let map = get_map();
-
-        let unified_line = parse_words(line, map);
-
-        let digits = gather_digits(unified_line);
-        let mut last_first = String::new();
-        last_first.push(digits.chars().nth(0).unwrap());
-        last_first.push(digits.chars().nth_back(0).unwrap());
-        last_first.parse::<u32>().unwrap()
-    }
+const EXPECTED: &str = "a digit, spelled out or not";
 
-    fn sum_lines(input: &str) -> u32 {
-        let mut values = Vec::new();
-        for line in input.lines() {
-            values.push(Self::parse_line(line));
-        }
-        values.iter().sum()
-    }
+/// Reads digits as well as the numbers a vocabulary spells out, English unless told otherwise.
+pub struct WordParser {
+    scanner: Scanner,
 }
 
-fn gather_digits(unified_line: String) -> String {
-    let mut digits = String::new();
-    for char in unified_line.chars() {
-        match char.to_digit(10) {
-            Some(_) => digits.push(char),
-            None => (),
+impl WordParser {
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        Self {
+            scanner: Scanner::new(&vocabulary),
         }
     }
-    digits
 }
 
-fn parse_words(line: &str, map: HashMap<&str, &str>) -> String {
-    let mut unified_line = String::new();
-
-    for ch in line.chars() {
-        unified_line.push(ch);
-        for key in map.keys() {
-            unified_line = unified_line.replace(key, map.get(key).unwrap())
-        }
+impl Default for WordParser {
+    fn default() -> Self {
+        Self::new(Vocabulary::english())
     }
-    unified_line
 }
 
-fn get_map() -> HashMap<&'static str, &'static str> {
-    let mut map: HashMap<&str, &str> = HashMap::new();
-    map.insert("one", "1ne");
-    map.insert("two", "2wo");
-    map.insert("three", "3hree");
-    map.insert("four", "4our");
-    map.insert("five", "5ive");
-    map.insert("six", "6ix");
-    map.insert("seven", "7even");
-    map.insert("eight", "8ight");
-    map.insert("nine", "9ine");
-    map
+impl Parser for WordParser {
+    /// Words may overlap. Where several numbers start at the same spot, the longest one counts.
+    fn calibrate(&self, line: &str) -> Result<Calibration, ParseError> {
+        let mut tokens = self.scanner.scan(line);
+        let token = tokens
+            .next()
+            .ok_or_else(|| ParseError::end_of(line, EXPECTED))?;
+        let (first, last) = tokens.fold((token, token), |(first, last), token| {
+            (
+                cmp::min_by_key(first, token, |token| (token.start, Reverse(token.end))),
+                cmp::max_by_key(last, token, |token| (token.start, token.end)),
+            )
+        });
+        Ok(Calibration { first, last })
+    }
 }
 
 #[cfg(test)]
@@ -68,35 +47,76 @@ mod tests {
     use super::*;
     #[test]
     fn parse_lines() {
-        let output = WordParser::parse_line("two1nine");
+        let output = WordParser::default().parse_line("two1nine").unwrap();
         assert_eq!(output, 29);
-        let output = WordParser::parse_line("eightwothree");
+        let output = WordParser::default().parse_line("eightwothree").unwrap();
         assert_eq!(output, 83);
-        let output = WordParser::parse_line("abcone2threexyz");
+        let output = WordParser::default().parse_line("abcone2threexyz").unwrap();
         assert_eq!(output, 13);
-        let output = WordParser::parse_line("xtwone3four");
+        let output = WordParser::default().parse_line("xtwone3four").unwrap();
         assert_eq!(output, 24);
-        let output = WordParser::parse_line("4nineeightseven2");
+        let output = WordParser::default()
+            .parse_line("4nineeightseven2")
+            .unwrap();
         assert_eq!(output, 42);
-        let output = WordParser::parse_line("zoneight234");
+        let output = WordParser::default().parse_line("zoneight234").unwrap();
         assert_eq!(output, 14);
-        let output = WordParser::parse_line("7pqrstsixteen");
+        let output = WordParser::default().parse_line("7pqrstsixteen").unwrap();
         assert_eq!(output, 76);
-        let output = WordParser::parse_line("oneoneeight");
+        let output = WordParser::default().parse_line("oneoneeight").unwrap();
         assert_eq!(output, 18);
     }
 
     #[test]
     fn sum() {
-        let output = WordParser::sum_lines(
-            "two1nine
+        let output = WordParser::default()
+            .sum_lines(
+                "two1nine
             eightwothree
             abcone2threexyz
             xtwone3four
             4nineeightseven2
             zoneight234
             7pqrstsixteen",
-        );
+            )
+            .unwrap();
         assert_eq!(output, 281);
     }
+
+    #[test]
+    fn reads_other_languages() {
+        let parser = WordParser::new(Vocabulary::czech().with(Vocabulary::german()));
+        assert_eq!(parser.parse_line("xjednadvatři").unwrap(), 13);
+        assert_eq!(parser.parse_line("ČTYŘIpětSEDMzwei").unwrap(), 42);
+        assert_eq!(parser.parse_line("achtundsiebzig").unwrap(), 88);
+        assert_eq!(parser.parse_line("osmdeset").unwrap(), 80);
+        assert!(parser.parse_line("one").is_err());
+    }
+
+    #[test]
+    fn reads_numbers_of_several_digits() {
+        let vocabulary = Vocabulary::parse("seven = 7\nseventeen = 17\ntwelve = 12").unwrap();
+        let parser = WordParser::new(vocabulary);
+        assert_eq!(parser.parse_line("twelve").unwrap(), 12);
+        assert_eq!(parser.parse_line("seventeen3").unwrap(), 13);
+        assert_eq!(parser.parse_line("4seventeen").unwrap(), 47);
+        assert_eq!(parser.parse_line("Twelvexseven").unwrap(), 17);
+    }
+
+    #[test]
+    fn ignores_case() {
+        let parser = WordParser::default();
+        assert_eq!(parser.parse_line("EightWO").unwrap(), 82);
+        assert_eq!(parser.parse_line("sIx").unwrap(), 66);
+    }
+
+    #[test]
+    fn reads_long_documents() {
+        let line = "xtwone3four".repeat(100_000);
+        let input = format!("{line}\n{line}\nseven{line}Eight");
+        assert_eq!(
+            WordParser::default().sum_lines(&input).unwrap(),
+            24 + 24 + 78
+        );
+    }
 }
@@ -0,0 +1,179 @@
+use std::{collections::BTreeMap, fmt};
+
+use aoc_common::ParseError;
+
+use crate::{parse_cubes, Game};
+
+/// How many cubes of each color the elf's bag holds, no cubes of a color it doesn't list.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bag {
+    limits: BTreeMap<String, u32>,
+}
+
+impl Bag {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 12 red, 13 green and 14 blue cubes, the bag of the puzzle.
+    pub fn standard() -> Self {
+        let mut bag = Self::new();
+        bag.add("red", 12).add("green", 13).add("blue", 14);
+        bag
+    }
+
+    /// Puts `limit` cubes of `color` in the bag, instead of any there were.
+    pub fn add(&mut self, color: &str, limit: u32) -> &mut Self {
+        self.limits.insert(color.to_string(), limit);
+        self
+    }
+
+    pub fn limit(&self, color: &str) -> u32 {
+        self.limits.get(color).copied().unwrap_or(0)
+    }
+
+    /// Colors in alphabetical order with their limits.
+    pub fn limits(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.limits
+            .iter()
+            .map(|(color, limit)| (color.as_str(), *limit))
+    }
+
+    /// Whether `game` could have been played with this bag.
+    pub fn allows(&self, game: &Game) -> bool {
+        game.maxima()
+            .all(|(color, maximum)| maximum <= self.limit(color))
+    }
+
+    /// The smallest bag all of `games` could have been played with.
+    pub fn minimal<'a>(games: impl IntoIterator<Item = &'a Game>) -> Self {
+        let mut bag = Self::new();
+        for game in games {
+            for (color, maximum) in game.maxima() {
+                if maximum >= bag.limit(color) {
+                    bag.add(color, maximum);
+                }
+            }
+        }
+        bag
+    }
+
+    /// Cubes to add to the bag for it to allow `game`, none when it already does.
+    pub fn shortfall<'a>(&self, game: &'a Game) -> Vec<(&'a str, u32)> {
+        game.maxima()
+            .filter(|(color, maximum)| *maximum > self.limit(color))
+            .map(|(color, maximum)| (color, maximum - self.limit(color)))
+            .collect()
+    }
+
+    /// Every time `game` showed more cubes of a color than the bag holds.
+    pub fn violations<'a>(&self, game: &'a Game) -> Vec<Violation<'a>> {
+        let rounds = game.rounds().iter().enumerate();
+        rounds
+            .flat_map(|(round, cubes)| cubes.cubes().map(move |cubes| (round, cubes)))
+            .filter(|(_, (color, amount))| *amount > self.limit(color))
+            .map(|(round, (color, amount))| Violation {
+                round,
+                color,
+                amount,
+                limit: self.limit(color),
+            })
+            .collect()
+    }
+
+    /// Reads cubes the way a round shows them, as in `12 red, 13 green, 14 blue`.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut bag = Self::new();
+        for (color, limit) in parse_cubes(input, input)? {
+            bag.add(color, limit);
+        }
+        Ok(bag)
+    }
+}
+
+/// Cubes shown in a round that the bag doesn't hold, `round` indexes the game's rounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation<'a> {
+    pub round: usize,
+    pub color: &'a str,
+    pub amount: u32,
+    pub limit: u32,
+}
+
+impl fmt::Display for Violation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "round {}: {} {}, but only {} in the bag",
+            self.round + 1,
+            self.amount,
+            self.color,
+            self.limit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_games_against_limits() {
+        let game = Game::parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 300 yellow").unwrap();
+        assert!(!Bag::standard().allows(&game));
+
+        let bag = Bag::parse("4 red, 2 green, 3 blue, 300 yellow").unwrap();
+        assert!(bag.allows(&game));
+        let mut bag = bag;
+        bag.add("red", 3);
+        assert!(!bag.allows(&game));
+    }
+
+    #[test]
+    fn parses_bags() {
+        let bag = Bag::parse("12 red, 13 green, 14 blue").unwrap();
+        assert_eq!(bag, Bag::standard());
+        assert_eq!(bag.limit("purple"), 0);
+
+        let error = Bag::parse("12 red, green").unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (8, "an amount and a color")
+        );
+    }
+
+    #[test]
+    fn finds_minimal_bag() {
+        let games = crate::parse(EXAMPLE).unwrap();
+        let bag = Bag::minimal(&games);
+        assert_eq!(bag, Bag::parse("20 red, 13 green, 15 blue").unwrap());
+        assert!(games.iter().all(|game| bag.allows(game)));
+        assert!(Bag::minimal(&[]).limits().next().is_none());
+    }
+
+    #[test]
+    fn finds_what_a_game_needs() {
+        let games = crate::parse(EXAMPLE).unwrap();
+        let bag = Bag::standard();
+        assert!(bag.shortfall(&games[0]).is_empty());
+        assert_eq!(bag.shortfall(&games[2]), vec![("red", 8)]);
+        assert_eq!(bag.shortfall(&games[3]), vec![("blue", 1), ("red", 2)]);
+
+        let violations = bag.violations(&games[3]);
+        let report: Vec<String> = violations.iter().map(Violation::to_string).collect();
+        assert_eq!(
+            report,
+            vec![
+                "round 3: 15 blue, but only 14 in the bag",
+                "round 3: 14 red, but only 12 in the bag",
+            ]
+        );
+        assert!(bag.violations(&games[1]).is_empty());
+    }
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+}
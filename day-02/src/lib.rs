@@ -0,0 +1,287 @@
+use std::{collections::BTreeMap, fmt};
+
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Solver};
+
+mod bag;
+pub use bag::{Bag, Violation};
+
+mod stats;
+pub use stats::{color_stats, ColorStats};
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+    type Parsed<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed)?.into()))
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Game>, ParseError> {
+    parse_lines(data, Game::parse_game)
+}
+
+pub fn render(games: &[Game]) -> String {
+    games
+        .iter()
+        .map(|game| {
+            let rounds: Vec<String> = game.rounds().iter().map(Round::to_string).collect();
+            format!("Game {}: {}", game.id, rounds.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(games: &[Game]) -> u32 {
+    sum_possible(games, &Bag::standard())
+}
+
+/// Sums the ids of the games `bag` allows.
+pub fn sum_possible(games: &[Game], bag: &Bag) -> u32 {
+    games
+        .iter()
+        .filter(|game| bag.allows(game))
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn part2(games: &[Game]) -> Result<u64, ParseError> {
+    let bag = Bag::standard();
+    games
+        .iter()
+        .enumerate()
+        .try_fold(0u64, |sum, (index, game)| {
+            bag.limits()
+                .try_fold(1u64, |power, (color, _)| {
+                    power.checked_mul(game.maximum(color).into())
+                })
+                .and_then(|power| sum.checked_add(power))
+                .ok_or_else(|| {
+                    ParseError::new(1, "fewer cubes", "too many to add up").on_line(index + 1)
+                })
+        })
+}
+
+/// Reads cubes like `3 blue, 4 red` in `text`, a slice of `line`.
+fn parse_cubes<'a>(line: &str, text: &'a str) -> Result<Vec<(&'a str, u32)>, ParseError> {
+    text.split(',')
+        .map(
+            |cubes| match cubes.split_whitespace().collect::<Vec<_>>()[..] {
+                [amount, color] => Ok((color, parse_number(line, amount)?)),
+                _ => Err(ParseError::at(line, cubes, "an amount and a color")),
+            },
+        )
+        .collect()
+}
+
+/// Cubes the elf showed at once, in the order the game lists them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    cubes: Vec<(String, u32)>,
+}
+
+impl Round {
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(color, amount)| (color.as_str(), *amount))
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (color, amount)) in self.cubes().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{amount} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A game with its rounds, and the most cubes of each color any of them showed.
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    rounds: Vec<Round>,
+    maxima: BTreeMap<String, u32>,
+}
+
+impl Game {
+    pub fn parse_game(input: &str) -> Result<Self, ParseError> {
+        let (game, rounds) = input
+            .split_once(':')
+            .ok_or_else(|| ParseError::end_of(input, "':'"))?;
+
+        let id = match game.split_whitespace().last() {
+            Some(id) => parse_number(input, id)?,
+            None => return Err(ParseError::at(input, game, "a game id")),
+        };
+
+        let rounds = rounds
+            .split(';')
+            .map(|round| {
+                let cubes = parse_cubes(input, round)?;
+                let cubes = cubes
+                    .into_iter()
+                    .map(|(color, amount)| (color.to_string(), amount));
+                Ok(Round {
+                    cubes: cubes.collect(),
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let mut maxima = BTreeMap::new();
+        for (color, amount) in rounds.iter().flat_map(Round::cubes) {
+            let maximum = maxima.entry(color.to_string()).or_insert(0);
+            *maximum = amount.max(*maximum);
+        }
+        Ok(Self { id, rounds, maxima })
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// 0 for a color the game never showed.
+    pub fn maximum(&self, color: &str) -> u32 {
+        self.maxima.get(color).copied().unwrap_or(0)
+    }
+
+    /// Colors the game showed in alphabetical order, with the most cubes of each.
+    pub fn maxima(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.maxima
+            .iter()
+            .map(|(color, maximum)| (color.as_str(), *maximum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const COLORS: [&str; 5] = ["red", "green", "blue", "yellow", "dark-violet"];
+
+    /// A game along with the maximum of each color it shows.
+    fn game(id: u32) -> impl Strategy<Value = (String, BTreeMap<String, u32>)> {
+        let throw = (0..COLORS.len(), 0..=300u32);
+        let round = proptest::collection::vec(throw, 1..4);
+        proptest::collection::vec(round, 1..5).prop_map(move |rounds| {
+            let mut maxima = BTreeMap::new();
+            let rounds: Vec<String> = rounds
+                .iter()
+                .map(|throws| {
+                    let throws: Vec<String> = throws
+                        .iter()
+                        .map(|&(color, amount)| {
+                            let maximum = maxima.entry(COLORS[color].to_string()).or_insert(0);
+                            *maximum = amount.max(*maximum);
+                            format!("{amount} {}", COLORS[color])
+                        })
+                        .collect();
+                    throws.join(", ")
+                })
+                .collect();
+            (format!("Game {id}: {}", rounds.join("; ")), maxima)
+        })
+    }
+
+    fn games() -> impl Strategy<Value = (String, Vec<BTreeMap<String, u32>>)> {
+        (1..20u32)
+            .prop_flat_map(|count| (1..=count).map(game).collect::<Vec<_>>())
+            .prop_map(|games| {
+                let (lines, maxima): (Vec<String>, _) = games.into_iter().unzip();
+                (lines.join("\n"), maxima)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn finds_maximum_of_generated_games((input, maxima) in games()) {
+            let games = parse(&input).unwrap();
+            let found: Vec<BTreeMap<String, u32>> = games
+                .iter()
+                .map(|game| game.maxima().map(|(color, maximum)| (color.to_string(), maximum)).collect())
+                .collect();
+            prop_assert_eq!(found, maxima);
+        }
+
+        #[test]
+        fn round_trips((input, _) in games()) {
+            let games = parse(&input).unwrap();
+            prop_assert_eq!(render(&games), input);
+        }
+    }
+
+    #[test]
+    fn get_max() {
+        let bag = Bag::standard();
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let game = Game::parse_game(input).unwrap();
+        assert_eq!(game.id, 1);
+        let maxima: Vec<_> = game.maxima().collect();
+        assert_eq!(maxima, vec![("blue", 6), ("green", 2), ("red", 4)]);
+        assert!(bag.allows(&game));
+
+        let input = "Game 100: 6 green, 15 red, 12 blue; 9 red; 16 red; 17 red, 3 blue, 7 green";
+        let game = Game::parse_game(input).unwrap();
+        assert_eq!(game.id, 100);
+        let maxima: Vec<_> = game.maxima().collect();
+        assert_eq!(maxima, vec![("blue", 12), ("green", 7), ("red", 17)]);
+        assert!(!bag.allows(&game));
+    }
+
+    #[test]
+    fn counts_past_255() {
+        let games = parse("Game 300: 256 red, 1 green, 1 blue\nGame 2: 2 purple").unwrap();
+        assert_eq!(games[0].maximum("red"), 256);
+        assert_eq!(games[1].maximum("purple"), 2);
+        assert_eq!(part1(&games), 0);
+        assert_eq!(part2(&games), Ok(256));
+
+        let mut bag = Bag::standard();
+        bag.add("red", 300).add("purple", 2);
+        assert_eq!(sum_possible(&games, &bag), 302);
+    }
+
+    #[test]
+    fn reports_too_many_cubes() {
+        let games = parse(
+            "Game 1: 1 red, 1 green, 1 blue\nGame 2: 4000000000 red, 4000000000 green, 4000000000 blue",
+        )
+        .unwrap();
+        let error = part2(&games).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected fewer cubes, found too many to add up"
+        );
+    }
+
+    #[test]
+    fn reports_malformed_games() {
+        let error = parse("Game 1: 3 blue\nGame 2: 4 purple blue").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: expected an amount and a color, found \" 4 purple blue\""
+        );
+
+        let error = Game::parse_game("Game x: 3 blue").unwrap_err();
+        assert_eq!(error.column, 6);
+
+        let error = Game::parse_game("Game 3: 3 blue, 4").unwrap_err();
+        assert_eq!(error.expected, "an amount and a color");
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use crate::Game;
+
+/// How many cubes of a color the rounds showing it had.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorStats {
+    pub rounds: usize,
+    pub total: u64,
+    pub min: u32,
+    /// The lower of the middle two for an even number of rounds.
+    pub median: u32,
+    pub max: u32,
+}
+
+impl ColorStats {
+    pub fn mean(&self) -> f64 {
+        self.total as f64 / self.rounds as f64
+    }
+
+    fn from_amounts(mut amounts: Vec<u32>) -> Self {
+        amounts.sort_unstable();
+        Self {
+            rounds: amounts.len(),
+            total: amounts.iter().map(|amount| u64::from(*amount)).sum(),
+            min: amounts[0],
+            median: amounts[(amounts.len() - 1) / 2],
+            max: amounts[amounts.len() - 1],
+        }
+    }
+}
+
+/// Statistics of every color over the rounds of all `games`, a round showing a color twice
+/// counts both times.
+pub fn color_stats(games: &[Game]) -> BTreeMap<String, ColorStats> {
+    let mut amounts: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+    for (color, amount) in games
+        .iter()
+        .flat_map(Game::rounds)
+        .flat_map(|round| round.cubes())
+    {
+        amounts.entry(color).or_default().push(amount);
+    }
+    amounts
+        .into_iter()
+        .map(|(color, amounts)| (color.to_string(), ColorStats::from_amounts(amounts)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_colors() {
+        let games = crate::parse(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+        )
+        .unwrap();
+        let stats = color_stats(&games);
+        assert_eq!(stats.keys().collect::<Vec<_>>(), ["blue", "green", "red"]);
+        assert_eq!(
+            stats["blue"],
+            ColorStats {
+                rounds: 5,
+                total: 15,
+                min: 1,
+                median: 3,
+                max: 6,
+            }
+        );
+        assert_eq!(stats["red"].median, 1);
+        assert_eq!(stats["green"].mean(), 2.0);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{Coordinate, Gearbox, NumberId};
+
+const RESET: &str = "\x1b[0m";
+
+/// How a run of cells gets colored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    PartNumber,
+    /// A number touching no part.
+    LooseNumber,
+    Gear,
+    Part,
+}
+
+impl Kind {
+    fn ansi(self) -> &'static str {
+        match self {
+            Kind::PartNumber => "\x1b[32m",
+            Kind::LooseNumber => "\x1b[31m",
+            Kind::Gear => "\x1b[1;33m",
+            Kind::Part => "\x1b[36m",
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Kind::PartNumber => "part-number",
+            Kind::LooseNumber => "loose-number",
+            Kind::Gear => "gear",
+            Kind::Part => "part",
+        }
+    }
+}
+
+/// What sits at a spot of the schematic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Cell {
+    Empty,
+    Digit(NumberId, char),
+    Part(Coordinate, char),
+}
+
+impl Cell {
+    pub(crate) fn char(self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Digit(_, char) | Cell::Part(_, char) => char,
+        }
+    }
+}
+
+/// The schematic as rows of cells, every one as wide as its widest line.
+pub(crate) fn cells(gearbox: &Gearbox) -> Vec<Vec<Cell>> {
+    let mut rows = vec![vec![Cell::Empty; gearbox.width]; gearbox.height];
+
+    for (id, number) in gearbox.numbers.iter().enumerate() {
+        let digits = format!("{:0width$}", number.value, width = number.coordinates.len());
+        for (coordinate, digit) in number.coordinates.iter().zip(digits.chars()) {
+            rows[coordinate.y as usize][coordinate.x as usize] = Cell::Digit(id, digit);
+        }
+    }
+    for (coordinate, part) in &gearbox.parts {
+        let symbol = part.symbol.chars().next().unwrap();
+        rows[coordinate.y as usize][coordinate.x as usize] = Cell::Part(*coordinate, symbol);
+    }
+    rows
+}
+
+/// Runs of cells that belong together, a whole number or a single other cell.
+fn runs(row: &[Cell]) -> Vec<&[Cell]> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for end in 1..=row.len() {
+        let joined = match (row[end - 1], row.get(end)) {
+            (Cell::Digit(id, _), Some(Cell::Digit(next, _))) => id == *next,
+            _ => false,
+        };
+        if !joined {
+            runs.push(&row[start..end]);
+            start = end;
+        }
+    }
+    runs
+}
+
+impl Gearbox {
+    fn is_gear(&self, part: Coordinate) -> bool {
+        self.parts[&part].symbol == "*" && self.numbers_of(part).count() == 2
+    }
+
+    fn kind(&self, cell: Cell) -> Option<Kind> {
+        match cell {
+            Cell::Empty => None,
+            Cell::Digit(id, _) if self.parts_of(id).next().is_some() => Some(Kind::PartNumber),
+            Cell::Digit(..) => Some(Kind::LooseNumber),
+            Cell::Part(coordinate, _) if self.is_gear(coordinate) => Some(Kind::Gear),
+            Cell::Part(..) => Some(Kind::Part),
+        }
+    }
+
+    fn describe(&self, cell: Cell) -> String {
+        let at = |coordinate: Coordinate| {
+            format!("line {}, column {}", coordinate.y + 1, coordinate.x + 1)
+        };
+        match cell {
+            Cell::Empty => String::new(),
+            Cell::Digit(id, _) => {
+                let parts: Vec<String> = self
+                    .parts_of(id)
+                    .map(|part| format!("{} at {}", self.parts[&part].symbol, at(part)))
+                    .collect();
+                match parts.is_empty() {
+                    true => "touches no part".to_string(),
+                    false => format!("touches {}", parts.join("; ")),
+                }
+            }
+            Cell::Part(coordinate, _) => {
+                let numbers: Vec<String> = self
+                    .numbers_of(coordinate)
+                    .map(|id| self.numbers[id].value.to_string())
+                    .collect();
+                let gear = match self.is_gear(coordinate) {
+                    true => "gear ",
+                    false => "",
+                };
+                match numbers.is_empty() {
+                    true => format!("at {}, touches no number", at(coordinate)),
+                    false => format!(
+                        "{gear}at {}, touches {}",
+                        at(coordinate),
+                        numbers.join(", ")
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Draws the schematic with part numbers in green, numbers touching no part in red,
+    /// gears in bold yellow and the other parts in cyan.
+    pub fn render_ansi(&self) -> String {
+        let rows: Vec<String> = cells(self)
+            .iter()
+            .map(|row| {
+                let mut line = String::new();
+                for run in runs(row) {
+                    let text: String = run.iter().map(|cell| cell.char()).collect();
+                    match self.kind(run[0]) {
+                        Some(kind) => line.push_str(&format!("{}{text}{RESET}", kind.ansi())),
+                        None => line.push_str(&text),
+                    }
+                }
+                line
+            })
+            .collect();
+        rows.join("\n")
+    }
+
+    /// The schematic as an HTML page in the colors of `render_ansi`, hovering over a number
+    /// shows the parts it touches and hovering over a part the numbers it touches.
+    pub fn render_html(&self) -> String {
+        let mut html = String::from(
+            "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<style>
+.part-number { color: green; }
+.loose-number { color: red; }
+.gear { color: goldenrod; font-weight: bold; }
+.part { color: darkcyan; }
+</style>
+</head>
+<body>
+<pre>
+",
+        );
+        for row in cells(self) {
+            for run in runs(&row) {
+                let text: String = run.iter().map(|cell| escape(cell.char())).collect();
+                let Some(kind) = self.kind(run[0]) else {
+                    html.push_str(&text);
+                    continue;
+                };
+                let title: String = self.describe(run[0]).chars().map(escape).collect();
+                html.push_str(&format!(
+                    "<span class=\"{}\" title=\"{title}\">{text}</span>",
+                    kind.class()
+                ));
+            }
+            html.push('\n');
+        }
+        html.push_str("</pre>\n</body>\n</html>\n");
+        html
+    }
+}
+
+fn escape(char: char) -> String {
+    match char {
+        '&' => "&amp;".to_string(),
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '"' => "&quot;".to_string(),
+        _ => char.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+
+    #[test]
+    fn colors_numbers_and_gears() {
+        let gearbox = parse("467..114..\n...*......\n..35..&...").unwrap();
+        assert_eq!(
+            gearbox.render_ansi(),
+            "\x1b[32m467\x1b[0m..\x1b[31m114\x1b[0m..\n\
+             ...\x1b[1;33m*\x1b[0m......\n\
+             ..\x1b[32m35\x1b[0m..\x1b[36m&\x1b[0m..."
+        );
+    }
+
+    #[test]
+    fn keeps_trailing_dots() {
+        let gearbox = parse("12.\n.*..\n....").unwrap();
+        assert_eq!(crate::render(&gearbox), "12..\n.*..\n....");
+    }
+
+    #[test]
+    fn explains_numbers_in_html() {
+        let gearbox = parse("467..114..\n...*......\n..35..&...").unwrap();
+        let html = gearbox.render_html();
+        assert!(html.contains(
+            "<span class=\"part-number\" title=\"touches * at line 2, column 4\">467</span>"
+        ));
+        assert!(html.contains("<span class=\"loose-number\" title=\"touches no part\">114</span>"));
+        assert!(html.contains(
+            "<span class=\"gear\" title=\"gear at line 2, column 4, touches 467, 35\">*</span>"
+        ));
+        assert!(html.contains(
+            "<span class=\"part\" title=\"at line 3, column 7, touches no number\">&amp;</span>"
+        ));
+    }
+}
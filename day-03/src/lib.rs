@@ -0,0 +1,550 @@
+use std::collections::{BTreeMap, HashMap};
+
+use aoc_common::{Answer, ParseError, Solver};
+use aoc_graph::Graph;
+
+mod annotate;
+
+mod stream;
+pub use stream::{stream, stream_sums, Found, Stream, StreamError};
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+    type Parsed<'a> = Gearbox;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Gearbox, ParseError> {
+    Gearbox::evaluate(input)
+}
+
+/// Draws the schematic again, padding shorter lines with '.' to the widest one.
+pub fn render(gearbox: &Gearbox) -> String {
+    annotate::cells(gearbox)
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.char()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(gearbox: &Gearbox) -> u64 {
+    gearbox.sum_adjacent()
+}
+
+pub fn part2(gearbox: &Gearbox) -> u64 {
+    gearbox.sum_gears()
+}
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, PartialOrd, Ord)]
+pub struct Coordinate {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Coordinate {
+    fn neighbors(self) -> impl Iterator<Item = Coordinate> {
+        (-1..2)
+            .flat_map(move |y| (-1..2).map(move |x| (x, y)))
+            .filter(|&offset| offset != (0, 0))
+            .map(move |(x, y)| Coordinate {
+                x: self.x + x,
+                y: self.y + y,
+            })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Number {
+    pub coordinates: Vec<Coordinate>,
+    pub value: u16,
+}
+
+impl Number {
+    fn new() -> Self {
+        Number {
+            coordinates: vec![],
+            value: 0,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Part {
+    pub symbol: String,
+}
+
+/// Index of a number in the order the schematic reads them.
+pub type NumberId = usize;
+
+/// Numbers and parts of the schematic as nodes, edges join numbers to the parts they touch.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+enum Node {
+    Number(NumberId),
+    Part(Coordinate),
+}
+
+/// How the numbers around a part combine into one value, saturating at `u64::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reducer {
+    Sum,
+    Product,
+    Min,
+    Max,
+}
+
+impl Reducer {
+    pub fn reduce(self, values: impl IntoIterator<Item = u64>) -> u64 {
+        let values = values.into_iter();
+        match self {
+            Reducer::Sum => values.fold(0, u64::saturating_add),
+            Reducer::Product => values.fold(1, u64::saturating_mul),
+            Reducer::Min => values.min().unwrap_or(0),
+            Reducer::Max => values.max().unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Gearbox {
+    numbers: Vec<Number>,
+    parts: HashMap<Coordinate, Part>,
+    /// Every number, interned first so its node is its id, and the parts touching one.
+    adjacency: Graph<Node>,
+    /// The widest line and the number of lines, to draw the schematic again.
+    width: usize,
+    height: usize,
+}
+
+impl Gearbox {
+    pub fn evaluate(data: &str) -> Result<Self, ParseError> {
+        let mut gearbox = Gearbox {
+            numbers: vec![],
+            parts: HashMap::new(),
+            adjacency: Graph::undirected(),
+            width: 0,
+            height: 0,
+        };
+
+        gearbox.parse(data)?;
+        gearbox.evaluate_adjacency();
+        Ok(gearbox)
+    }
+
+    fn parse(&mut self, data: &str) -> Result<(), ParseError> {
+        for (y, line) in data.lines().enumerate() {
+            self.width = self.width.max(line.chars().count());
+            self.height = y + 1;
+            let mut buffer = String::new();
+            let mut current_number = Number::new();
+            for (x, ch) in line.chars().enumerate() {
+                if ch.is_ascii_digit() {
+                    buffer.push(ch);
+                    current_number.coordinates.push(Coordinate {
+                        x: x as i64,
+                        y: y as i64,
+                    });
+                    continue;
+                }
+
+                self.process_buffer(&mut buffer, &mut current_number)?;
+
+                if ch == '.' {
+                    continue;
+                }
+
+                self.parts.insert(
+                    Coordinate {
+                        x: x as i64,
+                        y: y as i64,
+                    },
+                    Part {
+                        symbol: ch.to_string(),
+                    },
+                );
+            }
+            self.process_buffer(&mut buffer, &mut current_number)?;
+        }
+        Ok(())
+    }
+
+    fn process_buffer(
+        &mut self,
+        buffer: &mut String,
+        current_number: &mut Number,
+    ) -> Result<(), ParseError> {
+        if !buffer.is_empty() {
+            let start = &current_number.coordinates[0];
+            current_number.value = buffer.parse::<u16>().map_err(|_| {
+                ParseError::new(
+                    start.x as usize + 1,
+                    "a part number up to 65535",
+                    format!("\"{buffer}\""),
+                )
+                .on_line(start.y as usize + 1)
+            })?;
+            buffer.clear();
+            self.numbers.push(current_number.clone());
+            *current_number = Number::new();
+        }
+        Ok(())
+    }
+
+    /// Joins every number to each part touching any of its digits, once.
+    fn evaluate_adjacency(&mut self) {
+        for id in 0..self.numbers.len() {
+            self.adjacency.intern(Node::Number(id));
+        }
+        for (id, number) in self.numbers.iter().enumerate() {
+            for coordinate in number
+                .coordinates
+                .iter()
+                .flat_map(|digit| digit.neighbors())
+            {
+                if !self.parts.contains_key(&coordinate) {
+                    continue;
+                }
+                let part = self.adjacency.intern(Node::Part(coordinate));
+                if !self.adjacency.has_edge(id, part) {
+                    self.adjacency.connect(id, part, ());
+                }
+            }
+        }
+    }
+
+    /// Coordinates of the parts touching a number.
+    pub fn parts_of(&self, number: NumberId) -> impl Iterator<Item = Coordinate> + '_ {
+        self.adjacency
+            .neighbors(number)
+            .map(|node| match self.adjacency.key(node) {
+                Node::Part(coordinate) => *coordinate,
+                Node::Number(_) => unreachable!("Numbers only touch parts"),
+            })
+    }
+
+    /// Ids of the numbers touching the part at `part`.
+    pub fn numbers_of(&self, part: Coordinate) -> impl Iterator<Item = NumberId> + '_ {
+        let node = self.adjacency.id(&Node::Part(part));
+        node.into_iter()
+            .flat_map(|node| self.adjacency.neighbors(node))
+    }
+
+    pub fn number(&self, id: NumberId) -> &Number {
+        &self.numbers[id]
+    }
+
+    /// Parts touching exactly `count` numbers, in no particular order.
+    pub fn parts_with_neighbors(&self, count: usize) -> impl Iterator<Item = (Coordinate, &Part)> {
+        self.parts
+            .iter()
+            .filter(move |(coordinate, _)| self.numbers_of(**coordinate).count() == count)
+            .map(|(coordinate, part)| (*coordinate, part))
+    }
+
+    /// Reduces the numbers around every part touching at least one, summing the results of
+    /// the parts sharing a symbol.
+    pub fn reduce_by_symbol(&self, reducer: Reducer) -> BTreeMap<&str, u64> {
+        let mut reduced = BTreeMap::new();
+        for (coordinate, part) in &self.parts {
+            let values: Vec<u64> = self
+                .numbers_of(*coordinate)
+                .map(|id| self.numbers[id].value.into())
+                .collect();
+            if values.is_empty() {
+                continue;
+            }
+            let sum: &mut u64 = reduced.entry(part.symbol.as_str()).or_default();
+            *sum = sum.saturating_add(reducer.reduce(values));
+        }
+        reduced
+    }
+
+    pub fn sum_adjacent(&self) -> u64 {
+        (0..self.numbers.len())
+            .filter(|&id| self.parts_of(id).next().is_some())
+            .map(|id| u64::from(self.numbers[id].value))
+            .sum()
+    }
+
+    /// Sums the products of the two numbers around every `*` touching exactly two.
+    pub fn sum_gears(&self) -> u64 {
+        self.parts_with_neighbors(2)
+            .filter(|(_, part)| part.symbol == "*")
+            .map(|(coordinate, _)| {
+                let values = self
+                    .numbers_of(coordinate)
+                    .map(|id| u64::from(self.numbers[id].value));
+                Reducer::Product.reduce(values)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// A row of the schematic, along with the part numbers in it.
+    fn row() -> impl Strategy<Value = (String, Vec<u16>)> {
+        let item = prop_oneof![
+            "[.]{1,3}".prop_map(|dots| (dots, None)),
+            "[*#+$/@=%&-]".prop_map(|symbol| (symbol, None)),
+            (0..1000u16).prop_map(|number| (number.to_string(), Some(number))),
+        ];
+        proptest::collection::vec(item, 1..8).prop_map(|items| {
+            let mut row = String::new();
+            let mut numbers = Vec::new();
+            for (text, number) in items {
+                if let Some(number) = number {
+                    if row.ends_with(|char: char| char.is_ascii_digit()) {
+                        row.push('.');
+                    }
+                    numbers.push(number);
+                }
+                row.push_str(&text);
+            }
+            (row, numbers)
+        })
+    }
+
+    fn schematic() -> impl Strategy<Value = (String, Vec<u16>)> {
+        proptest::collection::vec(row(), 1..10).prop_map(|rows| {
+            let (rows, numbers): (Vec<String>, Vec<Vec<u16>>) = rows.into_iter().unzip();
+            (rows.join("\n"), numbers.concat())
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn finds_generated_numbers((input, numbers) in schematic()) {
+            let gearbox = parse(&input).unwrap();
+            let found: Vec<u16> = gearbox.numbers.iter().map(|number| number.value).collect();
+            prop_assert_eq!(found, numbers);
+        }
+
+        #[test]
+        fn streams_the_same_sums((input, _) in schematic()) {
+            let gearbox = parse(&input).unwrap();
+            let sums = stream_sums(input.as_bytes()).unwrap();
+            prop_assert_eq!(sums, (gearbox.sum_adjacent(), gearbox.sum_gears()));
+        }
+
+        #[test]
+        fn round_trips((input, _) in schematic()) {
+            let gearbox = parse(&input).unwrap();
+            prop_assert_eq!(parse(&render(&gearbox)).unwrap(), gearbox);
+        }
+    }
+
+    #[test]
+    fn integration_test_part_numbers() {
+        let data = "
+..224.....487...................718.....................378............................................284........310......313..........311.
+....*..............................*744....486*485......*......741......@...359.#666...439................*925....*......$..+........@515
+.235................758..440...........................251....*......262.....*..........*......................752......774..............
+.........705%..@746........+..942*591.347.470...#..257.........637...........793.......299..../.....813....509......464......&.........688..
+.....82................................*.../..901.....*..................836.....&............814...*........*..............80...17*....*...";
+        let mut gearbox = Gearbox {
+            numbers: vec![],
+            parts: HashMap::new(),
+            adjacency: Graph::undirected(),
+            width: 0,
+            height: 0,
+        };
+
+        gearbox.parse(data).unwrap();
+        gearbox.evaluate_adjacency();
+
+        let sum_of_adjacent_parts = gearbox.sum_adjacent();
+
+        assert_eq!(sum_of_adjacent_parts, 19910);
+    }
+
+    #[test]
+    fn integration_gears() {
+        let data = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let mut gearbox = Gearbox {
+            numbers: vec![],
+            parts: HashMap::new(),
+            adjacency: Graph::undirected(),
+            width: 0,
+            height: 0,
+        };
+
+        gearbox.parse(data).unwrap();
+        gearbox.evaluate_adjacency();
+
+        let sum_gears = gearbox.sum_gears();
+
+        assert_eq!(sum_gears, 467835);
+    }
+
+    #[test]
+    fn parses_correctly() {
+        let data = "467*114...";
+
+        let mut gearbox = Gearbox {
+            numbers: vec![],
+            parts: HashMap::new(),
+            adjacency: Graph::undirected(),
+            width: 0,
+            height: 0,
+        };
+
+        gearbox.parse(data).unwrap();
+
+        assert_eq!(gearbox.numbers.len(), 2);
+        assert_eq!((gearbox.width, gearbox.height), (10, 1));
+        assert_eq!(
+            *gearbox.numbers.first().unwrap(),
+            Number {
+                coordinates: vec![
+                    Coordinate { x: 0, y: 0 },
+                    Coordinate { x: 1, y: 0 },
+                    Coordinate { x: 2, y: 0 },
+                ],
+                value: 467,
+            }
+        );
+        assert_eq!(
+            *gearbox.numbers.get(1).unwrap(),
+            Number {
+                coordinates: vec![
+                    Coordinate { x: 4, y: 0 },
+                    Coordinate { x: 5, y: 0 },
+                    Coordinate { x: 6, y: 0 },
+                ],
+                value: 114,
+            }
+        );
+
+        assert_eq!(
+            gearbox.parts,
+            HashMap::from([(
+                Coordinate { x: 3, y: 0 },
+                Part {
+                    symbol: "*".to_string(),
+                }
+            )])
+        )
+    }
+
+    #[test]
+    fn evaluates_adjacency() {
+        let gearbox = Gearbox::evaluate("467..114\n...*....").unwrap();
+        let part = Coordinate { x: 3, y: 1 };
+
+        assert_eq!(gearbox.parts_of(0).collect::<Vec<_>>(), vec![part]);
+        assert_eq!(gearbox.parts_of(1).count(), 0);
+        assert_eq!(gearbox.numbers_of(part).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(gearbox.sum_adjacent(), 467);
+    }
+
+    #[test]
+    fn counts_each_number_once_per_part() {
+        let gearbox = Gearbox::evaluate("12.\n*..\n.5.\n5*5").unwrap();
+        assert_eq!(gearbox.adjacency.edge_count(), 5);
+        assert_eq!(gearbox.numbers_of(Coordinate { x: 0, y: 1 }).count(), 2);
+
+        // Two numbers of the same value still make a gear
+        assert_eq!(gearbox.sum_gears(), 12 * 5);
+        assert_eq!(gearbox.parts_with_neighbors(3).count(), 1);
+    }
+
+    #[test]
+    fn reduces_by_symbol() {
+        let gearbox = Gearbox::evaluate("2*3.7\n..#..\n4$$..").unwrap();
+        let sums = gearbox.reduce_by_symbol(Reducer::Sum);
+        assert_eq!(sums, BTreeMap::from([("#", 3), ("$", 4), ("*", 5)]));
+        let products = gearbox.reduce_by_symbol(Reducer::Product);
+        assert_eq!(products, BTreeMap::from([("#", 3), ("$", 4), ("*", 6)]));
+        assert_eq!(gearbox.reduce_by_symbol(Reducer::Max)["*"], 3);
+    }
+
+    #[test]
+    fn reports_oversized_numbers() {
+        let error = Gearbox::evaluate("...*\n.123456").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a part number up to 65535, found \"123456\""
+        );
+    }
+
+    #[test]
+    fn streams_part_numbers_and_gears() {
+        let data = "467..114..\n...*......\n..35..633.\n......#...\n617*......";
+        let found: Vec<Found> = stream(data.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(
+            found,
+            vec![
+                Found::PartNumber {
+                    value: 467,
+                    at: Coordinate { x: 0, y: 0 }
+                },
+                Found::Gear {
+                    ratio: 467 * 35,
+                    at: Coordinate { x: 3, y: 1 }
+                },
+                Found::PartNumber {
+                    value: 35,
+                    at: Coordinate { x: 2, y: 2 }
+                },
+                Found::PartNumber {
+                    value: 633,
+                    at: Coordinate { x: 6, y: 2 }
+                },
+                Found::PartNumber {
+                    value: 617,
+                    at: Coordinate { x: 0, y: 4 }
+                },
+            ]
+        );
+
+        let error = stream_sums("...*\n.123456\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a part number up to 65535, found \"123456\""
+        );
+    }
+
+    #[test]
+    fn streams_past_old_coordinates() {
+        let rows = 40_000;
+        let data = "2*3\r\n".repeat(rows);
+        let last = stream(data.as_bytes()).last().unwrap().unwrap();
+        assert_eq!(
+            last,
+            Found::PartNumber {
+                value: 3,
+                at: Coordinate {
+                    x: 2,
+                    y: rows as i64 - 1
+                }
+            }
+        );
+        assert_eq!(stream_sums(data.as_bytes()).unwrap(), (5 * rows as u64, 0));
+    }
+}
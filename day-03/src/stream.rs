@@ -0,0 +1,209 @@
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+use aoc_common::ParseError;
+
+use crate::Coordinate;
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "{error}"),
+            StreamError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// What the stream finds in a row, once it has seen the rows around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Found {
+    /// A number touching a part, at its first digit.
+    PartNumber { value: u16, at: Coordinate },
+    /// A `*` touching exactly two numbers, with their product.
+    Gear { ratio: u64, at: Coordinate },
+}
+
+/// A row of the schematic with its numbers, as columns `start..end` and values.
+#[derive(Debug)]
+struct Row {
+    chars: Vec<char>,
+    numbers: Vec<(usize, usize, u16)>,
+}
+
+impl Row {
+    fn parse(line: &str, y: i64) -> Result<Self, ParseError> {
+        let chars: Vec<char> = line.chars().collect();
+        let mut numbers = Vec::new();
+        let mut x = 0;
+        while x < chars.len() {
+            if !chars[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < chars.len() && chars[x].is_ascii_digit() {
+                x += 1;
+            }
+            let digits: String = chars[start..x].iter().collect();
+            let value = digits.parse().map_err(|_| {
+                ParseError::new(
+                    start + 1,
+                    "a part number up to 65535",
+                    format!("\"{digits}\""),
+                )
+                .on_line(y as usize + 1)
+            })?;
+            numbers.push((start, x, value));
+        }
+        Ok(Self { chars, numbers })
+    }
+
+    fn has_symbol(&self, from: usize, to: usize) -> bool {
+        self.chars
+            .iter()
+            .take(to + 1)
+            .skip(from)
+            .any(|char| !char.is_ascii_digit() && *char != '.')
+    }
+
+    /// Values of the numbers touching column `x`, in this row or the one above or below.
+    fn numbers_around(&self, x: usize) -> impl Iterator<Item = u16> + '_ {
+        self.numbers
+            .iter()
+            .filter(move |(start, end, _)| *start <= x + 1 && *end >= x)
+            .map(|(_, _, value)| *value)
+    }
+}
+
+/// Reads a schematic a row at a time, keeping only the rows above and below the one it looks
+/// at in memory.
+pub struct Stream<R> {
+    reader: R,
+    line: String,
+    /// The row above, the current one and the row below.
+    window: [Option<Row>; 3],
+    /// Where the row below the window goes.
+    y: i64,
+    found: VecDeque<Found>,
+    done: bool,
+}
+
+pub fn stream<R: BufRead>(reader: R) -> Stream<R> {
+    Stream {
+        reader,
+        line: String::new(),
+        window: [None, None, None],
+        y: 0,
+        found: VecDeque::new(),
+        done: false,
+    }
+}
+
+impl<R: BufRead> Stream<R> {
+    /// Slides the window a row down, then looks at its middle row. False at the end.
+    fn advance(&mut self) -> Result<bool, StreamError> {
+        self.line.clear();
+        let below = match self.reader.read_line(&mut self.line)? {
+            0 => None,
+            _ => {
+                let line = self.line.strip_suffix('\n').unwrap_or(&self.line);
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                Some(Row::parse(line, self.y)?)
+            }
+        };
+        self.window.rotate_left(1);
+        self.window[2] = below;
+        let y = self.y - 1;
+        self.y += 1;
+
+        let [above, Some(row), below] = &self.window else {
+            return Ok(self.window[2].is_some());
+        };
+        let rows = || {
+            [above.as_ref(), Some(row), below.as_ref()]
+                .into_iter()
+                .flatten()
+        };
+
+        for &(start, end, value) in &row.numbers {
+            if rows().any(|row| row.has_symbol(start.saturating_sub(1), end)) {
+                let at = Coordinate { x: start as i64, y };
+                self.found.push_back(Found::PartNumber { value, at });
+            }
+        }
+        for (x, _) in row
+            .chars
+            .iter()
+            .enumerate()
+            .filter(|(_, char)| **char == '*')
+        {
+            let mut numbers = rows().flat_map(|row| row.numbers_around(x));
+            if let (Some(first), Some(second), None) =
+                (numbers.next(), numbers.next(), numbers.next())
+            {
+                let ratio = u64::from(first) * u64::from(second);
+                let at = Coordinate { x: x as i64, y };
+                self.found.push_back(Found::Gear { ratio, at });
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<R: BufRead> Iterator for Stream<R> {
+    type Item = Result<Found, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(found) = self.found.pop_front() {
+                return Some(Ok(found));
+            }
+            if self.done {
+                return None;
+            }
+            match self.advance() {
+                Ok(more) => self.done = !more,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+/// The sum of the part numbers and the sum of the gear ratios of a schematic, streamed.
+pub fn stream_sums(reader: impl BufRead) -> Result<(u64, u64), StreamError> {
+    let mut sums = (0, 0);
+    for found in stream(reader) {
+        match found? {
+            Found::PartNumber { value, .. } => sums.0 += u64::from(value),
+            Found::Gear { ratio, .. } => sums.1 += ratio,
+        }
+    }
+    Ok(sums)
+}
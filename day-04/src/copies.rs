@@ -0,0 +1,197 @@
+use std::{error::Error, fmt};
+
+use aoc_common::ParseError;
+
+use crate::Card;
+
+/// Cards are named by their ids, and found on their `line`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyError {
+    /// A card wins copies of more cards than follow it.
+    PastLastCard {
+        card: usize,
+        line: usize,
+        matches: usize,
+        following: usize,
+    },
+    /// There are more copies than fit in a `u128`, from `card` on.
+    Overflow { card: usize, line: usize },
+}
+
+impl CopyError {
+    pub(crate) fn overflow(cards: &[Card], index: usize) -> Self {
+        CopyError::Overflow {
+            card: cards[index].id,
+            line: index + 1,
+        }
+    }
+}
+
+impl fmt::Display for CopyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CopyError::PastLastCard {
+                card,
+                matches,
+                following,
+                ..
+            } => write!(
+                f,
+                "card {card} wins copies of the next {matches} cards, but only {following} follow it"
+            ),
+            CopyError::Overflow { card, .. } => {
+                write!(f, "too many copies of card {card} to count")
+            }
+        }
+    }
+}
+
+impl Error for CopyError {}
+
+/// Points at the line of the card, for the runner to report along with malformed input.
+impl From<CopyError> for ParseError {
+    fn from(error: CopyError) -> Self {
+        match error {
+            CopyError::PastLastCard {
+                line,
+                matches,
+                following,
+                ..
+            } => ParseError::new(
+                1,
+                format!("at most {following} matching numbers"),
+                format!("{matches}"),
+            )
+            .on_line(line),
+            CopyError::Overflow { line, .. } => {
+                ParseError::new(1, "fewer copies", "too many to count").on_line(line)
+            }
+        }
+    }
+}
+
+/// How many of each card there are once every card has won its copies.
+///
+/// Every card adds its count to a run of the cards after it, so the counts it adds start at
+/// the next card and stop past the last card it wins, which takes a single pass.
+pub fn count_copies(cards: &[Card]) -> Result<Vec<u128>, CopyError> {
+    let mut expiring = vec![0u128; cards.len() + 1];
+    let mut added = 0u128;
+    let mut counts = Vec::with_capacity(cards.len());
+
+    for (index, card) in cards.iter().enumerate() {
+        let overflow = || CopyError::overflow(cards, index);
+        added -= expiring[index];
+        let count = added.checked_add(1).ok_or_else(overflow)?;
+        let matches = card.matches;
+        if matches > 0 {
+            if index + matches >= cards.len() {
+                return Err(CopyError::PastLastCard {
+                    card: card.id,
+                    line: index + 1,
+                    matches,
+                    following: cards.len() - index - 1,
+                });
+            }
+            added = added.checked_add(count).ok_or_else(overflow)?;
+            expiring[index + matches + 1] += count;
+        }
+        counts.push(count);
+    }
+    Ok(counts)
+}
+
+pub fn sum_copy_scratchcards(cards: &[Card]) -> Result<u128, CopyError> {
+    let counts = count_copies(cards)?;
+    counts
+        .iter()
+        .try_fold(0u128, |sum, count| sum.checked_add(*count))
+        .ok_or_else(|| CopyError::overflow(cards, cards.len() - 1))
+}
+
+/// The copies of a card, and the earlier cards that won them, by their ids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    pub card: usize,
+    /// The original included.
+    pub count: u128,
+    /// Earlier cards with the copies each of them won.
+    pub won_from: Vec<(usize, u128)>,
+}
+
+/// Where the copies of every card came from. Each earlier card wins one copy for each of
+/// its own copies.
+pub fn copy_report(cards: &[Card]) -> Result<Vec<Cascade>, CopyError> {
+    let counts = count_copies(cards)?;
+    let mut report: Vec<Cascade> = counts
+        .iter()
+        .zip(cards)
+        .map(|(count, card)| Cascade {
+            card: card.id,
+            count: *count,
+            won_from: Vec::new(),
+        })
+        .collect();
+    for (index, card) in cards.iter().enumerate() {
+        for won in &mut report[index + 1..=index + card.matches] {
+            won.won_from.push((card.id, counts[index]));
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn reports_where_copies_came_from() {
+        let report = copy_report(&parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(
+            report[3],
+            Cascade {
+                card: 4,
+                count: 8,
+                won_from: vec![(1, 1), (2, 2), (3, 4)],
+            }
+        );
+        assert_eq!(report[5].won_from, vec![]);
+        let counts: Vec<u128> = report.iter().map(|cascade| cascade.count).collect();
+        assert_eq!(counts, vec![1, 2, 4, 8, 14, 1]);
+    }
+
+    #[test]
+    fn refuses_copies_past_the_last_card() {
+        let cards = parse("Card 1: 1 2 | 1\nCard 2: 1 2 | 1 2").unwrap();
+        let error = sum_copy_scratchcards(&cards).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "card 2 wins copies of the next 2 cards, but only 0 follow it"
+        );
+        assert_eq!(
+            ParseError::from(error).to_string(),
+            "line 2, column 1: expected at most 0 matching numbers, found 2"
+        );
+    }
+
+    #[test]
+    fn reports_overflow() {
+        // Every card wins the next two, so the counts grow like the Fibonacci numbers
+        let mut lines = vec!["Card 1: 1 2 | 1 2"; 200];
+        lines.extend(["Card 2: 1 | 1", "Card 3: 1 | 2"]);
+        let cards = parse(&lines.join("\n")).unwrap();
+        let error = count_copies(&cards).unwrap_err();
+        assert!(matches!(error, CopyError::Overflow { line, .. } if line > 150));
+
+        let cards = parse(&lines[50..].join("\n")).unwrap();
+        assert!(sum_copy_scratchcards(&cards).is_ok());
+    }
+}
@@ -0,0 +1,381 @@
+use std::{collections::HashSet, fmt};
+
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Solver};
+
+mod copies;
+pub use copies::{copy_report, count_copies, sum_copy_scratchcards, Cascade, CopyError};
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+    type Parsed<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let cards = parse(input)?;
+        for warning in warnings(&cards) {
+            aoc_common::diagnostic!("Warning: {warning}");
+        }
+        Ok(cards)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed)?.into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        let sum = part2(parsed)?;
+        let sum = i128::try_from(sum).map_err(|_| CopyError::overflow(parsed, parsed.len() - 1))?;
+        Ok(Some(Answer::Number(sum)))
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(data, Card::parse_line)
+}
+
+/// Writes the cards with their numbers in the order they were read.
+pub fn render(cards: &[Card]) -> String {
+    let numbers = |numbers: &[u32]| {
+        numbers
+            .iter()
+            .map(|number| format!("{number:>2}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    cards
+        .iter()
+        .map(|card| {
+            format!(
+                "Card {}: {} | {}",
+                card.id,
+                numbers(&card.winning_numbers),
+                numbers(&card.numbers_you_have)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(cards: &[Card]) -> Result<u64, ParseError> {
+    cards
+        .iter()
+        .enumerate()
+        .try_fold(0u64, |sum, (index, card)| {
+            let score = card
+                .calculate_score()
+                .map_err(|error| error.on_line(index + 1))?;
+            sum.checked_add(score).ok_or_else(|| {
+                ParseError::new(1, "fewer matching numbers", "too many points to add up")
+                    .on_line(index + 1)
+            })
+        })
+}
+
+pub fn part2(cards: &[Card]) -> Result<u128, CopyError> {
+    sum_copy_scratchcards(cards)
+}
+
+/// Something odd about the cards that doesn't keep them from being scored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// A number listed more than once on the same side of a card, which counts once.
+    DuplicateNumber { card: usize, number: u32 },
+    /// A card with another id than the one after the card before it.
+    UnexpectedId { expected: usize, found: usize },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::DuplicateNumber { card, number } => {
+                write!(f, "card {card} lists {number} more than once")
+            }
+            Warning::UnexpectedId { expected, found } => {
+                write!(f, "expected card {expected}, found card {found}")
+            }
+        }
+    }
+}
+
+/// Duplicate numbers and ids out of sequence, in the order of the cards.
+pub fn warnings(cards: &[Card]) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for (index, card) in cards.iter().enumerate() {
+        let expected = match index {
+            0 => 1,
+            _ => cards[index - 1].id + 1,
+        };
+        if card.id != expected {
+            warnings.push(Warning::UnexpectedId {
+                expected,
+                found: card.id,
+            });
+        }
+        for numbers in [&card.winning_numbers, &card.numbers_you_have] {
+            let mut seen = HashSet::new();
+            for number in numbers {
+                if !seen.insert(number) {
+                    warnings.push(Warning::DuplicateNumber {
+                        card: card.id,
+                        number: *number,
+                    });
+                }
+            }
+        }
+    }
+    warnings
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Card {
+    pub id: usize,
+    winning_numbers: Vec<u32>,
+    numbers_you_have: Vec<u32>,
+    /// How many different numbers you have are winning ones.
+    pub matches: usize,
+}
+
+impl Card {
+    pub fn new(id: usize, winning_numbers: Vec<u32>, numbers_you_have: Vec<u32>) -> Self {
+        let winning: HashSet<&u32> = winning_numbers.iter().collect();
+        let have: HashSet<&u32> = numbers_you_have.iter().collect();
+        let matches = winning.intersection(&have).count();
+        Self {
+            id,
+            winning_numbers,
+            numbers_you_have,
+            matches,
+        }
+    }
+
+    pub fn winning_numbers(&self) -> &[u32] {
+        &self.winning_numbers
+    }
+
+    pub fn numbers_you_have(&self) -> &[u32] {
+        &self.numbers_you_have
+    }
+
+    /// Past 64 matches the score no longer fits in a `u64`.
+    pub fn calculate_score(&self) -> Result<u64, ParseError> {
+        match self.matches {
+            0 => Ok(0),
+            matches => u32::try_from(matches - 1)
+                .ok()
+                .and_then(|shift| 1u64.checked_shl(shift))
+                .ok_or_else(|| {
+                    ParseError::new(1, "at most 64 matching numbers", format!("{matches}"))
+                }),
+        }
+    }
+
+    pub fn parse_line(line: &str) -> Result<Self, ParseError> {
+        let (header, content) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::end_of(line, "':'"))?;
+        let id = match header.split_whitespace().last() {
+            Some(id) => parse_number(line, id)?,
+            None => return Err(ParseError::at(line, header, "a card id")),
+        };
+        let (winning, owned) = content
+            .split_once('|')
+            .ok_or_else(|| ParseError::end_of(line, "'|'"))?;
+        let parse_numbers = |numbers: &str| {
+            numbers
+                .split_whitespace()
+                .map(|num_string| parse_number::<u32>(line, num_string))
+                .collect::<Result<Vec<u32>, ParseError>>()
+        };
+        Ok(Self::new(
+            id,
+            parse_numbers(winning)?,
+            parse_numbers(owned)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// A card, along with how many of its numbers are winning ones.
+    fn card(id: usize) -> impl Strategy<Value = (String, usize)> {
+        let winning = proptest::collection::hash_set(1..100u32, 1..10);
+        (winning, proptest::collection::hash_set(1..100u32, 1..25)).prop_map(
+            move |(winning, have)| {
+                let matches = winning.intersection(&have).count();
+                let join = |numbers: HashSet<u32>| {
+                    let numbers: Vec<String> = numbers
+                        .iter()
+                        .map(|number| format!("{number:>2}"))
+                        .collect();
+                    numbers.join(" ")
+                };
+                (
+                    format!("Card {id:>3}: {} | {}", join(winning), join(have)),
+                    matches,
+                )
+            },
+        )
+    }
+
+    fn cards() -> impl Strategy<Value = (String, Vec<usize>)> {
+        (1..30usize)
+            .prop_flat_map(|count| (1..=count).map(card).collect::<Vec<_>>())
+            .prop_map(|cards| {
+                let (lines, matches): (Vec<String>, _) = cards.into_iter().unzip();
+                (lines.join("\n"), matches)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn counts_generated_matches((input, matches) in cards()) {
+            let cards = parse(&input).unwrap();
+            let found: Vec<usize> = cards.iter().map(|card| card.matches).collect();
+            prop_assert_eq!(found, matches);
+        }
+
+        #[test]
+        fn round_trips((input, _) in cards()) {
+            let cards = parse(&input).unwrap();
+            prop_assert_eq!(parse(&render(&cards)).unwrap(), cards);
+        }
+    }
+
+    #[test]
+    fn sums_copies_correctly() {
+        let cards: Vec<Card> = [4, 2, 2, 1, 0, 0]
+            .into_iter()
+            .enumerate()
+            .map(|(index, matches)| Card {
+                id: index + 1,
+                winning_numbers: vec![],
+                numbers_you_have: vec![],
+                matches,
+            })
+            .collect();
+
+        assert_eq!(sum_copy_scratchcards(&cards), Ok(30));
+        assert_eq!(count_copies(&cards), Ok(vec![1, 2, 4, 8, 14, 1]));
+    }
+
+    #[test]
+    fn copies_past_card_255() {
+        let mut lines: Vec<String> = (1..=300).map(|id| format!("Card {id}: 1 | 2")).collect();
+        lines[254] = "Card 255: 1 2 | 1 2".to_string();
+        let cards = parse(&lines.join("\n")).unwrap();
+        let counts = count_copies(&cards).unwrap();
+        assert_eq!((counts[255], counts[256], counts[0]), (2, 2, 1));
+        assert_eq!(sum_copy_scratchcards(&cards), Ok(302));
+    }
+
+    #[test]
+    fn parses_line() {
+        let line = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+
+        let card = Card::parse_line(line).unwrap();
+
+        assert_eq!(
+            card,
+            Card {
+                id: 1,
+                winning_numbers: vec![41, 48, 83, 86, 17],
+                numbers_you_have: vec![83, 86, 6, 31, 17, 9, 48, 53],
+                matches: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn reports_malformed_cards() {
+        let error = parse("Card 1: 41 48 | 83 86\nCard 2: 41 4B | 83").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 12: expected a number, found \"4B\""
+        );
+
+        let error = Card::parse_line("Card 3: 41 48 83").unwrap_err();
+        assert_eq!(error.expected, "'|'");
+
+        let error = Card::parse_line("Card x: 41 | 48").unwrap_err();
+        assert_eq!(error.column, 6);
+    }
+
+    #[test]
+    fn warns_about_duplicates_and_gaps() {
+        let cards = parse("Card 1: 41 48 41 | 41 41\nCard 3: 1 | 2\nCard 4: 1 | 2").unwrap();
+        assert_eq!(cards[0].matches, 1);
+        assert_eq!(
+            warnings(&cards),
+            vec![
+                Warning::DuplicateNumber {
+                    card: 1,
+                    number: 41
+                },
+                Warning::DuplicateNumber {
+                    card: 1,
+                    number: 41
+                },
+                Warning::UnexpectedId {
+                    expected: 2,
+                    found: 3
+                },
+            ]
+        );
+        assert_eq!(
+            warnings(&cards)[2].to_string(),
+            "expected card 2, found card 3"
+        );
+    }
+
+    #[test]
+    fn calculates_score() {
+        let card = Card::new(
+            1,
+            vec![41, 48, 83, 86, 17],
+            vec![83, 86, 6, 31, 17, 9, 48, 53],
+        );
+        assert_eq!(card.calculate_score(), Ok(8));
+
+        let card = Card::new(
+            2,
+            vec![41, 92, 73, 84, 69],
+            vec![59, 84, 76, 51, 58, 5, 54, 83],
+        );
+        assert_eq!(card.calculate_score(), Ok(1));
+
+        let card = Card::new(
+            3,
+            vec![31, 18, 13, 56, 72],
+            vec![74, 77, 10, 23, 35, 67, 36, 11],
+        );
+        assert_eq!(card.calculate_score(), Ok(0));
+
+        let numbers: Vec<u32> = (0..100).collect();
+        let card = Card::new(4, numbers.clone(), numbers);
+        let error = card.calculate_score().unwrap_err();
+        assert_eq!(error.expected, "at most 64 matching numbers");
+        assert_eq!(error.found, "100");
+    }
+
+    #[test]
+    fn reports_scores_too_large_to_add_up() {
+        let numbers: Vec<u32> = (0..64).collect();
+        let cards = vec![
+            Card::new(1, numbers.clone(), numbers.clone()),
+            Card::new(2, numbers.clone(), numbers.clone()),
+        ];
+        assert_eq!(
+            part1(&cards).unwrap_err().to_string(),
+            "line 2, column 1: expected fewer matching numbers, found too many points to add up"
+        );
+
+        let numbers: Vec<u32> = (0..65).collect();
+        let cards = vec![Card::new(1, numbers.clone(), numbers)];
+        assert_eq!(part1(&cards).unwrap_err().line, 1);
+    }
+}
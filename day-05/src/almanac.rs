@@ -0,0 +1,392 @@
+use std::{fmt, ops::Range};
+
+use aoc_common::{parse_number, ParseError};
+
+/// Where a run of sources ends up, `source..source + length` going to `destination..`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Shift {
+    destination: u64,
+    source: u64,
+    length: u64,
+}
+
+impl Shift {
+    fn sources(&self) -> Range<u64> {
+        self.source..self.source + self.length
+    }
+
+    fn apply(&self, value: u64) -> u64 {
+        value - self.source + self.destination
+    }
+}
+
+/// A `source-to-destination map:` section, shifts sorted by their sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    pub source: String,
+    pub destination: String,
+    shifts: Vec<Shift>,
+    /// The shifts in the order the almanac lists them, to write them back.
+    listed: Vec<Shift>,
+}
+
+impl Map {
+    /// Values no shift covers stay what they are.
+    pub fn map(&self, value: u64) -> u64 {
+        let index = self.shifts.partition_point(|shift| shift.source <= value);
+        match index.checked_sub(1).map(|index| &self.shifts[index]) {
+            Some(shift) if shift.sources().contains(&value) => shift.apply(value),
+            _ => value,
+        }
+    }
+
+    /// Maps whole ranges at once, splitting them where they cross the edges of the shifts.
+    pub fn map_ranges(&self, ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut mapped = Vec::new();
+        for range in ranges.iter().filter(|range| !range.is_empty()) {
+            let mut start = range.start;
+            let first = self
+                .shifts
+                .partition_point(|shift| shift.source + shift.length <= start);
+            for shift in &self.shifts[first..] {
+                if shift.source >= range.end {
+                    break;
+                }
+                if start < shift.source {
+                    mapped.push(start..shift.source);
+                    start = shift.source;
+                }
+                let end = range.end.min(shift.source + shift.length);
+                mapped.push(shift.apply(start)..shift.apply(end - 1) + 1);
+                start = end;
+            }
+            if start < range.end {
+                mapped.push(start..range.end);
+            }
+        }
+        mapped
+    }
+}
+
+/// The seeds to plant and the maps leading from seeds to locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines().enumerate().peekable();
+        let (_, first) = lines.next().unwrap_or((0, ""));
+        let seeds = first
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::at(first, first, "\"seeds:\"").on_line(1))?;
+        let seeds = seeds
+            .split_whitespace()
+            .map(|seed| parse_number(first, seed))
+            .collect::<Result<Vec<u64>, ParseError>>()
+            .map_err(|error| error.on_line(1))?;
+
+        let mut maps: Vec<Map> = Vec::new();
+        while let Some((index, line)) = lines.next() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let expected_source = maps.last().map_or("seed", |map| map.destination.as_str());
+            let (source, destination) =
+                parse_header(line, expected_source).map_err(|error| error.on_line(index + 1))?;
+
+            let mut listed = Vec::new();
+            while let Some((index, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+                let shift = parse_shift(line).map_err(|error| error.on_line(index + 1))?;
+                listed.push(shift);
+            }
+            // A shift of no values maps nothing, and has no last value to map ranges with
+            let mut shifts: Vec<Shift> = listed
+                .iter()
+                .copied()
+                .filter(|shift| shift.length > 0)
+                .collect();
+            shifts.sort_by_key(|shift| shift.source);
+            if let Some(pair) = shifts
+                .windows(2)
+                .find(|pair| pair[0].sources().end > pair[1].source)
+            {
+                let line = listed.iter().position(|shift| *shift == pair[1]).unwrap() + index + 2;
+                return Err(ParseError::new(
+                    1,
+                    "sources that don't overlap those of other lines",
+                    format!("{} overlapping", pair[1].source),
+                )
+                .on_line(line));
+            }
+            maps.push(Map {
+                source: source.to_string(),
+                destination: destination.to_string(),
+                shifts,
+                listed,
+            });
+        }
+        Ok(Self { seeds, maps })
+    }
+
+    pub fn maps(&self) -> &[Map] {
+        &self.maps
+    }
+
+    /// Where a seed ends up once every map is applied.
+    pub fn location(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |value, map| map.map(value))
+    }
+
+    pub fn lowest_location(&self) -> Option<u64> {
+        self.seeds.iter().map(|seed| self.location(*seed)).min()
+    }
+
+    /// The seeds read as pairs of a start and a length, `None` for an odd number of them.
+    pub fn seed_ranges(&self) -> Option<Vec<Range<u64>>> {
+        if self.seeds.len() % 2 == 1 {
+            return None;
+        }
+        let ranges = self
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0].saturating_add(pair[1]));
+        Some(ranges.collect())
+    }
+
+    /// The lowest location of any seed in `ranges`, taking the ranges through the maps whole.
+    pub fn lowest_location_of(&self, ranges: &[Range<u64>]) -> Option<u64> {
+        let locations = self
+            .maps
+            .iter()
+            .fold(ranges.to_vec(), |ranges, map| map.map_ranges(&ranges));
+        locations.iter().map(|range| range.start).min()
+    }
+}
+
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seeds: Vec<String> = self.seeds.iter().map(u64::to_string).collect();
+        write!(f, "seeds: {}", seeds.join(" "))?;
+        for map in &self.maps {
+            write!(f, "\n\n{}-to-{} map:", map.source, map.destination)?;
+            for shift in &map.listed {
+                write!(
+                    f,
+                    "\n{} {} {}",
+                    shift.destination, shift.source, shift.length
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_header<'a>(
+    line: &'a str,
+    expected_source: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    let name = line
+        .strip_suffix(" map:")
+        .ok_or_else(|| ParseError::end_of(line, "\" map:\""))?;
+    let (source, destination) = name
+        .split_once("-to-")
+        .ok_or_else(|| ParseError::at(line, name, "a name like \"seed-to-soil\""))?;
+    if source != expected_source {
+        return Err(ParseError::at(
+            line,
+            source,
+            format!("\"{expected_source}\""),
+        ));
+    }
+    Ok((source, destination))
+}
+
+fn parse_shift(line: &str) -> Result<Shift, ParseError> {
+    let numbers = line
+        .split_whitespace()
+        .map(|number| parse_number(line, number))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    let [destination, source, length] = numbers[..] else {
+        return Err(ParseError::at(
+            line,
+            line,
+            "a destination, a source and a length",
+        ));
+    };
+    if source.checked_add(length).is_none() || destination.checked_add(length).is_none() {
+        return Err(ParseError::at(line, line, "ranges that end before 2^64"));
+    }
+    Ok(Shift {
+        destination,
+        source,
+        length,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn maps_seeds_to_locations() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        let locations: Vec<u64> = almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.location(*seed))
+            .collect();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+        assert_eq!(almanac.lowest_location(), Some(35));
+    }
+
+    #[test]
+    fn maps_seed_ranges() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        let ranges = almanac.seed_ranges().unwrap();
+        assert_eq!(ranges, vec![79..93, 55..68]);
+        assert_eq!(almanac.lowest_location_of(&ranges), Some(46));
+    }
+
+    #[test]
+    fn splits_ranges_on_shift_edges() {
+        let almanac = Almanac::parse("seeds: 1\n\nseed-to-soil map:\n100 10 5\n200 15 5").unwrap();
+        let map = &almanac.maps()[0];
+        assert_eq!(
+            map.map_ranges(&[0..30, 40..45]),
+            vec![0..10, 100..105, 200..205, 20..30, 40..45]
+        );
+        assert_eq!(map.map_ranges(&[12..17, 5..5]), vec![102..105, 200..202]);
+    }
+
+    #[test]
+    fn ignores_empty_shifts() {
+        let almanac =
+            Almanac::parse("seeds: 0 30\n\nseed-to-soil map:\n100 10 0\n200 10 5").unwrap();
+        assert_eq!(almanac.location(10), 200);
+        assert_eq!(almanac.lowest_location(), Some(0));
+        assert_eq!(almanac.lowest_location_of(&[0..30, 10..10]), Some(0));
+        assert_eq!(
+            almanac.maps()[0].map_ranges(&[8..12, 40..41]),
+            vec![8..10, 200..202, 40..41]
+        );
+    }
+
+    #[test]
+    fn reports_malformed_almanacs() {
+        let error = Almanac::parse("seeds: 1 x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 10: expected a number, found \"x\""
+        );
+
+        let error = Almanac::parse("seeds: 1\n\nsoil-to-water map:\n1 2 3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected \"seed\", found \"soil\""
+        );
+
+        let error = Almanac::parse("seeds: 1\n\nseed-to-soil map:\n1 2").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (4, "a destination, a source and a length")
+        );
+
+        let error = Almanac::parse("seeds: 1\n\nseed-to-soil map:\n1 2 3\n10 4 3").unwrap_err();
+        assert_eq!(error.line, 5);
+
+        let error =
+            Almanac::parse("seeds: 1\n\nseed-to-soil map:\n1 18446744073709551615 3").unwrap_err();
+        assert_eq!(error.expected, "ranges that end before 2^64");
+    }
+
+    /// An almanac of small numbers with maps that don't overlap, chained from seeds.
+    fn almanac() -> impl Strategy<Value = String> {
+        let shift = (0..200u64, 0..20u64, 0..20u64);
+        let map = proptest::collection::vec(shift, 0..5).prop_map(|shifts| {
+            // Spread the sources apart so they never overlap
+            let lines: Vec<String> = shifts
+                .iter()
+                .enumerate()
+                .map(|(index, (destination, offset, length))| {
+                    format!("{destination} {} {length}", index as u64 * 40 + offset)
+                })
+                .collect();
+            lines
+        });
+        let names = ["seed", "soil", "fertilizer", "water", "location"];
+        (
+            proptest::collection::vec(0..250u64, 0..4),
+            proptest::collection::vec(map, 1..5),
+        )
+            .prop_map(move |(seeds, maps)| {
+                let seeds: Vec<String> = seeds.iter().map(u64::to_string).collect();
+                let mut input = format!("seeds: {}", seeds.join(" "));
+                for (index, lines) in maps.iter().enumerate() {
+                    input.push_str(&format!(
+                        "\n\n{}-to-{} map:",
+                        names[index],
+                        names[index + 1]
+                    ));
+                    for line in lines {
+                        input.push('\n');
+                        input.push_str(line);
+                    }
+                }
+                input
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in almanac()) {
+            prop_assert_eq!(Almanac::parse(&input).unwrap().to_string(), input);
+        }
+
+        #[test]
+        fn maps_ranges_like_every_seed(input in almanac(), start in 0..250u64, length in 0..50u64) {
+            let almanac = Almanac::parse(&input).unwrap();
+            let range = start..start + length;
+            let lowest = range.clone().map(|seed| almanac.location(seed)).min();
+            prop_assert_eq!(almanac.lowest_location_of(&[range]), lowest);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+mod almanac;
+mod warehouse;
+
+pub use almanac::{Almanac, Map};
+use aoc_common::{Answer, ParseError, Solver};
+pub use warehouse::{Capacity, CraneError, CraneModel, CrateMover9000, CrateMover9001, Warehouse};
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+    type Parsed<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(part1(parsed).map(Into::into))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(part2(parsed)?.map(Into::into))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    Almanac::parse(input)
+}
+
+pub fn render(almanac: &Almanac) -> String {
+    almanac.to_string()
+}
+
+/// `None` without seeds.
+pub fn part1(almanac: &Almanac) -> Option<u64> {
+    almanac.lowest_location()
+}
+
+/// Reads the seeds as ranges, which takes an even number of them.
+pub fn part2(almanac: &Almanac) -> Result<Option<u64>, ParseError> {
+    let ranges = almanac.seed_ranges().ok_or_else(|| {
+        ParseError::new(
+            1,
+            "pairs of a start and a length",
+            format!("{} seeds", almanac.seeds.len()),
+        )
+        .on_line(1)
+    })?;
+    Ok(almanac.lowest_location_of(&ranges))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn needs_pairs_of_seeds() {
+        let almanac = parse("seeds: 1 2 3\n\nseed-to-soil map:\n10 0 5").unwrap();
+        assert_eq!(part1(&almanac), Some(11));
+        let error = part2(&almanac).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected pairs of a start and a length, found 3 seeds"
+        );
+    }
+}
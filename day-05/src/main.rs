@@ -1,9 +1,46 @@
-mod warehouse;
-use std::fs;
+use std::{env, error::Error, fs};
 
-use warehouse::Warehouse;
-fn main() {
-    let contents: String = fs::read_to_string("data.txt").expect("Should have been able to read the file");
-    let warehouse = Warehouse::from(&contents).start_crane();
-    println!("Top crates are: {}", warehouse.read_top());
+use advent_2022_05::{parse, part1, part2, CrateMover9000, CrateMover9001, Warehouse};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let contents: String =
+        fs::read_to_string("data.txt").expect("Should have been able to read the file");
+    let flags: Vec<String> = env::args().skip(1).collect();
+    if flags.iter().any(|flag| flag == "--warehouse") {
+        let warehouse = Warehouse::from(&contents)?;
+        if flags.iter().any(|flag| flag == "--dry-run") {
+            let errors = warehouse.dry_run();
+            for error in &errors {
+                eprintln!("{error}");
+            }
+            println!("{} of the moves can't be made", errors.len());
+            return Ok(());
+        }
+        if flags.iter().any(|flag| flag == "--replay") {
+            let mut replay = warehouse.clone();
+            println!("{}", replay.draw(0).unwrap_or_default());
+            while replay.step(&CrateMover9001)? {
+                println!(
+                    "\nafter move {}:\n{}",
+                    replay.position(),
+                    replay.draw(replay.position()).unwrap_or_default()
+                );
+            }
+            return Ok(());
+        }
+        let top = warehouse.clone().start_crane(&CrateMover9000)?.read_top();
+        println!("Top crates with the CrateMover 9000: {top}");
+        let top = warehouse.start_crane(&CrateMover9001)?.read_top();
+        println!("Top crates with the CrateMover 9001: {top}");
+        return Ok(());
+    }
+    let almanac = parse(&contents)?;
+    match part1(&almanac) {
+        Some(location) => println!("Lowest location of the seeds: {location}"),
+        None => println!("The almanac lists no seeds"),
+    }
+    if let Some(location) = part2(&almanac)? {
+        println!("Lowest location of the seed ranges: {location}");
+    }
+    Ok(())
 }
@@ -1,46 +1,280 @@
+mod crane_models;
 mod giant_cargo_crane;
 mod splitter;
 mod stack_of_crates;
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
+use aoc_common::ParseError;
+
+pub use crane_models::{Capacity, CraneModel, CrateMover9000, CrateMover9001};
 use giant_cargo_crane::Crane;
+pub use giant_cargo_crane::CraneError;
 use splitter::Inits;
 
-use self::stack_of_crates::{load_stacks, Stack};
+use self::stack_of_crates::{draw_stacks, load_stacks, Stack};
 
+/// Stacks of crates with the moves to make, and the stacks after every move made so far.
+#[derive(Debug, PartialEq, Clone)]
 pub struct Warehouse {
     crane: Crane,
-    stacks: HashMap<u8, Stack>,
+    /// The stacks before any move, then after each of them, including undone ones.
+    states: Vec<HashMap<usize, Stack>>,
+    /// How many moves are made, indexing `states`.
+    position: usize,
 }
 
 impl Warehouse {
-    pub fn from(input: &str) -> Warehouse {
-        let inits = Inits::from(input);
-        let stacks = load_stacks(&inits.stacks_map);
-        Warehouse {
-            crane: Crane::from(&inits.crane_instructions),
-            stacks,
+    pub fn from(input: &str) -> Result<Warehouse, ParseError> {
+        let inits = Inits::from(input)?;
+        let stacks = load_stacks(&inits.stacks_map)?;
+        let crane = Crane::from(&inits.crane_instructions).map_err(|error| {
+            let line = error.line + inits.instructions_offset();
+            error.on_line(line)
+        })?;
+        Ok(Warehouse {
+            crane,
+            states: vec![stacks],
+            position: 0,
+        })
+    }
+
+    fn stacks(&self) -> &HashMap<usize, Stack> {
+        &self.states[self.position]
+    }
+
+    /// Runs the instructions left with a crane of the given model, stopping at the first
+    /// move it can't make.
+    pub fn start_crane(mut self, model: &dyn CraneModel) -> Result<Warehouse, CraneError> {
+        while self.step(model)? {}
+        Ok(self)
+    }
+
+    /// Makes the next move, `false` once there is none left. Moves undone before are
+    /// dropped from the history.
+    pub fn step(&mut self, model: &dyn CraneModel) -> Result<bool, CraneError> {
+        if self.position == self.crane.len() {
+            return Ok(false);
         }
+        let stacks = self.stacks().clone();
+        let stacks = self.crane.execute(self.position, model, stacks)?;
+        self.states.truncate(self.position + 1);
+        self.states.push(stacks);
+        self.position += 1;
+        Ok(true)
+    }
+
+    /// Takes back the last move, `false` when there is none.
+    pub fn undo(&mut self) -> bool {
+        let undone = self.position > 0;
+        self.position -= usize::from(undone);
+        undone
+    }
+
+    /// Makes the last undone move again, `false` when there is none.
+    pub fn redo(&mut self) -> bool {
+        let redone = self.position + 1 < self.states.len();
+        self.position += usize::from(redone);
+        redone
+    }
+
+    /// How many moves are made.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Draws the stacks after `moves` moves, as long as the history holds them.
+    pub fn draw(&self, moves: usize) -> Option<String> {
+        self.states.get(moves).map(draw_stacks)
     }
 
-    pub fn start_crane(mut self) -> Warehouse {
-        self.stacks = self.crane.execute_instructions(self.stacks);
-        self
+    /// Every move the crane couldn't make from here on, leaving the stacks as they are.
+    pub fn dry_run(&self) -> Vec<CraneError> {
+        self.crane.dry_run(self.position, self.stacks())
     }
 
+    /// The top crate of each stack in order, a space for an empty stack.
     pub fn read_top(self) -> String {
-        let mut top_crates = String::new();
-        for id in 1..(self.stacks.len() + 1) {
-            top_crates.push(
-                self.stacks
-                    .get(&u8::try_from(id).unwrap())
-                    .unwrap()
-                    .crates
-                    .chars()
-                    .last()
-                    .unwrap(),
-            );
+        let mut stacks: Vec<&Stack> = self.stacks().values().collect();
+        stacks.sort_by_key(|stack| stack.id);
+        stacks
+            .iter()
+            .map(|stack| stack.crates.chars().last().unwrap_or(' '))
+            .collect()
+    }
+}
+
+/// The stacks as they are now, followed by the moves left to make.
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining = self.crane.remaining(self.position);
+        write!(f, "{}\n\n{remaining}", draw_stacks(self.stacks()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn reads_top_by_crane_model() {
+        let warehouse = Warehouse::from(EXAMPLE).unwrap();
+        let top = |model: &dyn CraneModel| warehouse.clone().start_crane(model).unwrap().read_top();
+        assert_eq!(top(&CrateMover9000), "CMZ");
+        assert_eq!(top(&CrateMover9001), "MCD");
+        let limited = Capacity {
+            model: CrateMover9001,
+            limit: std::num::NonZeroUsize::new(2).unwrap(),
+        };
+        assert_eq!(top(&limited), "MCZ");
+    }
+
+    #[test]
+    fn reports_impossible_moves() {
+        let input = "[A]\n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\nmove 1 from 3 to 1";
+        let warehouse = Warehouse::from(input).unwrap();
+        let errors: Vec<String> = warehouse
+            .dry_run()
+            .iter()
+            .map(CraneError::to_string)
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "instruction 2: moves 2 crates from stack 2, but only 1 are there",
+                "instruction 3: there is no stack 3",
+            ]
+        );
+
+        let error = warehouse.clone().start_crane(&CrateMover9000).unwrap_err();
+        assert_eq!(
+            error,
+            CraneError::NotEnoughCrates {
+                instruction: 1,
+                from: 2,
+                wanted: 2,
+                available: 1
+            }
+        );
+        let emptied = Warehouse::from("[A]\n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+        assert_eq!(
+            emptied.start_crane(&CrateMover9001).unwrap().read_top(),
+            " A"
+        );
+    }
+
+    #[test]
+    fn replays_moves() {
+        let mut warehouse = Warehouse::from(EXAMPLE).unwrap();
+        assert!(!warehouse.undo());
+        assert!(warehouse.step(&CrateMover9000).unwrap());
+        assert!(warehouse.step(&CrateMover9000).unwrap());
+        let second = "        [Z]
+        [N]
+    [C] [D]
+    [M] [P]
+ 1   2   3 ";
+        assert_eq!(warehouse.draw(2).unwrap(), second);
+        assert_eq!(
+            warehouse.to_string(),
+            format!("{second}\n\nmove 2 from 2 to 1\nmove 1 from 1 to 2")
+        );
+
+        assert!(warehouse.undo());
+        assert_eq!(warehouse.position(), 1);
+        assert_eq!(
+            warehouse.draw(0).unwrap(),
+            EXAMPLE.split("\n\n").next().unwrap()
+        );
+        assert!(warehouse.redo());
+        assert!(!warehouse.redo());
+        assert_eq!(warehouse.position(), 2);
+
+        let finished = warehouse.start_crane(&CrateMover9000).unwrap();
+        assert_eq!(finished.position(), 4);
+        assert_eq!(
+            finished.to_string(),
+            "        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3 \n\n"
+        );
+        assert_eq!(
+            Warehouse::from(&finished.to_string()).unwrap().read_top(),
+            "CMZ"
+        );
+    }
+
+    /// A drawing of up to 12 stacks, followed by moves between them.
+    fn warehouse() -> impl Strategy<Value = String> {
+        proptest::collection::vec("[A-Z]{0,6}", 1..=12).prop_flat_map(|stacks| {
+            let count = stacks.len();
+            let instruction = (1..6usize, 1..=count, 1..=count);
+            let instructions = proptest::collection::vec(instruction, 1..10);
+            (Just(stacks), instructions).prop_map(|(stacks, instructions)| {
+                let height = stacks.iter().map(String::len).max().unwrap();
+                let mut lines: Vec<String> = (0..height)
+                    .rev()
+                    .map(|level| {
+                        let cells: Vec<String> = stacks
+                            .iter()
+                            .map(|crates| match crates.chars().nth(level) {
+                                Some(wooden_crate) => format!("[{wooden_crate}]"),
+                                None => "   ".to_string(),
+                            })
+                            .collect();
+                        cells.join(" ")
+                    })
+                    .collect();
+                let numbers: Vec<String> =
+                    (1..=stacks.len()).map(|id| format!("{id:^3}")).collect();
+                lines.push(numbers.join(" "));
+                lines.push(String::new());
+                for (repeats, from, to) in instructions {
+                    lines.push(format!("move {repeats} from {from} to {to}"));
+                }
+                lines.join("\n")
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in warehouse()) {
+            let warehouse = Warehouse::from(&input).unwrap();
+            prop_assert_eq!(warehouse.to_string(), input);
+        }
+
+        #[test]
+        fn dry_runs_fail_where_runs_fail(input in warehouse()) {
+            let warehouse = Warehouse::from(&input).unwrap();
+            let first = warehouse.dry_run().into_iter().next();
+            let run = warehouse.start_crane(&CrateMover9000);
+            prop_assert_eq!(run.err(), first);
+        }
+
+        #[test]
+        fn resumes_from_rendered_states(input in warehouse(), moves in 0..10usize) {
+            let mut warehouse = Warehouse::from(&input).unwrap();
+            for _ in 0..moves {
+                if !matches!(warehouse.step(&CrateMover9001), Ok(true)) {
+                    break;
+                }
+            }
+            let resumed = Warehouse::from(&warehouse.to_string()).unwrap();
+            prop_assert_eq!(resumed.to_string(), warehouse.to_string());
+            let finished = warehouse.start_crane(&CrateMover9001).map(Warehouse::read_top);
+            let resumed = resumed.start_crane(&CrateMover9001).map(Warehouse::read_top);
+            // Instruction indices count from where the rendered state starts
+            prop_assert_eq!(resumed.ok(), finished.ok());
         }
-        top_crates
     }
 }
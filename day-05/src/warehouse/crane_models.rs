@@ -0,0 +1,70 @@
+use std::num::NonZeroUsize;
+
+/// How a crane carries crates from one stack to another.
+pub trait CraneModel {
+    /// Takes `count` crates off the top of `from` and returns them in the order they land,
+    /// bottom first. Stops early when `from` runs out of crates.
+    fn carry(&self, from: &mut String, count: usize) -> String;
+}
+
+/// Moves one crate at a time, so a move turns the crates upside down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9000;
+
+impl CraneModel for CrateMover9000 {
+    fn carry(&self, from: &mut String, count: usize) -> String {
+        (0..count).map_while(|_| from.pop()).collect()
+    }
+}
+
+/// Moves all the crates at once, keeping their order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9001;
+
+impl CraneModel for CrateMover9001 {
+    fn carry(&self, from: &mut String, count: usize) -> String {
+        let lifted: String = (0..count).map_while(|_| from.pop()).collect();
+        lifted.chars().rev().collect()
+    }
+}
+
+/// A crane that lifts at most `limit` crates at once, splitting larger moves into lifts
+/// made with `model`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capacity<M> {
+    pub model: M,
+    pub limit: NonZeroUsize,
+}
+
+impl<M: CraneModel> CraneModel for Capacity<M> {
+    fn carry(&self, from: &mut String, count: usize) -> String {
+        let mut landed = String::new();
+        let mut left = count;
+        while left > 0 {
+            let lift = left.min(self.limit.get());
+            landed.push_str(&self.model.carry(from, lift));
+            left -= lift;
+        }
+        landed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carries_crates_by_model() {
+        let mut crates = "ABCDE".to_string();
+        assert_eq!(CrateMover9000.carry(&mut crates, 2), "ED");
+        assert_eq!(CrateMover9001.carry(&mut crates, 2), "BC");
+        assert_eq!(crates, "A");
+
+        let mut crates = "ABCDE".to_string();
+        let limited = Capacity {
+            model: CrateMover9001,
+            limit: NonZeroUsize::new(2).unwrap(),
+        };
+        assert_eq!(limited.carry(&mut crates, 5), "DEBCA");
+    }
+}
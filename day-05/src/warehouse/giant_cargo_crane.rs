@@ -1,78 +1,203 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, error::Error, fmt};
 
-use super::stack_of_crates::Stack;
+use aoc_common::{parse_lines, parse_number, ParseError};
 
-#[derive(Debug, PartialEq)]
+use super::{stack_of_crates::Stack, CraneModel};
+
+#[derive(Debug, PartialEq, Clone)]
 struct Instruction {
-    repeats: u8,
-    from: u8,
-    to: u8,
+    repeats: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Instruction {
+    /// Checks the move against the heights of the stacks, `None` for a missing stack.
+    fn check(
+        &self,
+        index: usize,
+        height: impl Fn(&usize) -> Option<usize>,
+    ) -> Result<(), CraneError> {
+        let missing = |stack: usize| CraneError::MissingStack {
+            instruction: index,
+            stack,
+        };
+        height(&self.to).ok_or_else(|| missing(self.to))?;
+        let available = height(&self.from).ok_or_else(|| missing(self.from))?;
+        let wanted = self.repeats;
+        if wanted > available {
+            return Err(CraneError::NotEnoughCrates {
+                instruction: index,
+                from: self.from,
+                wanted,
+                available,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A move the crane can't make, `instruction` indexes the instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CraneError {
+    /// The move names a stack the warehouse doesn't have.
+    MissingStack { instruction: usize, stack: usize },
+    /// The move takes more crates than `from` holds at that point.
+    NotEnoughCrates {
+        instruction: usize,
+        from: usize,
+        wanted: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for CraneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CraneError::MissingStack { instruction, stack } => write!(
+                f,
+                "instruction {}: there is no stack {stack}",
+                instruction + 1
+            ),
+            CraneError::NotEnoughCrates {
+                instruction,
+                from,
+                wanted,
+                available,
+            } => write!(
+                f,
+                "instruction {}: moves {wanted} crates from stack {from}, but only {available} are there",
+                instruction + 1
+            ),
+        }
+    }
 }
 
+impl Error for CraneError {}
+
+#[derive(Debug, PartialEq, Clone)]
 pub(crate) struct Crane {
     instructions: Vec<Instruction>,
 }
 
 impl Crane {
-    pub fn from(input: &str) -> Crane {
-        let mut instructions = Vec::new();
-        let lines: Vec<_> = input.lines().collect();
-        for line in lines {
-            instructions.push(Crane::parse_line(line))
-        }
-        Crane { instructions }
+    pub fn from(input: &str) -> Result<Crane, ParseError> {
+        let instructions = parse_lines(input, Crane::parse_line)?;
+        Ok(Crane { instructions })
     }
 
-    fn parse_line(line: &str) -> Instruction {
+    fn parse_line(line: &str) -> Result<Instruction, ParseError> {
         let splitted: Vec<_> = line.split_whitespace().collect();
-        let repeats = splitted[1].parse().expect("Wasn't u8");
-        let from = splitted[3].parse().expect("Wasn't u8");
-        let to = splitted[5].parse().expect("Wasn't u8");
-        Instruction { repeats, from, to }
+        for (index, keyword) in [(0, "move"), (2, "from"), (4, "to")] {
+            match splitted.get(index) {
+                Some(word) if *word == keyword => (),
+                Some(word) => return Err(ParseError::at(line, word, format!("\"{keyword}\""))),
+                None => return Err(ParseError::end_of(line, format!("\"{keyword}\""))),
+            }
+        }
+        let number = |index: usize| match splitted.get(index) {
+            Some(word) => parse_number(line, word),
+            None => Err(ParseError::end_of(line, "a number")),
+        };
+        let repeats = number(1)?;
+        let from = number(3)?;
+        let to = number(5)?;
+        Ok(Instruction { repeats, from, to })
     }
 
-    pub fn execute_instructions(&self, mut stacks: HashMap<u8, Stack>) -> HashMap<u8, Stack> {
-        for instruction in &self.instructions {
-            stacks = move_crate(&instruction, stacks)
-        }
-        stacks
+    pub fn len(&self) -> usize {
+        self.instructions.len()
     }
-}
 
-fn move_crate(instruction: &Instruction, mut stacks: HashMap<u8, Stack>) -> HashMap<u8, Stack> {
-    let mut buffer = String::new();
-    for _ in 0..instruction.repeats {
-        let wooden_crate = stacks
-            .get_mut(&instruction.from)
-            .unwrap()
-            .crates
-            .pop()
-            .unwrap();
+    /// The instructions from `index` on.
+    pub fn remaining(&self, index: usize) -> Crane {
+        let instructions = self.instructions.get(index..).unwrap_or_default();
+        Crane {
+            instructions: instructions.to_vec(),
+        }
+    }
 
-        buffer.push(wooden_crate);
+    /// Makes the move of instruction `index`, leaving the stacks as they are past the last one.
+    pub fn execute(
+        &self,
+        index: usize,
+        model: &dyn CraneModel,
+        stacks: HashMap<usize, Stack>,
+    ) -> Result<HashMap<usize, Stack>, CraneError> {
+        let Some(instruction) = self.instructions.get(index) else {
+            return Ok(stacks);
+        };
+        let heights = |id: &usize| stacks.get(id).map(|stack| stack.crates.chars().count());
+        instruction.check(index, heights)?;
+        Ok(move_crate(model, instruction, stacks))
     }
 
-    buffer = buffer.chars().rev().collect();
+    /// Every move from instruction `index` on that would fail, counting only the crates the
+    /// moves before it could make bring, without moving any.
+    pub fn dry_run(&self, index: usize, stacks: &HashMap<usize, Stack>) -> Vec<CraneError> {
+        let mut heights: HashMap<usize, usize> = stacks
+            .iter()
+            .map(|(id, stack)| (*id, stack.crates.chars().count()))
+            .collect();
+        let mut errors = Vec::new();
+        for (index, instruction) in self.instructions.iter().enumerate().skip(index) {
+            if let Err(error) = instruction.check(index, |id| heights.get(id).copied()) {
+                errors.push(error);
+                continue;
+            }
+            let count = instruction.repeats;
+            heights
+                .entry(instruction.from)
+                .and_modify(|height| *height -= count);
+            heights
+                .entry(instruction.to)
+                .and_modify(|height| *height += count);
+        }
+        errors
+    }
+}
 
-    stacks
-        .get_mut(&instruction.to)
-        .unwrap()
-        .crates
-        .push_str(&buffer);
+impl fmt::Display for Crane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self
+            .instructions
+            .iter()
+            .map(|Instruction { repeats, from, to }| format!("move {repeats} from {from} to {to}"))
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
 
+/// Makes a move `Instruction::check` allowed.
+fn move_crate(
+    model: &dyn CraneModel,
+    instruction: &Instruction,
+    mut stacks: HashMap<usize, Stack>,
+) -> HashMap<usize, Stack> {
+    // Crates put back where they came from end up as they were, whatever the model
+    if instruction.from == instruction.to {
+        return stacks;
+    }
+    let landed = match stacks.get_mut(&instruction.from) {
+        Some(from) => model.carry(&mut from.crates, instruction.repeats),
+        None => return stacks,
+    };
+    if let Some(to) = stacks.get_mut(&instruction.to) {
+        to.crates.push_str(&landed);
+    }
     stacks
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use super::{super::CrateMover9001, *};
     #[test]
     fn loads_4_instructions() {
         let input = "move 1 from 2 to 1
         move 3 from 1 to 3
         move 2 from 2 to 1
         move 1 from 1 to 2";
-        assert_eq!(4, Crane::from(input).instructions.len())
+        assert_eq!(4, Crane::from(input).unwrap().instructions.len())
     }
 
     #[test]
@@ -83,7 +208,21 @@ mod tests {
             from: 2,
             to: 1,
         }]);
-        assert_eq!(expected, Crane::from(input).instructions)
+        assert_eq!(expected, Crane::from(input).unwrap().instructions)
+    }
+
+    #[test]
+    fn reports_malformed_instructions() {
+        let error = Crane::from("move 1 from 2 to 1\nmove 1 form 2 to 1")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: expected \"from\", found \"form\""
+        );
+
+        let error = Crane::parse_line("move 1 from 2 to").err().unwrap();
+        assert_eq!(error.expected, "a number");
     }
 
     #[test]
@@ -109,7 +248,7 @@ mod tests {
             },
         );
 
-        stacks = move_crate(&input_instruction, stacks);
+        stacks = move_crate(&CrateMover9001, &input_instruction, stacks);
 
         assert_eq!(2, stacks.get(&1).unwrap().crates.len());
         assert_eq!("AB".to_string(), stacks.get(&1).unwrap().crates);
@@ -141,7 +280,7 @@ mod tests {
 
         let crane = Crane { instructions };
 
-        stacks = crane.execute_instructions(stacks);
+        stacks = crane.execute(0, &CrateMover9001, stacks).unwrap();
 
         assert_eq!(0, stacks.get(&1).unwrap().crates.len());
         assert_eq!("".to_string(), stacks.get(&1).unwrap().crates);
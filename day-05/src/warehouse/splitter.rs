@@ -1,16 +1,31 @@
+use aoc_common::ParseError;
+
 pub struct Inits {
     pub stacks_map: String,
     pub crane_instructions: String,
 }
 
 impl Inits {
-    pub fn from(input: &str) -> Inits {
+    pub fn from(input: &str) -> Result<Inits, ParseError> {
         let unified_newlines = input.replace("\r", "");
         let parts: Vec<_> = unified_newlines.split("\n\n").collect();
-        Inits {
+        if parts.len() < 2 {
+            return Err(ParseError::new(
+                1,
+                "an empty line between the stacks and the instructions",
+                "end of input",
+            )
+            .on_line(unified_newlines.lines().count() + 1));
+        }
+        Ok(Inits {
             stacks_map: String::from(parts[0]),
             crane_instructions: String::from(parts[1]),
-        }
+        })
+    }
+
+    /// Line of the input where the crane instructions start.
+    pub fn instructions_offset(&self) -> usize {
+        self.stacks_map.lines().count() + 1
     }
 }
 
@@ -24,18 +39,25 @@ mod tests {
         let input = " 1   2   3 
 
         move 1 from 2 to 1";
-        let output = Inits::from(input);
-        assert!(output.stacks_map.len() > 0);
-        assert!(output.crane_instructions.len() > 0);
+        let output = Inits::from(input).unwrap();
+        assert!(!output.stacks_map.is_empty());
+        assert!(!output.crane_instructions.is_empty());
     }
+
+    #[test]
+    fn reports_missing_instructions() {
+        let error = Inits::from("[A]\n 1 ").err().unwrap();
+        assert_eq!(error.line, 3);
+    }
+
     #[test]
     #[ignore]
     fn full_data_load() {
         let contents: String =
             fs::read_to_string("data.txt").expect("Should have been able to read the file");
 
-        let output = Inits::from(&contents);
-        assert!(output.stacks_map.len() > 0);
-        assert!(output.crane_instructions.len() > 0);
+        let output = Inits::from(&contents).unwrap();
+        assert!(!output.stacks_map.is_empty());
+        assert!(!output.crane_instructions.is_empty());
     }
 }
@@ -1,42 +1,124 @@
 use std::collections::HashMap;
 
-#[derive(Debug, PartialEq)]
+use aoc_common::ParseError;
 
+#[derive(Debug, PartialEq, Clone)]
 pub struct Stack {
     pub crates: String,
-    pub id: u8,
+    pub id: usize,
 }
 
 impl Stack {
-    pub fn from(input: &str) -> Stack {
+    /// Crates listed bottom up.
+    pub fn new(id: usize, crates: &str) -> Stack {
         Stack {
-            crates: input[1..].chars().collect(),
-            id: input[0..1].parse().expect("Wasn't u8"),
+            crates: crates.to_string(),
+            id,
         }
     }
 }
 
-pub fn load_stacks(input: &str) -> HashMap<u8, Stack> {
+pub fn load_stacks(input: &str) -> Result<HashMap<usize, Stack>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((footer, drawing)) = lines.split_last() else {
+        return Ok(HashMap::new());
+    };
+    let labels = read_labels(footer).map_err(|error| error.on_line(lines.len()))?;
+    let columns = divide_to_columns(&drawing.join("\n"));
+    if columns.len() > labels.len() {
+        return Err(ParseError::end_of(footer, "a stack number").on_line(lines.len()));
+    }
+
     let mut stacks = HashMap::new();
-    let columns = divide_to_columns(input);
-    for column in columns {
-        let stack = Stack::from(&column);
-        stacks.insert(stack.id, stack);
+    for (index, id) in labels.into_iter().enumerate() {
+        let crates = columns.get(index).map_or("", String::as_str);
+        stacks.insert(id, Stack::new(id, crates));
+    }
+    Ok(stacks)
+}
+
+/// Reads the numbers below the stacks, each within the three columns of its crates so that
+/// numbers of up to three digits fit. A longer number would run into the next stack's columns
+/// and is rejected.
+fn read_labels(footer: &str) -> Result<Vec<usize>, ParseError> {
+    let offsets: Vec<usize> = footer
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain([footer.len()])
+        .collect();
+    let chars = offsets.len() - 1;
+    let mut labels: Vec<usize> = Vec::new();
+    for start in (0..chars).step_by(4) {
+        let cell = &footer[offsets[start]..offsets[chars.min(start + 3)]];
+        let label = cell.trim();
+        if label.is_empty() && footer[offsets[start]..].trim().is_empty() {
+            break;
+        }
+        if footer[offsets[chars.min(start + 3)]..].starts_with(|char: char| !char.is_whitespace()) {
+            let number = footer[offsets[start]..].split_whitespace().next().unwrap();
+            return Err(ParseError::at(
+                footer,
+                number,
+                "a stack number of up to three digits",
+            ));
+        }
+        let id: usize = label
+            .parse()
+            .map_err(|_| ParseError::at(footer, label, "a stack number"))?;
+        if let Some(previous) = labels.last().filter(|previous| id <= **previous) {
+            let expected = format!("a stack number above {previous}");
+            return Err(ParseError::at(footer, label, expected));
+        }
+        labels.push(id);
     }
-    stacks
+    Ok(labels)
+}
+
+/// Draws the stacks the way `load_stacks` reads them, crates on top of their numbers of up to
+/// three digits.
+pub fn draw_stacks(stacks: &HashMap<usize, Stack>) -> String {
+    let mut stacks: Vec<&Stack> = stacks.values().collect();
+    stacks.sort_by_key(|stack| stack.id);
+    let height = stacks
+        .iter()
+        .map(|stack| stack.crates.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let cells: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.crates.chars().nth(level) {
+                    Some(wooden_crate) => format!("[{wooden_crate}]"),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            cells.join(" ")
+        })
+        .collect();
+    let numbers: Vec<String> = stacks
+        .iter()
+        .map(|stack| format!("{:^3}", stack.id))
+        .collect();
+    lines.push(numbers.join(" "));
+    lines.join("\n")
 }
 
+/// Reads the crates of each column bottom up, from a drawing without its numbers.
 fn divide_to_columns(input: &str) -> Vec<String> {
     let mut columns: Vec<String> = Vec::new();
     let lines = input.lines();
     for line in lines.rev() {
         let chars = line.chars();
         for (char_index, char) in chars.enumerate() {
-            if char_index % 4 == 1 && char.is_whitespace() == false {
-                match columns.get_mut((char_index - 1) / 4) {
-                    Some(string) => string.push(char),
-                    None => columns.push(char.to_string()),
+            if char_index % 4 == 1 && !char.is_whitespace() {
+                let column = (char_index - 1) / 4;
+                if columns.len() <= column {
+                    columns.resize(column + 1, String::new());
                 }
+                columns[column].push(char);
             }
         }
     }
@@ -48,22 +130,65 @@ mod tests {
     use super::*;
     #[test]
     fn test_parsing() {
-        let input = "1WGVZ";
-        let output = Stack::from(input);
+        let stacks = load_stacks("    [G]\n[W] [V]\n 1   7 ").unwrap();
         let expected = Stack {
-            crates: "WGVZ".chars().collect(),
-            id: 1,
+            crates: "VG".chars().collect(),
+            id: 7,
         };
-        assert_eq!(expected, output);
+        assert_eq!(Some(&expected), stacks.get(&7));
+        assert_eq!("W", stacks[&1].crates);
+    }
+
+    #[test]
+    fn reports_missing_stack_numbers() {
+        let error = load_stacks("[A] [B]\n 1  [C]").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a stack number, found \"[C]\""
+        );
+
+        let error = load_stacks("[A] [B]\n 1 ").err().unwrap();
+        assert_eq!(error.expected, "a stack number");
+
+        let error = load_stacks(" 2   2 ").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a stack number above 2, found \"2\""
+        );
+    }
+
+    #[test]
+    fn reads_more_than_nine_stacks() {
+        let labels: Vec<String> = (1..=12).map(|id| format!("{id:^3}")).collect();
+        let footer = labels.join(" ");
+        assert_eq!(&footer[32..], " 9  10  11  12 ");
+        let input = format!("{}[Q]\n{footer}", " ".repeat(44));
+        let stacks = load_stacks(&input).unwrap();
+        assert_eq!(stacks.len(), 12);
+        assert_eq!(stacks[&12].crates, "Q");
+        assert_eq!(draw_stacks(&stacks), input);
+    }
+
+    #[test]
+    fn rejects_stack_numbers_past_999() {
+        let input = "        [Q]\n 1  998 999";
+        let stacks = load_stacks(input).unwrap();
+        assert_eq!(stacks[&999].crates, "Q");
+        assert_eq!(draw_stacks(&stacks), input);
+
+        let error = load_stacks("        [Q]\n998 999 1000").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 9: expected a stack number of up to three digits, found \"1000\""
+        );
     }
 
     #[test]
     fn test_columns() {
         let input = "    [D]    
 [N] [C]    
-[Z] [M] [P]
- 1   2   3 ";
-        let expected = Vec::from(["1ZN", "2MCD", "3P"]);
+[Z] [M] [P]";
+        let expected = Vec::from(["ZN", "MCD", "P"]);
         let output = divide_to_columns(input);
         assert_eq!(3, output.len());
         assert_eq!(expected, output);
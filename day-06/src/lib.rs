@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, ParseError, Solver};
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(_parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(None)
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// The datastream has to be made of lowercase letters only.
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    let datastream = input.trim_end();
+    match datastream
+        .char_indices()
+        .find(|(_, ch)| !ch.is_ascii_lowercase())
+    {
+        Some((index, ch)) => Err(ParseError::at(
+            datastream,
+            &datastream[index..index + ch.len_utf8()],
+            "a lowercase letter",
+        )
+        .on_line(datastream[..index].lines().count().max(1))),
+        None => Ok(datastream),
+    }
+}
+
+/// Only the start-of-message marker is implemented, so there is no `part1` yet.
+pub fn part2(datastream: &str) -> u16 {
+    count_start(datastream)
+}
+
+fn count_start(input: &str) -> u16 {
+    const NUM_CHARS: usize = 14;
+    let mut counter = 0;
+    let mut buffer = String::new();
+    for char in input.chars() {
+        buffer.push(char);
+        counter += 1;
+        if buffer.len() > NUM_CHARS {
+            buffer = String::from(&buffer[1..]);
+        }
+        if buffer.len() == NUM_CHARS {
+            let myset: HashSet<char> = buffer.chars().collect();
+            if myset.len() == NUM_CHARS {
+                return counter;
+            }
+        }
+    }
+    counter
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn round_trips(input in "[a-z]{0,100}\n?") {
+            prop_assert_eq!(parse(&input).unwrap(), input.trim_end());
+        }
+
+        #[test]
+        fn finds_first_marker(datastream in "[a-p]{14,60}") {
+            let chars: Vec<char> = datastream.chars().collect();
+            let marker = chars
+                .windows(14)
+                .position(|window| window.iter().collect::<HashSet<_>>().len() == 14)
+                .map_or(chars.len(), |start| start + 14);
+            prop_assert_eq!(count_start(&datastream) as usize, marker);
+        }
+    }
+    #[test]
+    fn test_count_start() {
+        assert_eq!(19, count_start("mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+        assert_eq!(23, count_start("bvwbjplbgvbhsrlpgdmjqwftvncz"));
+        assert_eq!(23, count_start("nppdvjthqldpwncqszvftbrmjlhg"));
+        assert_eq!(29, count_start("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"));
+        assert_eq!(26, count_start("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"));
+        assert_eq!(14, count_start("abcdefghijklmnn"));
+    }
+
+    #[test]
+    fn rejects_foreign_characters() {
+        assert_eq!(parse("mjqjpqm\n"), Ok("mjqjpqm"));
+        assert_eq!(
+            parse("mjq-jpqm").unwrap_err().to_string(),
+            "line 1, column 4: expected a lowercase letter, found \"-\""
+        );
+    }
+}
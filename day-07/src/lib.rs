@@ -0,0 +1,661 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Solver};
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+    type Parsed<'a> = Vec<(&'a str, u16)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// Reads the cards of every hand together with its bid.
+pub fn parse(input: &str) -> Result<Vec<(&str, u16)>, ParseError> {
+    parse_lines(input, parse_bid)
+}
+
+const CARDS: &str = "23456789TJQKA";
+
+fn parse_bid(line: &str) -> Result<(&str, u16), ParseError> {
+    let mut parts = line.split_whitespace();
+    let cards = parts
+        .next()
+        .ok_or_else(|| ParseError::end_of(line, "a hand of 5 cards"))?;
+    if let Some((index, card)) = cards
+        .char_indices()
+        .find(|(_, card)| !CARDS.contains(*card))
+    {
+        return Err(ParseError::at(
+            line,
+            &cards[index..index + card.len_utf8()],
+            "a card",
+        ));
+    }
+    if cards.len() != 5 {
+        return Err(ParseError::at(line, cards, "a hand of 5 cards"));
+    }
+    let bid = parts
+        .next()
+        .ok_or_else(|| ParseError::end_of(line, "a bid"))?;
+    Ok((cards, parse_number(line, bid)?))
+}
+
+pub fn render(bids: &[(&str, u16)]) -> String {
+    bids.iter()
+        .map(|(cards, bid)| format!("{cards} {bid}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(bids: &[(&str, u16)]) -> u64 {
+    Game::simple().total_winnings(bids)
+}
+
+pub fn part2(bids: &[(&str, u16)]) -> u64 {
+    Game::joker().total_winnings(bids)
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Strength {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Hand {
+    strenght: Strength,
+    cards: String,
+    multiplier: u16,
+}
+
+pub struct Game {
+    value_map: HashMap<char, u8>,
+    count_rule: fn(&str) -> HashMap<char, u8>,
+}
+
+impl Game {
+    pub fn simple() -> Self {
+        Game {
+            value_map: get_values_map_simple(),
+            count_rule: get_count_simple,
+        }
+    }
+
+    pub fn joker() -> Self {
+        Game {
+            value_map: get_values_map_joker(),
+            count_rule: get_count_joker,
+        }
+    }
+
+    pub fn calculate_total_winnings(&self, data: &str) -> Result<u64, ParseError> {
+        Ok(self.total_winnings(&parse(data)?))
+    }
+
+    pub fn total_winnings(&self, bids: &[(&str, u16)]) -> u64 {
+        let mut hands = self.evaluate_hands(bids);
+
+        sort_hands(&mut hands, &self.value_map);
+
+        for hand in &hands {
+            aoc_common::diagnostic!("{} {:?}", hand.cards, hand.strenght);
+        }
+
+        calculate_scores(&hands)
+    }
+
+    pub fn parse_hands(&self, data: &str) -> Result<Vec<Hand>, ParseError> {
+        Ok(self.evaluate_hands(&parse(data)?))
+    }
+
+    pub fn evaluate_hands(&self, bids: &[(&str, u16)]) -> Vec<Hand> {
+        bids.iter()
+            .map(|(cards, multiplier)| Hand {
+                strenght: calculate_strength(cards, self.count_rule),
+                cards: cards.to_string(),
+                multiplier: *multiplier,
+            })
+            .collect()
+    }
+}
+
+fn calculate_scores(hands: &[Hand]) -> u64 {
+    hands.iter().enumerate().fold(0, |acc, (index, hand)| {
+        acc + (hand.multiplier as u64 * (index as u64 + 1))
+    })
+}
+
+fn get_values_map_simple() -> HashMap<char, u8> {
+    HashMap::from([
+        ('2', 2),
+        ('3', 3),
+        ('4', 4),
+        ('5', 5),
+        ('6', 6),
+        ('7', 7),
+        ('8', 8),
+        ('9', 9),
+        ('T', 10),
+        ('J', 11),
+        ('Q', 12),
+        ('K', 13),
+        ('A', 14),
+    ])
+}
+
+fn get_values_map_joker() -> HashMap<char, u8> {
+    HashMap::from([
+        ('2', 2),
+        ('3', 3),
+        ('4', 4),
+        ('5', 5),
+        ('6', 6),
+        ('7', 7),
+        ('8', 8),
+        ('9', 9),
+        ('T', 10),
+        ('J', 1),
+        ('Q', 12),
+        ('K', 13),
+        ('A', 14),
+    ])
+}
+
+fn sort_hands(hands: &mut [Hand], map: &HashMap<char, u8>) {
+    hands.sort_unstable_by(|a, b| {
+        let mut result = a.strenght.cmp(&b.strenght);
+        for i in 0..5 {
+            if result != Ordering::Equal {
+                break;
+            }
+
+            let a_card = map.get(&a.cards.chars().nth(i).unwrap()).unwrap();
+            let b_card = map.get(&b.cards.chars().nth(i).unwrap()).unwrap();
+
+            result = b_card.cmp(a_card);
+        }
+        result
+    });
+    hands.reverse();
+}
+
+fn calculate_strength(hand: &str, count_rule: fn(&str) -> HashMap<char, u8>) -> Strength {
+    let card_count = count_rule(hand);
+
+    if card_count.values().max().unwrap() == &5 {
+        return Strength::FiveOfAKind;
+    }
+
+    if card_count.values().max().unwrap() == &4 {
+        return Strength::FourOfAKind;
+    }
+
+    if card_count.values().max().unwrap() == &3 {
+        if card_count.values().collect::<Vec<&u8>>().contains(&&2)
+            || card_count.values().filter(|card| card == &&3).count() == 2
+        {
+            return Strength::FullHouse;
+        }
+        return Strength::ThreeOfAKind;
+    }
+
+    let amount_of_pairs = card_count.values().filter(|card| card == &&2).count();
+    if amount_of_pairs == 2 {
+        return Strength::TwoPair;
+    }
+
+    if amount_of_pairs == 1 || amount_of_pairs > 2 {
+        return Strength::OnePair;
+    }
+
+    Strength::HighCard
+}
+
+fn get_count_simple(hand: &str) -> HashMap<char, u8> {
+    let mut card_count: HashMap<char, u8> = get_values_map_simple()
+        .iter()
+        .map(|card| (*card.0, 0u8))
+        .collect();
+
+    hand.chars().for_each(|card| {
+        let count = card_count.get_mut(&card).unwrap();
+        *count += 1;
+    });
+    card_count
+}
+
+fn get_count_joker(hand: &str) -> HashMap<char, u8> {
+    let mut card_count: HashMap<char, u8> = get_values_map_simple()
+        .iter()
+        .map(|card| (*card.0, 0u8))
+        .collect();
+
+    hand.chars().for_each(|card| {
+        let count = card_count.get_mut(&card).unwrap();
+        *count += 1;
+    });
+
+    // jokers pretend to be the most common other card, that always gives the strongest hand
+    let jokers = card_count.insert('J', 0).unwrap();
+    let (_, most_common) = card_count
+        .iter_mut()
+        .max_by_key(|(_, count)| **count)
+        .unwrap();
+    *most_common += jokers;
+
+    card_count
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Hands are all different, there is no telling which of two equal hands ranks higher.
+    fn bids() -> impl Strategy<Value = Vec<String>> {
+        let hands = proptest::collection::hash_set("[2-9TJQKA]{5}", 1..50);
+        hands.prop_flat_map(|hands| {
+            let count = hands.len();
+            (Just(hands), proptest::collection::vec(1..1000u16, count)).prop_map(|(hands, bids)| {
+                hands
+                    .into_iter()
+                    .zip(bids)
+                    .map(|(hand, bid)| format!("{hand} {bid}"))
+                    .collect()
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(lines in bids()) {
+            let input = lines.join("\n");
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn ranks_regardless_of_order(
+            (lines, shuffled) in bids().prop_flat_map(|lines| (Just(lines.clone()), Just(lines).prop_shuffle()))
+        ) {
+            let (input, shuffled) = (lines.join("\n"), shuffled.join("\n"));
+            let (bids, shuffled) = (parse(&input).unwrap(), parse(&shuffled).unwrap());
+            prop_assert_eq!(part1(&bids), part1(&shuffled));
+            prop_assert_eq!(part2(&bids), part2(&shuffled));
+        }
+    }
+
+    #[test]
+    fn parses_cards_with_joker() {
+        let data = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+5JAQA 1
+J25QT 2
+AAJQQ 3";
+        let game = Game {
+            value_map: get_values_map_joker(),
+            count_rule: get_count_joker,
+        };
+
+        let expected_hands = vec![
+            Hand {
+                cards: "32T3K".to_string(),
+                multiplier: 765,
+                strenght: Strength::OnePair,
+            },
+            Hand {
+                cards: "T55J5".to_string(),
+                multiplier: 684,
+                strenght: Strength::FourOfAKind,
+            },
+            Hand {
+                cards: "KK677".to_string(),
+                multiplier: 28,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "KTJJT".to_string(),
+                multiplier: 220,
+                strenght: Strength::FourOfAKind,
+            },
+            Hand {
+                cards: "QQQJA".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+            Hand {
+                cards: "5JAQA".to_string(),
+                multiplier: 1,
+                strenght: Strength::ThreeOfAKind,
+            },
+            Hand {
+                cards: "J25QT".to_string(),
+                multiplier: 2,
+                strenght: Strength::OnePair,
+            },
+            Hand {
+                cards: "AAJQQ".to_string(),
+                multiplier: 3,
+                strenght: Strength::FullHouse,
+            },
+        ];
+
+        assert_eq!(game.parse_hands(data).unwrap(), expected_hands);
+    }
+
+    #[test]
+    fn counts_jokers() {
+        assert_eq!(get_count_joker("T55J5").get(&'5').unwrap(), &4);
+        assert_eq!(get_count_joker("T55J5").get(&'T').unwrap(), &1);
+        assert_eq!(get_count_joker("T55J5").get(&'J').unwrap(), &0);
+
+        let card_count = get_count_joker("J25QT");
+        let amount_of_pairs = card_count.values().filter(|card| card == &&2).count();
+        assert_eq!(card_count.get(&'J').unwrap(), &0);
+        assert_eq!(amount_of_pairs, 1);
+
+        assert_eq!(get_count_joker("JJJJJ").values().max().unwrap(), &5);
+    }
+
+    #[test]
+    fn integration_test() {
+        let data = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        let game = Game {
+            value_map: get_values_map_simple(),
+            count_rule: get_count_simple,
+        };
+
+        let result = game.calculate_total_winnings(data).unwrap();
+        assert_eq!(result, 6440);
+    }
+
+    #[test]
+    fn integration_joker() {
+        let data = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        let game = Game {
+            value_map: get_values_map_joker(),
+            count_rule: get_count_joker,
+        };
+
+        let result = game.calculate_total_winnings(data).unwrap();
+        assert_eq!(result, 5905);
+    }
+
+    #[test]
+    fn parses_cards() {
+        let data = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        let game = Game {
+            value_map: get_values_map_simple(),
+            count_rule: get_count_simple,
+        };
+
+        let expected_hands = vec![
+            Hand {
+                cards: "32T3K".to_string(),
+                multiplier: 765,
+                strenght: Strength::OnePair,
+            },
+            Hand {
+                cards: "T55J5".to_string(),
+                multiplier: 684,
+                strenght: Strength::ThreeOfAKind,
+            },
+            Hand {
+                cards: "KK677".to_string(),
+                multiplier: 28,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "KTJJT".to_string(),
+                multiplier: 220,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "QQQJA".to_string(),
+                multiplier: 483,
+                strenght: Strength::ThreeOfAKind,
+            },
+        ];
+
+        assert_eq!(game.parse_hands(data).unwrap(), expected_hands);
+    }
+
+    #[test]
+    fn reports_malformed_hands() {
+        let error = parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a card, found \"X\""
+        );
+
+        let error = parse("32T3K 765\nT55J 684").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = parse("32T3K").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a bid, found end of line"
+        );
+    }
+
+    #[test]
+    fn recognizes_correct_strenght() {
+        assert_eq!(
+            calculate_strength("99999", get_count_simple),
+            Strength::FiveOfAKind
+        );
+        assert_eq!(
+            calculate_strength("T55J5", get_count_simple),
+            Strength::ThreeOfAKind
+        );
+        assert_eq!(
+            calculate_strength("QQQJA", get_count_simple),
+            Strength::ThreeOfAKind
+        );
+        assert_eq!(
+            calculate_strength("QQQJJ", get_count_simple),
+            Strength::FullHouse
+        );
+        assert_eq!(
+            calculate_strength("KK677", get_count_simple),
+            Strength::TwoPair
+        );
+        assert_eq!(
+            calculate_strength("KTJJT", get_count_simple),
+            Strength::TwoPair
+        );
+        assert_eq!(
+            calculate_strength("59999", get_count_simple),
+            Strength::FourOfAKind
+        );
+        assert_eq!(
+            calculate_strength("9QATJ", get_count_simple),
+            Strength::HighCard
+        );
+        assert_eq!(
+            calculate_strength("32T3K", get_count_simple),
+            Strength::OnePair
+        );
+    }
+
+    #[test]
+    fn orders_hands_correctly() {
+        let mut initial_hands = vec![
+            Hand {
+                cards: "59999".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+            Hand {
+                cards: "2AAAA".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+        ];
+
+        let expected_hands = vec![
+            Hand {
+                cards: "2AAAA".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+            Hand {
+                cards: "59999".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+        ];
+
+        sort_hands(&mut initial_hands, &get_values_map_simple());
+
+        assert_eq!(initial_hands, expected_hands);
+
+        let mut initial_hands = vec![
+            Hand {
+                cards: "32T3K".to_string(),
+                multiplier: 765,
+                strenght: Strength::OnePair,
+            },
+            Hand {
+                cards: "T55J5".to_string(),
+                multiplier: 684,
+                strenght: Strength::ThreeOfAKind,
+            },
+            Hand {
+                cards: "KK677".to_string(),
+                multiplier: 28,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "KTJJT".to_string(),
+                multiplier: 220,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "QQQJA".to_string(),
+                multiplier: 483,
+                strenght: Strength::ThreeOfAKind,
+            },
+            Hand {
+                cards: "59999".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+            Hand {
+                cards: "2AAAA".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+        ];
+
+        let expected_hands = vec![
+            Hand {
+                cards: "32T3K".to_string(),
+                multiplier: 765,
+                strenght: Strength::OnePair,
+            },
+            Hand {
+                cards: "KTJJT".to_string(),
+                multiplier: 220,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "KK677".to_string(),
+                multiplier: 28,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "T55J5".to_string(),
+                multiplier: 684,
+                strenght: Strength::ThreeOfAKind,
+            },
+            Hand {
+                cards: "QQQJA".to_string(),
+                multiplier: 483,
+                strenght: Strength::ThreeOfAKind,
+            },
+            Hand {
+                cards: "2AAAA".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+            Hand {
+                cards: "59999".to_string(),
+                multiplier: 483,
+                strenght: Strength::FourOfAKind,
+            },
+        ];
+
+        sort_hands(&mut initial_hands, &get_values_map_simple());
+
+        assert_eq!(initial_hands, expected_hands);
+    }
+
+    #[test]
+    fn calculates_scores_correctly() {
+        let hands = vec![
+            Hand {
+                cards: "32T3K".to_string(),
+                multiplier: 765,
+                strenght: Strength::OnePair,
+            },
+            Hand {
+                cards: "KTJJT".to_string(),
+                multiplier: 220,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "KK677".to_string(),
+                multiplier: 28,
+                strenght: Strength::TwoPair,
+            },
+            Hand {
+                cards: "T55J5".to_string(),
+                multiplier: 684,
+                strenght: Strength::ThreeOfAKind,
+            },
+            Hand {
+                cards: "QQQJA".to_string(),
+                multiplier: 483,
+                strenght: Strength::ThreeOfAKind,
+            },
+        ];
+
+        assert_eq!(calculate_scores(&hands), 6440);
+    }
+}
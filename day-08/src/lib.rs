@@ -0,0 +1,292 @@
+use core::panic;
+use num_integer::lcm;
+
+use aoc_common::{parse_lines, Answer, ParseError, Solver};
+use aoc_graph::{Graph, NodeId};
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+    type Parsed<'a> = DesertMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<DesertMap, ParseError> {
+    DesertMap::from(input)
+}
+
+/// Writes the instructions, followed by the nodes ordered by their id.
+pub fn render(desert_map: &DesertMap) -> String {
+    let map = &desert_map.map;
+    let mut nodes: Vec<_> = map
+        .nodes()
+        .filter(|&(id, _)| !map.edges(id).is_empty())
+        .collect();
+    nodes.sort_by_key(|&(_, key)| key);
+    let nodes: Vec<String> = nodes
+        .into_iter()
+        .map(|(id, key)| {
+            let (left, right) = desert_map.turns(id);
+            format!("{key} = ({}, {})", map.key(left), map.key(right))
+        })
+        .collect();
+    format!("{}\n\n{}", desert_map.instructions, nodes.join("\n"))
+}
+
+pub fn part1(desert_map: &DesertMap) -> u64 {
+    desert_map.count_steps()
+}
+
+pub fn part2(desert_map: &DesertMap) -> u64 {
+    desert_map.count_ghost_steps_smartly()
+}
+
+/// Every node has two edges, the one to the left first and the one to the right second.
+#[derive(Debug, PartialEq)]
+pub struct DesertMap {
+    map: Graph<String>,
+    instructions: String,
+}
+
+impl DesertMap {
+    pub fn from(data: &str) -> Result<Self, ParseError> {
+        let (instructions, nodes) = data.split_once("\n\n").ok_or_else(|| {
+            let lines = data.lines().count();
+            ParseError::new(1, "an empty line after the instructions", "end of input")
+                .on_line(lines + 1)
+        })?;
+
+        if let Some((index, instruction)) = instructions
+            .char_indices()
+            .find(|(_, instruction)| !matches!(instruction, 'L' | 'R'))
+        {
+            let token = &instructions[index..index + instruction.len_utf8()];
+            return Err(ParseError::at(instructions, token, "'L' or 'R'").on_line(1));
+        }
+
+        let nodes = parse_lines(nodes, parse_node).map_err(|error| {
+            let line = error.line + 2;
+            error.on_line(line)
+        })?;
+        let mut map = Graph::directed();
+        for (id, (left, right)) in nodes {
+            let id = map.intern(id);
+            if map.edges(id).is_empty() {
+                let left = map.intern(left);
+                let right = map.intern(right);
+                map.connect(id, left, ());
+                map.connect(id, right, ());
+            }
+        }
+
+        Ok(Self {
+            map,
+            instructions: instructions.to_string(),
+        })
+    }
+
+    pub fn count_steps(&self) -> u64 {
+        let finish = self.node("ZZZ");
+        let mut current_node = self.node("AAA");
+        let mut counter = 0;
+        while current_node != finish {
+            for instruction in self.instructions.chars() {
+                counter += 1;
+
+                current_node = self.next_node(current_node, instruction);
+
+                if current_node == finish {
+                    break;
+                }
+            }
+        }
+        counter
+    }
+
+    fn node(&self, id: &str) -> NodeId {
+        self.map
+            .id(id)
+            .unwrap_or_else(|| panic!("The map should have node {id}"))
+    }
+
+    fn turns(&self, node: NodeId) -> (NodeId, NodeId) {
+        match self.map.edges(node) {
+            [(left, _), (right, _)] => (*left, *right),
+            _ => panic!("Node {} leads nowhere", self.map.key(node)),
+        }
+    }
+
+    fn next_node(&self, node: NodeId, instruction: char) -> NodeId {
+        let (left, right) = self.turns(node);
+
+        match instruction {
+            'L' => left,
+            'R' => right,
+            _ => panic!("Unexpected instruction"),
+        }
+    }
+
+    pub fn count_ghost_steps_smartly(&self) -> u64 {
+        let mut current_nodes = self
+            .map
+            .nodes()
+            .filter(|(_, key)| key.ends_with('A'))
+            .map(|(id, _)| id)
+            .collect::<Vec<NodeId>>();
+        let mut cycle_times = current_nodes.iter().map(|_| 0u64).collect::<Vec<_>>();
+
+        let mut counter = 0;
+        let mut is_every_cycle_time_found = false;
+
+        while !is_every_cycle_time_found {
+            for instruction in self.instructions.chars() {
+                counter += 1;
+
+                for (index, current_node) in current_nodes.iter_mut().enumerate() {
+                    *current_node = self.next_node(*current_node, instruction);
+                    if self.map.key(*current_node).ends_with('Z')
+                        && cycle_times.get(index).unwrap() == &0
+                    {
+                        let count = cycle_times.get_mut(index).unwrap();
+                        *count = counter;
+                    };
+                }
+
+                is_every_cycle_time_found = cycle_times.iter().all(|time| time > &0);
+
+                if is_every_cycle_time_found {
+                    break;
+                }
+            }
+        }
+
+        cycle_times.iter().fold(1, |acc, cycle| lcm(acc, *cycle))
+    }
+}
+/// Reads a line like `AAA = (BBB, CCC)`.
+fn parse_node(line: &str) -> Result<(String, (String, String)), ParseError> {
+    let (id, targets) = line
+        .split_once(" = ")
+        .ok_or_else(|| ParseError::end_of(line, "' = '"))?;
+    let (left, right) = targets
+        .strip_prefix('(')
+        .and_then(|targets| targets.strip_suffix(')'))
+        .and_then(|targets| targets.split_once(", "))
+        .ok_or_else(|| ParseError::at(line, targets, "(left, right)"))?;
+
+    Ok((id.to_string(), (left.to_string(), right.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Instructions and nodes in id order, all pointing at other nodes of the map.
+    fn desert_map() -> impl Strategy<Value = String> {
+        let ids = proptest::collection::btree_set("[A-Z0-9]{3}", 1..30);
+        ("[LR]{1,20}", ids).prop_flat_map(|(instructions, ids)| {
+            let ids: Vec<String> = ids.into_iter().collect();
+            let target = proptest::sample::select(ids.clone());
+            let targets = proptest::collection::vec((target.clone(), target), ids.len());
+            targets.prop_map(move |targets| {
+                let nodes: Vec<String> = ids
+                    .iter()
+                    .zip(targets)
+                    .map(|(id, (left, right))| format!("{id} = ({left}, {right})"))
+                    .collect();
+                format!("{instructions}\n\n{}", nodes.join("\n"))
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in desert_map()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+    }
+
+    #[test]
+    fn parses_map() {
+        let data = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        let instructions = "LLR".to_string();
+        let mut map = Graph::directed();
+        for (id, left, right) in [
+            ("AAA", "BBB", "BBB"),
+            ("BBB", "AAA", "ZZZ"),
+            ("ZZZ", "ZZZ", "ZZZ"),
+        ] {
+            map.add_edge(id.to_string(), left.to_string(), ());
+            map.add_edge(id.to_string(), right.to_string(), ());
+        }
+
+        let expected_desert_map = DesertMap { map, instructions };
+
+        assert_eq!(DesertMap::from(data).unwrap(), expected_desert_map);
+    }
+
+    #[test]
+    fn reports_malformed_map() {
+        let error = DesertMap::from("LLR\n\nAAA = (BBB, BBB)\nBBB = AAA, ZZZ").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 7: expected (left, right), found \"AAA, ZZZ\""
+        );
+
+        let error = DesertMap::from("LXR\n\nAAA = (BBB, BBB)").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+
+        let error = DesertMap::from("LLR\nAAA = (BBB, BBB)").unwrap_err();
+        assert_eq!(error.line, 3);
+    }
+
+    #[test]
+    fn find_ghost_path_smartly() {
+        let data = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        let desert_map = DesertMap::from(data).unwrap();
+        let step_count: u64 = desert_map.count_ghost_steps_smartly();
+        assert_eq!(step_count, 6);
+    }
+
+    #[test]
+    fn finds_path() {
+        let data = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        let desert_map = DesertMap::from(data).unwrap();
+        let step_count: u64 = desert_map.count_steps();
+        assert_eq!(step_count, 6);
+    }
+}
@@ -0,0 +1,209 @@
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Solver};
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+    type Parsed<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_lines(input, parse_line)
+}
+
+pub fn render(sequences: &[Vec<i64>]) -> String {
+    sequences
+        .iter()
+        .map(|sequence| {
+            let numbers: Vec<String> = sequence.iter().map(i64::to_string).collect();
+            numbers.join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|sequence| predict(sequence, forwards_prediction))
+        .sum()
+}
+
+pub fn part2(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|sequence| predict(sequence, backwards_prediction))
+        .sum()
+}
+
+fn parse_line(line: &str) -> Result<Vec<i64>, ParseError> {
+    if line.trim().is_empty() {
+        return Err(ParseError::end_of(line, "a number"));
+    }
+    line.split_whitespace()
+        .map(|num_string| parse_number(line, num_string))
+        .collect()
+}
+
+fn predict(sequence: &[i64], prediction_strategy: fn(Vec<Vec<i64>>, &mut i64)) -> i64 {
+    let derivations = get_all_derivations(sequence);
+
+    let mut prediction = 0;
+    prediction_strategy(derivations, &mut prediction);
+    prediction
+}
+
+fn forwards_prediction(derivations: Vec<Vec<i64>>, prediction: &mut i64) {
+    derivations.iter().rev().for_each(|level| {
+        let current_last = level.iter().last().unwrap();
+        *prediction += current_last;
+    });
+}
+
+fn backwards_prediction(derivations: Vec<Vec<i64>>, prediction: &mut i64) {
+    derivations.iter().rev().for_each(|level| {
+        let current_first = level.first().unwrap();
+        *prediction = current_first - *prediction;
+    });
+}
+
+fn get_all_derivations(sequence: &[i64]) -> Vec<Vec<i64>> {
+    let mut derivations = Vec::new();
+    derivations.push(sequence.to_vec());
+
+    for i in 0..20 {
+        let current_level = derivations.get(i).unwrap();
+        let next_level = derive(current_level);
+
+        let first_elem = next_level.first().unwrap();
+        if next_level.iter().all(|number| number == first_elem) {
+            derivations.push(next_level);
+            break;
+        }
+
+        derivations.push(next_level);
+
+        if i == 20 {
+            panic!("Went deeper than expected!");
+        }
+    }
+    derivations
+}
+
+fn derive(sequence: &[i64]) -> Vec<i64> {
+    let mut derived_sequence = Vec::new();
+
+    let mut seq_iterator = sequence.iter().peekable();
+
+    while let Some(number) = seq_iterator.next() {
+        if let Some(next) = seq_iterator.peek() {
+            derived_sequence.push(*next - number)
+        }
+    }
+
+    derived_sequence
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn evaluate(coefficients: &[i64], x: i64) -> i64 {
+        coefficients.iter().rev().fold(0, |value, c| value * x + c)
+    }
+
+    /// A polynomial, by its coefficients, with more values than its degree needs.
+    fn polynomial() -> impl Strategy<Value = (Vec<i64>, i64)> {
+        (3..21i64).prop_flat_map(|length| {
+            let degree = (length as usize - 2).min(4);
+            (
+                proptest::collection::vec(-5..=5i64, 1..=degree + 1),
+                Just(length),
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn extrapolates_polynomials((coefficients, length) in polynomial()) {
+            let sequence: Vec<i64> = (0..length).map(|x| evaluate(&coefficients, x)).collect();
+            let sequences = [sequence];
+            prop_assert_eq!(part1(&sequences), evaluate(&coefficients, length));
+            prop_assert_eq!(part2(&sequences), evaluate(&coefficients, -1));
+        }
+
+        #[test]
+        fn round_trips(sequences in proptest::collection::vec(proptest::collection::vec(any::<i64>(), 1..21), 1..10)) {
+            let input = render(&sequences);
+            prop_assert_eq!(parse(&input).unwrap(), sequences);
+        }
+    }
+
+    #[test]
+    fn creates_all_derivations_correctly() {
+        let sequence = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(get_all_derivations(&sequence).len(), 2);
+
+        let sequence = vec![1, 3, 6, 10, 15, 21];
+        assert_eq!(get_all_derivations(&sequence).len(), 3);
+
+        let sequence = vec![10, 13, 16, 21, 30, 45];
+        assert_eq!(get_all_derivations(&sequence).len(), 4);
+    }
+
+    #[test]
+    fn reports_malformed_sequences() {
+        assert_eq!(
+            parse("0 3 6\n-1 -2").unwrap(),
+            vec![vec![0, 3, 6], vec![-1, -2]]
+        );
+
+        let error = parse("0 3 6\n1 3 six").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a number, found \"six\""
+        );
+        assert_eq!(parse("0 3 6\n\n1 3 6").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn derives_sequence() {
+        let sequence = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(derive(&sequence), vec![3, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn predicts_value_correctly() {
+        let sequence = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(predict(&sequence, forwards_prediction), 18);
+
+        let sequence = vec![1, 3, 6, 10, 15, 21];
+        assert_eq!(predict(&sequence, forwards_prediction), 28);
+
+        let sequence = vec![10, 13, 16, 21, 30, 45];
+        assert_eq!(predict(&sequence, forwards_prediction), 68);
+    }
+
+    #[test]
+    fn predicts_previous_value_correctly() {
+        let sequence = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(predict(&sequence, backwards_prediction), -3);
+
+        let sequence = vec![10, 13, 16, 21, 30, 45];
+        assert_eq!(predict(&sequence, backwards_prediction), 5);
+    }
+}
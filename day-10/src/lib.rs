@@ -0,0 +1,831 @@
+use std::{collections::HashMap, vec};
+
+use aoc_common::{Answer, ParseError, Solver};
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    type Parsed<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// Parses the pipes and walks the main loop right away, both parts need it.
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let mut grid = Grid::parse_grid(input)?;
+    grid.calculate_loop()?;
+    Ok(grid)
+}
+
+pub fn part1(grid: &Grid) -> u16 {
+    grid.farthes_distance.unwrap()
+}
+
+pub fn part2(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    grid.find_contained();
+    grid.contained.unwrap()
+}
+
+/// Draws the pipes again, with 'S' back in place of the pipe found under it.
+pub fn render(grid: &Grid) -> String {
+    grid.locations
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|location| match location.coord == grid.start {
+                    true => 'S',
+                    false => location.shape,
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Location {
+    is_pipe: bool,
+    coord: Coordinate,
+    shape: char,
+    is_main_loop: Option<bool>,
+    distance_from_start: Option<u16>,
+    is_inside: Option<bool>,
+}
+
+impl Location {
+    fn get_matching_pipes() -> HashMap<Direction, Vec<char>> {
+        HashMap::from([
+            (Direction::Up, vec!['|', 'F', '7']),
+            (Direction::Down, vec!['|', 'L', 'J']),
+            (Direction::Right, vec!['-', 'J', '7']),
+            (Direction::Left, vec!['-', 'F', 'L']),
+        ])
+    }
+
+    fn get_pipe_directions() -> HashMap<char, Vec<Direction>> {
+        HashMap::from([
+            ('|', vec![Direction::Down, Direction::Up]),
+            ('-', vec![Direction::Right, Direction::Left]),
+            ('L', vec![Direction::Up, Direction::Right]),
+            ('J', vec![Direction::Up, Direction::Left]),
+            ('7', vec![Direction::Down, Direction::Left]),
+            ('F', vec![Direction::Down, Direction::Right]),
+            (
+                'S',
+                vec![
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Right,
+                    Direction::Left,
+                ],
+            ),
+        ])
+    }
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    locations: aoc_grid::Grid<Location>,
+    start: Coordinate,
+    matching_pipes: HashMap<Direction, Vec<char>>,
+    pipe_directions: HashMap<char, Vec<Direction>>,
+    farthes_distance: Option<u16>,
+    size: (usize, usize),
+    contained: Option<usize>,
+}
+
+impl Grid {
+    pub fn parse_grid(input: &str) -> Result<Self, ParseError> {
+        let shapes = aoc_grid::Grid::try_parse(input, "a pipe, '.' or 'S'", |shape| {
+            "|-LJ7F.S".contains(shape).then_some(shape)
+        })?;
+        let mut locations = Vec::new();
+        let mut start = None;
+
+        for (row_index, line) in shapes.rows().enumerate() {
+            let row = line
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(col_index, shape)| {
+                    let location = Coordinate {
+                        x: col_index,
+                        y: row_index,
+                    };
+                    match shape {
+                        '.' => Location {
+                            is_pipe: false,
+                            coord: location,
+                            shape,
+                            is_main_loop: Some(false),
+                            distance_from_start: None,
+                            is_inside: None,
+                        },
+                        '|' => Location {
+                            is_pipe: true,
+                            coord: location,
+                            shape,
+                            is_main_loop: None,
+                            distance_from_start: None,
+                            is_inside: None,
+                        },
+                        '-' => Location {
+                            is_pipe: true,
+                            coord: location,
+                            shape,
+                            is_main_loop: None,
+                            distance_from_start: None,
+                            is_inside: None,
+                        },
+                        'L' => Location {
+                            is_pipe: true,
+                            coord: location,
+                            shape,
+                            is_main_loop: None,
+                            distance_from_start: None,
+                            is_inside: None,
+                        },
+                        'J' => Location {
+                            is_pipe: true,
+                            coord: location,
+                            shape,
+                            is_main_loop: None,
+                            distance_from_start: None,
+                            is_inside: None,
+                        },
+                        '7' => Location {
+                            is_pipe: true,
+                            coord: location,
+                            shape,
+                            is_main_loop: None,
+                            distance_from_start: None,
+                            is_inside: None,
+                        },
+                        'F' => Location {
+                            is_pipe: true,
+                            coord: location,
+                            shape,
+                            is_main_loop: None,
+                            distance_from_start: None,
+                            is_inside: None,
+                        },
+                        'S' => {
+                            start = Some(location);
+                            Location {
+                                is_pipe: true,
+                                coord: location,
+                                shape,
+                                is_main_loop: Some(true),
+                                distance_from_start: Some(0),
+                                is_inside: None,
+                            }
+                        }
+                        _ => unreachable!("Only known shapes get through parsing"),
+                    }
+                })
+                .collect::<Vec<_>>();
+            locations.push(row);
+        }
+
+        let locations = aoc_grid::Grid::from_rows(locations);
+        let size = (locations.height(), locations.width());
+        let start = start.ok_or_else(|| {
+            ParseError::new(1, "a starting position 'S'", "end of input").on_line(size.0 + 1)
+        })?;
+
+        Ok(Self {
+            locations,
+            start,
+            matching_pipes: Location::get_matching_pipes(),
+            pipe_directions: Location::get_pipe_directions(),
+            farthes_distance: None,
+            size,
+            contained: None,
+        })
+    }
+
+    fn mark_neighbors(
+        &mut self,
+        current: &Coordinate,
+        previous: Option<&Coordinate>,
+    ) -> Vec<Coordinate> {
+        let mut neighbors = Vec::new();
+        let mut neighboring_directions = Vec::new();
+
+        let previous_distance;
+
+        {
+            let current_loc = &self.locations[current.y][current.x];
+            previous_distance = current_loc.distance_from_start.unwrap();
+
+            for direction in self.pipe_directions.get(&current_loc.shape).unwrap() {
+                if let Some(potential_coord) = calculate_offset(direction, current) {
+                    let is_different_from_previous = match previous {
+                        Some(previous_value) => *previous_value != potential_coord,
+                        None => true,
+                    };
+
+                    if potential_coord.y < self.locations.height()
+                        && potential_coord.x < self.locations.width()
+                        && is_different_from_previous
+                    {
+                        let potential = &self.locations[(potential_coord.x, potential_coord.y)];
+                        let matching_pipes = &self.matching_pipes[direction];
+                        if matching_pipes.contains(&potential.shape) {
+                            neighbors.push(potential_coord);
+                            neighboring_directions.push(direction);
+                        }
+                    }
+                }
+            }
+        }
+
+        for neighbor in &neighbors {
+            let potential = &mut self.locations[(neighbor.x, neighbor.y)];
+
+            potential.is_main_loop = Some(true);
+            match potential.distance_from_start {
+                Some(_) => (),
+                None => potential.distance_from_start = Some(previous_distance + 1),
+            };
+        }
+
+        if previous.is_none() && neighbors.len() == 2 {
+            // 'S' connects in every direction, so it would match whatever its pipes are.
+            for (key, dirs) in self.pipe_directions.iter().filter(|(key, _)| **key != 'S') {
+                if dirs.contains(neighboring_directions[0])
+                    && dirs.contains(neighboring_directions[1])
+                {
+                    let starting_loc = &mut self.locations[(current.x, current.y)];
+                    starting_loc.shape = *key;
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    pub fn calculate_loop(&mut self) -> Result<(), ParseError> {
+        let start = &self.start.clone();
+        let mut neighbors = self.mark_neighbors(start, None);
+        if neighbors.len() != 2 {
+            let found = format!("{} connecting pipes", neighbors.len());
+            return Err(self.error_at(start, "'S' connecting to exactly 2 pipes", found));
+        }
+        let mut previous_a_neighbor = *start;
+        let mut previous_b_neighbor = *start;
+        let mut is_end_found = false;
+
+        while !is_end_found {
+            if let Some(first_neighbor) = neighbors.get_mut(0) {
+                let next_a_neighbor = *self
+                    .mark_neighbors(first_neighbor, Some(&previous_a_neighbor))
+                    .first()
+                    .ok_or_else(|| self.dead_end(first_neighbor))?;
+
+                previous_a_neighbor = *first_neighbor;
+                *first_neighbor = next_a_neighbor;
+            }
+
+            if let Some(second_neighbor) = neighbors.get_mut(1) {
+                let next_b_neighbor = *self
+                    .mark_neighbors(second_neighbor, Some(&previous_b_neighbor))
+                    .first()
+                    .ok_or_else(|| self.dead_end(second_neighbor))?;
+
+                previous_b_neighbor = *second_neighbor;
+                *second_neighbor = next_b_neighbor;
+            }
+
+            let current_first = neighbors.first().unwrap();
+            let current_second = neighbors.get(1).unwrap();
+            let current_path_length = self.locations[current_first.y][neighbors.first().unwrap().x]
+                .distance_from_start
+                .unwrap();
+
+            if *current_first == *current_second {
+                self.farthes_distance = Some(current_path_length);
+                is_end_found = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn error_at(&self, coord: &Coordinate, expected: &str, found: String) -> ParseError {
+        ParseError::new(coord.x + 1, expected, found).on_line(coord.y + 1)
+    }
+
+    /// The loop stops at a pipe that leads nowhere or into one that doesn't connect back.
+    fn dead_end(&self, coord: &Coordinate) -> ParseError {
+        let shape = self.locations[coord.y][coord.x].shape;
+        self.error_at(coord, "a pipe continuing the loop", format!("'{shape}'"))
+    }
+
+    pub fn find_contained(&mut self) {
+        let mut boundary_info = Vec::new();
+
+        for row in self.locations.rows() {
+            for location in row {
+                let is_inside: bool = if location.is_main_loop.is_some_and(|x| x) {
+                    false
+                } else {
+                    self.calculate_boundary_crossings(location)
+                };
+                boundary_info.push((location.coord, is_inside))
+            }
+        }
+
+        for (coord, is_inside) in boundary_info {
+            let target_location = &mut self.locations[(coord.x, coord.y)];
+            target_location.is_inside = Some(is_inside);
+        }
+
+        for row in self.locations.rows() {
+            let row_string = row
+                .iter()
+                .map(|loc| {
+                    if loc.is_inside.unwrap() {
+                        'I'
+                    } else if loc.is_main_loop.is_some_and(|x| x) {
+                        loc.shape
+                    } else {
+                        'O'
+                    }
+                })
+                .collect::<String>();
+            aoc_common::diagnostic!("{row_string}");
+        }
+
+        self.contained = Some(
+            self.locations
+                .rows()
+                .map(|row| {
+                    row.iter()
+                        .filter(|location| location.is_inside.is_some_and(|x| x))
+                        .count()
+                })
+                .sum::<usize>(),
+        );
+    }
+
+    fn calculate_boundary_crossings(&self, location: &Location) -> bool {
+        let boundary_crossings = [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ]
+        .map(|direction| {
+            let mut current_coord = location.coord;
+            let mut is_left_up_open = true;
+            let mut is_bottom_right_open = true;
+            let mut left_up_crossings = 0;
+            let mut bottom_right_crossings = 0;
+            while let Some(coord) = calculate_offset(&direction, &current_coord) {
+                if coord.y == self.size.0 || coord.x == self.size.1 {
+                    break;
+                }
+                current_coord = coord;
+
+                let evaluated_loc = &self.locations[coord.y][coord.x];
+                if evaluated_loc.is_main_loop.is_none()
+                    || evaluated_loc.is_main_loop.is_some_and(|x| !x)
+                {
+                    continue;
+                }
+
+                if direction == Direction::Up || direction == Direction::Down {
+                    match evaluated_loc.shape {
+                        '-' => {
+                            is_left_up_open = false;
+                            is_bottom_right_open = false;
+                        }
+                        'F' => is_bottom_right_open = false,
+                        'L' => is_bottom_right_open = false,
+                        '7' => is_left_up_open = false,
+                        'J' => is_left_up_open = false,
+                        _ => (),
+                    }
+                } else if direction == Direction::Left || direction == Direction::Right {
+                    match evaluated_loc.shape {
+                        '|' => {
+                            is_left_up_open = false;
+                            is_bottom_right_open = false;
+                        }
+                        'L' => is_left_up_open = false,
+                        'J' => is_left_up_open = false,
+                        '7' => is_bottom_right_open = false,
+                        'F' => is_bottom_right_open = false,
+                        _ => (),
+                    }
+                }
+
+                if direction == Direction::Up || direction == Direction::Down {
+                    match evaluated_loc.shape {
+                        '-' => {
+                            left_up_crossings += 1;
+                            bottom_right_crossings += 1;
+                        }
+                        'F' => bottom_right_crossings += 1,
+                        'L' => bottom_right_crossings += 1,
+                        '7' => left_up_crossings += 1,
+                        'J' => left_up_crossings += 1,
+                        _ => (),
+                    }
+                } else if direction == Direction::Left || direction == Direction::Right {
+                    match evaluated_loc.shape {
+                        '|' => {
+                            left_up_crossings += 1;
+                            bottom_right_crossings += 1;
+                        }
+                        'L' => left_up_crossings += 1,
+                        'J' => left_up_crossings += 1,
+                        '7' => bottom_right_crossings += 1,
+                        'F' => bottom_right_crossings += 1,
+                        _ => (),
+                    }
+                }
+                if !is_left_up_open && !is_bottom_right_open {
+                    is_left_up_open = true;
+                    is_bottom_right_open = true;
+                }
+            }
+            // boundary_crossings
+
+            (left_up_crossings, bottom_right_crossings)
+        });
+
+        if boundary_crossings
+            .iter()
+            .any(|crossings| crossings.0 == 0 || crossings.1 == 0)
+        {
+            false
+        } else {
+            boundary_crossings
+                .iter()
+                .all(|crossing| crossing.0 % 2 == 1 && crossing.1 % 2 == 1)
+        }
+    }
+}
+
+fn calculate_offset(direction: &Direction, coord: &Coordinate) -> Option<Coordinate> {
+    match direction {
+        Direction::Up => coord.y.checked_sub(1).map(|y| Coordinate { x: coord.x, y }),
+        Direction::Down => coord.y.checked_add(1).map(|y| Coordinate { x: coord.x, y }),
+        Direction::Right => coord.x.checked_add(1).map(|x| Coordinate { x, y: coord.y }),
+        Direction::Left => coord.x.checked_sub(1).map(|x| Coordinate { x, y: coord.y }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// A rectangular loop through 'S' within scrap pipes, along with its width and height.
+    fn pipe_maze() -> impl Strategy<Value = (String, usize, usize)> {
+        (2..8usize, 2..8usize, 0..3usize, 0..3usize).prop_flat_map(|(width, height, left, top)| {
+            let (columns, rows) = (left + width + 2, top + height + 2);
+            let scrap = proptest::collection::vec("[-|LJ7F.]", columns * rows);
+            let start = 0..2 * (width + height) - 4;
+            (scrap, start).prop_map(move |(scrap, start)| {
+                let mut tiles: Vec<Vec<char>> = scrap
+                    .chunks(columns)
+                    .map(|row| {
+                        row.iter()
+                            .map(|tile| tile.chars().next().unwrap())
+                            .collect()
+                    })
+                    .collect();
+                let (right, bottom) = (left + width - 1, top + height - 1);
+                let on_edge = |x: usize, y: usize| {
+                    let (inside_x, inside_y) =
+                        ((left..=right).contains(&x), (top..=bottom).contains(&y));
+                    inside_x && inside_y && (x == left || x == right || y == top || y == bottom)
+                };
+                let edge: Vec<(usize, usize)> = (0..columns)
+                    .flat_map(|x| (0..rows).map(move |y| (x, y)))
+                    .filter(|&(x, y)| on_edge(x, y))
+                    .collect();
+                for &(x, y) in &edge {
+                    tiles[y][x] = match (x == left, x == right, y == top, y == bottom) {
+                        (true, _, true, _) => 'F',
+                        (_, true, true, _) => '7',
+                        (true, _, _, true) => 'L',
+                        (_, true, _, true) => 'J',
+                        (_, _, true, _) | (_, _, _, true) => '-',
+                        _ => '|',
+                    };
+                }
+
+                // Scrap next to 'S' must not look connected to it.
+                let (x, y) = edge[start];
+                tiles[y][x] = 'S';
+                let neighbors = [
+                    (x.wrapping_sub(1), y),
+                    (x + 1, y),
+                    (x, y.wrapping_sub(1)),
+                    (x, y + 1),
+                ];
+                for (x, y) in neighbors {
+                    if x < columns && y < rows && !edge.contains(&(x, y)) {
+                        tiles[y][x] = '.';
+                    }
+                }
+                let lines: Vec<String> = tiles.iter().map(|row| row.iter().collect()).collect();
+                (lines.join("\n"), width, height)
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn follows_generated_loops((input, width, height) in pipe_maze()) {
+            let grid = parse(&input).unwrap();
+            prop_assert_eq!(part1(&grid) as usize, width + height - 2);
+            prop_assert_eq!(part2(&grid), (width - 2) * (height - 2));
+        }
+
+        #[test]
+        fn round_trips((input, _, _) in pipe_maze()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+    }
+
+    #[test]
+    fn parses_grid() {
+        let input = ".....
+.S-7.";
+        let expected_grid: Vec<Vec<Location>> = vec![
+            vec![
+                Location {
+                    is_pipe: false,
+                    coord: Coordinate { x: 0, y: 0 },
+                    shape: '.',
+                    is_main_loop: Some(false),
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: false,
+                    coord: Coordinate { x: 1, y: 0 },
+                    shape: '.',
+                    is_main_loop: Some(false),
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: false,
+                    coord: Coordinate { x: 2, y: 0 },
+                    shape: '.',
+                    is_main_loop: Some(false),
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: false,
+                    coord: Coordinate { x: 3, y: 0 },
+                    shape: '.',
+                    is_main_loop: Some(false),
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: false,
+                    coord: Coordinate { x: 4, y: 0 },
+                    shape: '.',
+                    is_main_loop: Some(false),
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+            ],
+            vec![
+                Location {
+                    is_pipe: false,
+                    coord: Coordinate { x: 0, y: 1 },
+                    shape: '.',
+                    is_main_loop: Some(false),
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: true,
+                    coord: Coordinate { x: 1, y: 1 },
+                    shape: 'S',
+                    is_main_loop: Some(true),
+                    distance_from_start: Some(0),
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: true,
+                    coord: Coordinate { x: 2, y: 1 },
+                    shape: '-',
+                    is_main_loop: None,
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: true,
+                    coord: Coordinate { x: 3, y: 1 },
+                    shape: '7',
+                    is_main_loop: None,
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+                Location {
+                    is_pipe: false,
+                    coord: Coordinate { x: 4, y: 1 },
+                    shape: '.',
+                    is_main_loop: Some(false),
+                    distance_from_start: None,
+                    is_inside: None,
+                },
+            ],
+        ];
+
+        let grid = Grid::parse_grid(input).unwrap();
+
+        assert_eq!(grid.locations, expected_grid);
+        assert_eq!(grid.start, Coordinate { x: 1, y: 1 });
+    }
+
+    #[test]
+    fn finds_connected_neighbor() {
+        let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+        let mut grid = Grid::parse_grid(input).unwrap();
+
+        let start = &grid.start.clone();
+        let neighbors = grid.mark_neighbors(start, None);
+        assert_eq!(neighbors.len(), 2);
+
+        if let Some(first_neighbor) = neighbors.first() {
+            assert!(grid.locations[first_neighbor.y][first_neighbor.x]
+                .is_main_loop
+                .unwrap());
+            assert_eq!(
+                grid.locations[first_neighbor.y][first_neighbor.x]
+                    .distance_from_start
+                    .unwrap(),
+                1
+            );
+            let a_neighbor = grid.mark_neighbors(first_neighbor, Some(start));
+            assert_eq!(a_neighbor.len(), 1);
+        }
+
+        if let Some(second_neighbor) = neighbors.get(1) {
+            assert!(grid.locations[second_neighbor.y][second_neighbor.x]
+                .is_main_loop
+                .unwrap());
+            assert_eq!(
+                grid.locations[second_neighbor.y][second_neighbor.x]
+                    .distance_from_start
+                    .unwrap(),
+                1
+            );
+            let b_neighbor = grid.mark_neighbors(second_neighbor, Some(start));
+            assert_eq!(b_neighbor.len(), 1);
+        }
+    }
+
+    #[test]
+    fn finds_maximum_steps() {
+        let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+        let mut grid = Grid::parse_grid(input).unwrap();
+
+        grid.calculate_loop().unwrap();
+
+        let pipe_count = grid
+            .locations
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .filter(|location| location.is_main_loop.is_some_and(|x| x))
+                    .count()
+            })
+            .sum::<usize>();
+
+        assert_eq!(grid.farthes_distance, Some(4));
+        assert_eq!(pipe_count, 8);
+    }
+
+    #[test]
+    fn finds_nests() {
+        let input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+        let mut grid = Grid::parse_grid(input).unwrap();
+
+        grid.calculate_loop().unwrap();
+
+        grid.find_contained();
+
+        assert_eq!(grid.contained, Some(4));
+    }
+
+    #[test]
+    fn finds_nests_bigger() {
+        let input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+        let mut grid = Grid::parse_grid(input).unwrap();
+
+        grid.calculate_loop().unwrap();
+
+        grid.find_contained();
+
+        assert_eq!(grid.contained, Some(8));
+    }
+
+    #[test]
+    fn finds_different_nest() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+        let mut grid = Grid::parse_grid(input).unwrap();
+
+        grid.calculate_loop().unwrap();
+
+        grid.find_contained();
+
+        assert_eq!(grid.contained, Some(10));
+    }
+
+    #[test]
+    fn reports_malformed_pipes() {
+        let error = Grid::parse_grid("S-7\n|X|\nL-J").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a pipe, '.' or 'S', found 'X'"
+        );
+
+        let error = Grid::parse_grid("F-7\n|.|\nL-J").err().unwrap();
+        assert_eq!(error.line, 4);
+
+        let error = parse("S-7\n|.|\nL-.").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 2: expected a pipe continuing the loop, found '-'"
+        );
+
+        let error = parse("S-7\n..|\nL-J").err().unwrap();
+        assert_eq!(error.expected, "'S' connecting to exactly 2 pipes");
+    }
+}
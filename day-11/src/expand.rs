@@ -8,15 +8,13 @@ pub trait ExpandsSpace {
 impl ExpandsSpace for SpaceMap {
     fn collect_galaxies(&self) -> Vec<Coordinate> {
         self.grid
-            .iter()
-            .flat_map(|row| {
-                row.iter().filter_map(|loc| {
-                    if loc.observation == Observation::Galaxy {
-                        Some(loc.coordinate.clone())
-                    } else {
-                        None
-                    }
-                })
+            .cells()
+            .filter_map(|(_, loc)| {
+                if loc.observation == Observation::Galaxy {
+                    Some(loc.coordinate.clone())
+                } else {
+                    None
+                }
             })
             .collect()
     }
@@ -42,7 +40,7 @@ impl SpaceMap {
     fn counts_empty_rows_and_columns(&self) -> (Vec<usize>, Vec<usize>) {
         let empty_rows = self
             .grid
-            .iter()
+            .rows()
             .enumerate()
             .filter_map(|(y, row)| {
                 if row.iter().all(|loc| loc.observation == Observation::Space) {
@@ -53,17 +51,11 @@ impl SpaceMap {
             })
             .collect();
 
-        let empty_columns = (0..self.grid[0].len())
-            .filter_map(|x| {
-                if self
-                    .grid
-                    .iter()
-                    .all(|row| row[x].observation == Observation::Space)
-                {
-                    Some(x)
-                } else {
-                    None
-                }
+        let empty_columns = (0..self.grid.width())
+            .filter(|x| {
+                self.grid
+                    .column(*x)
+                    .all(|loc| loc.observation == Observation::Space)
             })
             .collect();
 
@@ -92,7 +84,7 @@ mod t {
 .........#...
 #....#.......";
 
-        let space_map = SpaceMap::parse_grid(input);
+        let space_map = SpaceMap::parse_grid(input).unwrap();
 
         let galaxies: Vec<Coordinate> = space_map.collect_galaxies();
 
@@ -126,7 +118,7 @@ mod t {
 .......#..
 #...#.....";
 
-        let space_map = SpaceMap::parse_grid(input);
+        let space_map = SpaceMap::parse_grid(input).unwrap();
 
         assert_eq!(
             space_map.counts_empty_rows_and_columns(),
@@ -135,7 +127,7 @@ mod t {
 
         let input = ".#.
 ...";
-        let space_map = SpaceMap::parse_grid(input);
+        let space_map = SpaceMap::parse_grid(input).unwrap();
 
         assert_eq!(
             space_map.counts_empty_rows_and_columns(),
@@ -147,7 +139,7 @@ mod t {
     fn expands_space() {
         let input = ".#..
 ...#";
-        let mut space_map = SpaceMap::parse_grid(input);
+        let mut space_map = SpaceMap::parse_grid(input).unwrap();
         space_map.expand(&2);
         let expected = vec![Coordinate { x: 2, y: 0 }, Coordinate { x: 5, y: 1 }];
         assert_eq!(space_map.expanded_galaxies, Some(expected));
@@ -26,7 +26,7 @@ mod t {
     #[test]
     fn establishes_pairs() {
         let space_map = SpaceMap {
-            grid: vec![],
+            grid: vec![].into(),
             expanded_galaxies: Some(vec![
                 Coordinate { x: 4, y: 0 },
                 Coordinate { x: 9, y: 1 },
@@ -46,7 +46,7 @@ mod t {
         assert_eq!(output.len(), 36);
 
         let space_map = SpaceMap {
-            grid: vec![],
+            grid: vec![].into(),
             expanded_galaxies: Some(vec![
                 Coordinate { x: 4, y: 0 },
                 Coordinate { x: 9, y: 1 },
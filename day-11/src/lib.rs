@@ -0,0 +1,188 @@
+use aoc_common::{Answer, ParseError, Solver};
+use aoc_grid::Grid;
+
+mod parsing;
+use parsing::ParseGrid;
+
+mod expand;
+use expand::ExpandsSpace;
+
+mod galaxy_relations;
+use galaxy_relations::PairsGalaxies;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+    type Parsed<'a> = SpaceMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<SpaceMap, ParseError> {
+    SpaceMap::parse_grid(input)
+}
+
+pub fn render(space_map: &SpaceMap) -> String {
+    space_map
+        .grid
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|location| match location.observation {
+                    Observation::Space => '.',
+                    Observation::Galaxy => '#',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(space_map: &SpaceMap) -> usize {
+    let space_map = space_map.clone().measure_distances(&2);
+    space_map.shortest_path_between_pairs.unwrap()
+}
+
+pub fn part2(space_map: &SpaceMap) -> usize {
+    let space_map = space_map.clone().measure_distances(&1000000);
+    space_map.shortest_path_between_pairs.unwrap()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Observation {
+    Space,
+    Galaxy,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+impl Coordinate {
+    fn distance_from(&self, target: &Coordinate) -> usize {
+        self.x.abs_diff(target.x) + self.y.abs_diff(target.y)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Location {
+    coordinate: Coordinate,
+    observation: Observation,
+    is_expanded: bool,
+}
+
+#[derive(Clone)]
+pub struct SpaceMap {
+    grid: Grid<Location>,
+    expanded_galaxies: Option<Vec<Coordinate>>,
+    shortest_path_between_pairs: Option<usize>,
+}
+
+impl SpaceMap {
+    pub fn calculate_galaxies(
+        input: &str,
+        space_age_multiplier: &usize,
+    ) -> Result<Self, ParseError> {
+        Ok(SpaceMap::parse_grid(input)?.measure_distances(space_age_multiplier))
+    }
+
+    pub fn measure_distances(mut self, space_age_multiplier: &usize) -> Self {
+        self.expand(space_age_multiplier);
+
+        let pairs = self.establish_pairs();
+
+        self.shortest_path_between_pairs =
+            Some(pairs.iter().map(|pair| pair.0.distance_from(&pair.1)).sum());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn image() -> impl Strategy<Value = String> {
+        (1..15usize, 1..15usize).prop_flat_map(|(width, height)| {
+            let row = proptest::collection::vec(prop_oneof![4 => Just('.'), 1 => Just('#')], width);
+            proptest::collection::vec(row, height).prop_map(|rows| {
+                let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+                rows.join("\n")
+            })
+        })
+    }
+
+    fn distances(space_map: &SpaceMap, space_age_multiplier: usize) -> usize {
+        let space_map = space_map.clone().measure_distances(&space_age_multiplier);
+        space_map.shortest_path_between_pairs.unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in image()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn grows_distances_linearly_with_age(input in image()) {
+            let space_map = parse(&input).unwrap();
+            let (young, old) = (distances(&space_map, 1), distances(&space_map, 2));
+            prop_assert_eq!(distances(&space_map, 10), young + (old - young) * 9);
+        }
+    }
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(
+            Coordinate { x: 1, y: 6 }.distance_from(&Coordinate { x: 5, y: 11 }),
+            9
+        );
+    }
+
+    #[test]
+    fn part_one_integration() {
+        let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        assert_eq!(
+            SpaceMap::calculate_galaxies(input, &2)
+                .unwrap()
+                .shortest_path_between_pairs,
+            Some(374)
+        );
+        assert_eq!(
+            SpaceMap::calculate_galaxies(input, &10)
+                .unwrap()
+                .shortest_path_between_pairs,
+            Some(1030)
+        );
+        assert_eq!(
+            SpaceMap::calculate_galaxies(input, &100)
+                .unwrap()
+                .shortest_path_between_pairs,
+            Some(8410)
+        );
+    }
+}
@@ -1,33 +1,40 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
 use crate::{Coordinate, Location, Observation, SpaceMap};
 
-pub trait ParseGrid {
-    fn parse_grid(input: &str) -> Self;
+pub trait ParseGrid: Sized {
+    fn parse_grid(input: &str) -> Result<Self, ParseError>;
 }
 
 impl ParseGrid for SpaceMap {
-    fn parse_grid(input: &str) -> Self {
-        Self {
-            grid: input
-                .lines()
-                .enumerate()
-                .map(|(y, line)| {
-                    line.chars()
-                        .enumerate()
-                        .map(|(x, ch)| Location {
-                            coordinate: Coordinate { x, y },
-                            observation: if ch == '#' {
-                                Observation::Galaxy
-                            } else {
-                                Observation::Space
-                            },
-                            is_expanded: false,
-                        })
-                        .collect()
-                })
-                .collect(),
+    fn parse_grid(input: &str) -> Result<Self, ParseError> {
+        let observations = Grid::try_parse(input, "'.' or '#'", |ch| match ch {
+            '.' => Some(Observation::Space),
+            '#' => Some(Observation::Galaxy),
+            _ => None,
+        })?;
+
+        Ok(Self {
+            grid: Grid::from_rows(
+                observations
+                    .rows()
+                    .enumerate()
+                    .map(|(y, row)| {
+                        row.iter()
+                            .enumerate()
+                            .map(|(x, observation)| Location {
+                                coordinate: Coordinate { x, y },
+                                observation: observation.clone(),
+                                is_expanded: false,
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            ),
             shortest_path_between_pairs: None,
             expanded_galaxies: None,
-        }
+        })
     }
 }
 
@@ -145,6 +152,15 @@ mod t {
             ],
         ];
 
-        assert_eq!(SpaceMap::parse_grid(input).grid, expected);
+        assert_eq!(SpaceMap::parse_grid(input).unwrap().grid, expected);
+    }
+
+    #[test]
+    fn rejects_unknown_observations() {
+        let error = SpaceMap::parse_grid("...#\n.*..").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected '.' or '#', found '*'"
+        );
     }
 }
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Solver};
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+    type Parsed<'a> = Vec<JournalLine>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<JournalLine>, ParseError> {
+    parse_lines(input, JournalLine::parse_line)
+}
+
+pub fn render(journal: &[JournalLine]) -> String {
+    journal
+        .iter()
+        .map(|j_line| {
+            let numbers: Vec<String> = j_line.numbers.iter().map(u8::to_string).collect();
+            format!("{} {}", j_line.springs, numbers.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(journal: &[JournalLine]) -> usize {
+    let mut cache = HashMap::new();
+    journal
+        .iter()
+        .map(|j_line| {
+            substring_method::find_recursively(&j_line.springs, &j_line.numbers, &mut cache)
+                .unwrap()
+        })
+        .sum()
+}
+
+pub fn part2(journal: &[JournalLine]) -> usize {
+    let unfolded = journal
+        .iter()
+        .map(|j_line| {
+            let mut j_line = j_line.clone();
+            j_line.unfold();
+            j_line
+        })
+        .collect::<Vec<_>>();
+    part1(&unfolded)
+}
+
+mod spans;
+
+mod substring_method;
+
+#[derive(Debug, PartialEq)]
+struct Span {
+    start: usize,
+    len: usize,
+}
+
+#[derive(Eq, Hash, Debug, PartialEq, Clone)]
+pub struct JournalLine {
+    pub springs: String,
+    pub numbers: Vec<u8>,
+}
+impl JournalLine {
+    pub fn parse_line(line: &str) -> Result<Self, ParseError> {
+        let mut parts = line.split_whitespace();
+        let springs = parts
+            .next()
+            .ok_or_else(|| ParseError::end_of(line, "springs"))?;
+        if let Some((index, spring)) = springs
+            .char_indices()
+            .find(|(_, spring)| !matches!(spring, '.' | '#' | '?'))
+        {
+            let token = &springs[index..index + spring.len_utf8()];
+            return Err(ParseError::at(line, token, "'.', '#' or '?'"));
+        }
+        let numbers = parts
+            .next()
+            .ok_or_else(|| ParseError::end_of(line, "group sizes"))?;
+
+        Ok(Self {
+            springs: springs.to_string(),
+            numbers: numbers
+                .split(',')
+                .map(|number| parse_number(line, number))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn unfold(&mut self) {
+        self.springs = (0..4).fold(self.springs.clone(), |acc, _| acc + "?" + &self.springs);
+        self.numbers = (0..4).fold(self.numbers.clone(), |acc, _| {
+            [acc, self.numbers.clone()].concat()
+        });
+    }
+}
+
+pub fn compute_variants(input: &str) -> Result<usize, ParseError> {
+    Ok(part1(&parse(input)?))
+}
+
+pub fn compute_unfolded_variants(input: &str) -> Result<usize, ParseError> {
+    Ok(part2(&parse(input)?))
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// A row of springs with at least one arrangement: a known row with some springs hidden.
+    fn journal_line() -> impl Strategy<Value = JournalLine> {
+        let group = (1..5u8, 1..3usize);
+        let groups = proptest::collection::vec(group, 1..4);
+        (0..3usize, groups).prop_flat_map(|(leading, groups)| {
+            let mut springs = ".".repeat(leading);
+            for (size, gap) in &groups {
+                springs.push_str(&"#".repeat(*size as usize));
+                springs.push_str(&".".repeat(*gap));
+            }
+            let numbers: Vec<u8> = groups.iter().map(|(size, _)| *size).collect();
+            let hidden = proptest::collection::vec(proptest::bool::weighted(0.4), springs.len());
+            hidden.prop_map(move |hidden| {
+                let springs = springs
+                    .chars()
+                    .zip(hidden)
+                    .map(|(spring, hidden)| if hidden { '?' } else { spring })
+                    .collect();
+                JournalLine {
+                    springs,
+                    numbers: numbers.clone(),
+                }
+            })
+        })
+    }
+
+    /// Tries every way to fill in the unknown springs.
+    fn count_arrangements(j_line: &JournalLine) -> usize {
+        let unknown = j_line.springs.matches('?').count();
+        (0..1u32 << unknown)
+            .filter(|filling| {
+                let mut bits = (0..unknown).map(|bit| filling & (1 << bit) != 0);
+                let springs: String = j_line
+                    .springs
+                    .chars()
+                    .map(|spring| match spring {
+                        '?' if bits.next().unwrap() => '#',
+                        '?' => '.',
+                        spring => spring,
+                    })
+                    .collect();
+                let groups: Vec<u8> = springs
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(|group| group.len() as u8)
+                    .collect();
+                groups == j_line.numbers
+            })
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(journal in proptest::collection::vec(journal_line(), 1..10)) {
+            prop_assert_eq!(parse(&render(&journal)).unwrap(), journal);
+        }
+
+        #[test]
+        fn counts_every_arrangement(j_line in journal_line()) {
+            prop_assume!(j_line.springs.matches('?').count() <= 14);
+            let expected = count_arrangements(&j_line);
+            prop_assert_eq!(part1(&[j_line]), expected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod t {
+    use super::*;
+
+    #[test]
+    fn expand() {
+        let line = ".# 1";
+        let mut j_line = JournalLine::parse_line(line).unwrap();
+        j_line.unfold();
+        assert_eq!(j_line.springs, ".#?.#?.#?.#?.#");
+        assert_eq!(j_line.numbers, vec![1, 1, 1, 1, 1]);
+
+        let line = "???.### 1,1,3";
+        let mut j_line = JournalLine::parse_line(line).unwrap();
+        j_line.unfold();
+        assert_eq!(j_line.springs, "???.###????.###????.###????.###????.###");
+        assert_eq!(
+            j_line.numbers,
+            vec![1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3]
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn integration() {
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        assert_eq!(compute_variants(input), Ok(21));
+        assert_eq!(compute_unfolded_variants(input), Ok(525152));
+    }
+
+    #[test]
+    fn parsing_test() {
+        let input = "???.### 1,1,3";
+
+        assert_eq!(
+            JournalLine::parse_line(input),
+            Ok(JournalLine {
+                springs: "???.###".to_string(),
+                numbers: vec![1, 1, 3]
+            })
+        );
+
+        let error = parse("???.### 1,1,3\n?#?x 1,3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected '.', '#' or '?', found \"x\""
+        );
+        let error = parse("???.### 1,,3").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+    }
+}
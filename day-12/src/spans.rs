@@ -10,17 +10,11 @@ pub(crate) fn find_spans(input: &str) -> Vec<Span> {
         }
 
         let is_next_char_end_of_span = match iter.peek() {
-            Some((_, next_char)) => {
-                if next_char != &'#' {
-                    true
-                } else {
-                    false
-                }
-            }
+            Some((_, next_char)) => next_char != &'#',
             None => true,
         };
 
-        if current_start.is_some() && is_next_char_end_of_span == true {
+        if current_start.is_some() && is_next_char_end_of_span {
             output.push(Span {
                 start: current_start.unwrap(),
                 len: index + 1 - current_start.unwrap(),
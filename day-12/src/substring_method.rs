@@ -1,36 +1,21 @@
-use std::{collections::HashMap, fs, ops::Sub};
+use std::collections::HashMap;
 
 use crate::{spans::find_spans, JournalLine};
 
-#[derive(Debug, PartialEq)]
-struct Span {
-    start: usize,
-    len: usize,
-}
-
-fn compute_variants(input: &str) -> usize {
-    let mut cache = HashMap::new();
-    input
-        .lines()
-        .map(|line| JournalLine::parse_line(line))
-        .map(|j_line| find_recursively(&j_line.springs, &j_line.numbers, &mut cache).unwrap())
-        .sum()
-}
-
 pub(crate) fn find_recursively(
     input: &str,
-    numbers: &Vec<u8>,
+    numbers: &[u8],
     cache: &mut HashMap<JournalLine, usize>,
 ) -> Option<usize> {
     let j_line = JournalLine {
         springs: input.to_string(),
-        numbers: numbers.clone(),
+        numbers: numbers.to_vec(),
     };
     if let Some(cache_result) = cache.get(&j_line) {
         return Some(*cache_result);
     };
 
-    let number = match numbers.get(0) {
+    let number = match numbers.first() {
         Some(number) => number,
         None => return Some(0), //distributed all numbers already
     };
@@ -42,7 +27,7 @@ pub(crate) fn find_recursively(
     let mut last_dot_separator = 0;
     let spans = find_spans(trimmed);
     let mut acc = 0;
-    for (index, char) in trimmed.chars().enumerate() {
+    for (index, char) in trimmed.char_indices() {
         if char == '.' {
             last_dot_separator = index;
             continue;
@@ -58,7 +43,7 @@ pub(crate) fn find_recursively(
         if leftward_string.contains('#') {
             continue;
         }
-        if rest_of_numbers.len() == 0 && potential_end_loc <= trimmed.len() {
+        if rest_of_numbers.is_empty() && potential_end_loc <= trimmed.len() {
             let leftward = &trimmed[..index];
             if leftward.contains('#') {
                 continue;
@@ -74,7 +59,7 @@ pub(crate) fn find_recursively(
                 acc += 1;
             }
         }
-        if rest_of_numbers.len() > 0 && potential_end_loc < trimmed.len() {
+        if !rest_of_numbers.is_empty() && potential_end_loc < trimmed.len() {
             if trimmed[index..potential_end_loc]
                 .chars()
                 .any(|char| char == '.')
@@ -99,7 +84,7 @@ pub(crate) fn find_recursively(
             }
         }
 
-        if let Some(span) = spans.get(0) {
+        if let Some(span) = spans.first() {
             if span.len == (*number).into() && span.start == index {
                 break;
             }
@@ -116,28 +101,28 @@ mod t {
     #[test]
     fn break_me() {
         let line = "?#?#?.? 2";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(0)
         );
 
         let line = "#????.???#?#?.? 2,2,1,2";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(2)
         );
 
         let line = "??#.???? 4";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(0)
         );
 
         let line = "#????? 4";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(1)
@@ -147,35 +132,35 @@ mod t {
     #[test]
     fn real_examples() {
         let line = "?#??#.?????.???. 4,1";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(8)
         );
 
         let line = ".?????#??.#????.. 4,4";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(3)
         );
 
         let line = "?.?#?#??#?.?#????? 4,2,5";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(6)
         );
 
         let line = "?##??.#??#.???.# 4,4,2,1";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(4)
         );
 
         let line = "#?#?.##???.?.? 4,2,1,1";
-        let j_line = JournalLine::parse_line(line);
+        let j_line = JournalLine::parse_line(line).unwrap();
         assert_eq!(
             find_recursively(&j_line.springs, &j_line.numbers, &mut HashMap::new()),
             Some(5)
@@ -191,7 +176,7 @@ mod t {
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
 
-        assert_eq!(compute_variants(input), 21);
+        assert_eq!(crate::compute_variants(input), Ok(21));
     }
 
     #[test]
@@ -199,7 +184,7 @@ mod t {
         let input = "???.### 1,1,3";
 
         assert_eq!(
-            JournalLine::parse_line(&input),
+            JournalLine::parse_line(input).unwrap(),
             JournalLine {
                 springs: "???.###".to_string(),
                 numbers: vec![1, 1, 3]
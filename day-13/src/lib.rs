@@ -0,0 +1,212 @@
+use std::fmt;
+
+use aoc_common::{Answer, ParseError, Solver};
+use aoc_grid::Grid;
+use mirrors::Mirrors;
+use parsing::Parse;
+use smudges::FixesSmudges;
+use subslice::CreatesSubslice;
+use transpose::Transposes;
+
+mod mirrors;
+mod parsing;
+mod smudges;
+mod subslice;
+mod transpose;
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+    type Parsed<'a> = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    Pattern::parse_sections(input)
+}
+
+pub fn render(patterns: &[Pattern]) -> String {
+    patterns
+        .iter()
+        .map(|pattern| pattern.data.to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+pub fn part1(patterns: &[Pattern]) -> usize {
+    let rows = find_mirror_lines(patterns);
+    let transposed: Vec<Pattern> = patterns.iter().map(|pattern| pattern.transpose()).collect();
+    let columns = find_mirror_lines(&transposed);
+    let row_sum: usize = rows.iter().map(|row| row * 100).sum();
+    let col_sum: usize = columns.iter().sum();
+    row_sum + col_sum
+}
+
+pub fn part2(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .map(|pattern| pattern.fix_smudge())
+        .map(|(rows, cols)| rows * 100 + cols)
+        .sum()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum GroundType {
+    Ash,
+    Rocks,
+}
+
+impl fmt::Display for GroundType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroundType::Ash => write!(f, "."),
+            GroundType::Rocks => write!(f, "#"),
+        }
+    }
+}
+
+impl GroundType {
+    fn switch(&self) -> Self {
+        match self {
+            GroundType::Ash => GroundType::Rocks,
+            GroundType::Rocks => GroundType::Ash,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pattern {
+    data: Grid<GroundType>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Slice {
+    data: Vec<Vec<GroundType>>,
+}
+
+pub fn find_result(input: &str) -> Result<usize, ParseError> {
+    Ok(part1(&parse(input)?))
+}
+
+fn find_mirror_lines(sections: &[Pattern]) -> Vec<usize> {
+    let mut lines = Vec::new();
+    for pattern in sections {
+        for index in 1..pattern.data.height() {
+            let slice = pattern.create_subslice(&index);
+            if slice.check_mirrorness() {
+                lines.push(index);
+                break;
+            }
+        }
+    }
+    lines
+}
+
+pub fn find_smudge_result(input: &str) -> Result<usize, ParseError> {
+    Ok(part2(&parse(input)?))
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn valley() -> impl Strategy<Value = String> {
+        let pattern = (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+            let row = proptest::collection::vec(prop_oneof![Just('.'), Just('#')], width);
+            proptest::collection::vec(row, height).prop_map(|rows| {
+                let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+                rows.join("\n")
+            })
+        });
+        proptest::collection::vec(pattern, 1..5).prop_map(|patterns| patterns.join("\n\n"))
+    }
+
+    /// The first line between rows of `lines` that every pair of rows reflects across.
+    fn reflection(lines: &[&str]) -> usize {
+        (1..lines.len())
+            .find(|&line| {
+                let (above, below) = lines.split_at(line);
+                above.iter().rev().zip(below).all(|(a, b)| a == b)
+            })
+            .unwrap_or(0)
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in valley()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn finds_reflections(input in valley()) {
+            let expected: usize = input
+                .split("\n\n")
+                .map(|pattern| {
+                    let rows: Vec<&str> = pattern.lines().collect();
+                    let columns: Vec<String> = (0..rows[0].len())
+                        .map(|x| rows.iter().map(|row| &row[x..=x]).collect())
+                        .collect();
+                    let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+                    reflection(&rows) * 100 + reflection(&columns)
+                })
+                .sum();
+            prop_assert_eq!(part1(&parse(&input).unwrap()), expected);
+        }
+    }
+}
+
+#[test]
+fn finds_smudge_score() {
+    let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    let result = find_smudge_result(input).unwrap();
+    assert_eq!(result, 400);
+}
+
+#[test]
+fn finds_score() {
+    let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    let result = find_result(input).unwrap();
+    assert_eq!(result, 405);
+}
@@ -19,7 +19,7 @@ impl Slice {
     }
 
     fn eval_counterparts_match(&self, index: &usize) -> bool {
-        let tuple = self.find_counter_parts(&index);
+        let tuple = self.find_counter_parts(index);
         tuple.0 == tuple.1
     }
 }
@@ -72,12 +72,12 @@ fn evaluates_subslice_mirrorness() {
     let input = Slice {
         data: vec![vec![GroundType::Rocks], vec![GroundType::Ash]],
     };
-    assert_eq!(input.check_mirrorness(), false);
+    assert!(!input.check_mirrorness());
 
     let input = Slice {
         data: vec![vec![GroundType::Rocks], vec![GroundType::Rocks]],
     };
-    assert_eq!(input.check_mirrorness(), true);
+    assert!(input.check_mirrorness());
 
     let input = Slice {
         data: vec![
@@ -85,7 +85,7 @@ fn evaluates_subslice_mirrorness() {
             vec![GroundType::Ash, GroundType::Rocks],
         ],
     };
-    assert_eq!(input.check_mirrorness(), false);
+    assert!(!input.check_mirrorness());
 
     let input = Slice {
         data: vec![
@@ -93,5 +93,5 @@ fn evaluates_subslice_mirrorness() {
             vec![GroundType::Rocks, GroundType::Ash],
         ],
     };
-    assert_eq!(input.check_mirrorness(), true);
+    assert!(input.check_mirrorness());
 }
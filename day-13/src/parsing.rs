@@ -1,33 +1,40 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
 use crate::{GroundType, Pattern};
 
 pub trait Parse {
-    fn parse_sections(input: &str) -> Vec<Pattern>;
+    fn parse_sections(input: &str) -> Result<Vec<Pattern>, ParseError>;
 }
 
 impl Parse for Pattern {
-    fn parse_sections(input: &str) -> Vec<Pattern> {
+    fn parse_sections(input: &str) -> Result<Vec<Pattern>, ParseError> {
+        let mut lines_before = 0;
         input
             .split("\n\n")
-            .map(|section| Pattern::parse_pattern(section))
+            .map(|section| {
+                let pattern = Pattern::parse_pattern(section).map_err(|error| {
+                    let line = error.line + lines_before;
+                    error.on_line(line)
+                });
+                lines_before += section.lines().count() + 1;
+                pattern
+            })
             .collect()
     }
 }
 
 impl Pattern {
-    fn parse_pattern(input: &str) -> Self {
-        let data = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|char| match char {
-                        '.' => GroundType::Ash,
-                        '#' => GroundType::Rocks,
-                        non_matching => panic!("{non_matching}"),
-                    })
-                    .collect()
-            })
-            .collect();
-        Self { data }
+    fn parse_pattern(input: &str) -> Result<Self, ParseError> {
+        let data = Grid::try_parse(input, "'.' or '#'", |char| match char {
+            '.' => Some(GroundType::Ash),
+            '#' => Some(GroundType::Rocks),
+            _ => None,
+        })?;
+        if data.width() == 0 {
+            return Err(ParseError::end_of("", "'.' or '#'").on_line(1));
+        }
+        Ok(Self { data })
     }
 }
 
@@ -41,9 +48,9 @@ fn parse_pattern() {
 ..##..##.
 #.#.##.#.";
 
-    let pattern = Pattern::parse_pattern(input);
-    assert_eq!(pattern.data.len(), 7);
-    assert_eq!(pattern.data[0].len(), 9);
+    let pattern = Pattern::parse_pattern(input).unwrap();
+    assert_eq!(pattern.data.height(), 7);
+    assert_eq!(pattern.data.width(), 9);
 }
 
 #[test]
@@ -63,10 +70,36 @@ fn parse_sections() {
 #####.##.
 ..##..###
 #....#..#";
-    let valley = Pattern::parse_sections(input);
+    let valley = Pattern::parse_sections(input).unwrap();
+
+    assert_eq!(valley[0].data.height(), 7);
+    assert_eq!(valley[0].data.width(), 9);
+    assert_eq!(valley[1].data.height(), 7);
+    assert_eq!(valley[1].data.width(), 9);
+}
+
+#[test]
+fn reports_line_in_valley() {
+    let input = "#.#
+.#.
+
+##.
+#x.";
+    let error = Pattern::parse_sections(input).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 5, column 2: expected '.' or '#', found 'x'"
+    );
+}
+
+#[test]
+fn rejects_empty_pattern() {
+    let error = Pattern::parse_sections("").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 1: expected '.' or '#', found end of line"
+    );
 
-    assert_eq!(valley[0].data.len(), 7);
-    assert_eq!(valley[0].data[0].len(), 9);
-    assert_eq!(valley[1].data.len(), 7);
-    assert_eq!(valley[1].data[0].len(), 9);
+    let error = Pattern::parse_sections("#.\n.#\n\n\n\n.#").unwrap_err();
+    assert_eq!(error.line, 4);
 }
@@ -1,6 +1,6 @@
-use crate::{
-    mirrors::Mirrors, parsing::Parse, subslice::CreatesSubslice, transpose::Transposes, Pattern,
-};
+#[cfg(test)]
+use crate::parsing::Parse;
+use crate::{mirrors::Mirrors, subslice::CreatesSubslice, transpose::Transposes, Pattern};
 
 pub trait FixesSmudges {
     fn fix_smudge(&self) -> (usize, usize);
@@ -10,23 +10,16 @@ impl FixesSmudges for Pattern {
     fn fix_smudge(&self) -> (usize, usize) {
         let initial_lines = self.find_both_lines((0, 0));
 
-        for (row_index, row) in self.data.iter().enumerate() {
-            for (col_index, _) in row.iter().enumerate() {
-                let mut copy = self.clone();
-                {
-                    let ground = copy
-                        .data
-                        .get_mut(row_index)
-                        .unwrap()
-                        .get_mut(col_index)
-                        .unwrap();
-                    *ground = ground.switch();
-                }
-
-                let new_lines = copy.find_both_lines(initial_lines);
-                if new_lines != (0, 0) && new_lines != initial_lines {
-                    return new_lines;
-                }
+        for (position, _) in self.data.cells() {
+            let mut copy = self.clone();
+            {
+                let ground = &mut copy.data[position];
+                *ground = ground.switch();
+            }
+
+            let new_lines = copy.find_both_lines(initial_lines);
+            if new_lines != (0, 0) && new_lines != initial_lines {
+                return new_lines;
             }
         }
         panic!()
@@ -42,9 +35,9 @@ impl Pattern {
     }
 
     fn find_mirror_line(&self, initial: usize) -> usize {
-        for index in 1..self.data.len() {
+        for index in 1..self.data.height() {
             let slice = self.create_subslice(&index);
-            if slice.check_mirrorness() == true && initial != index {
+            if slice.check_mirrorness() && initial != index {
                 return index;
             }
         }
@@ -64,7 +57,7 @@ fn real_example_2() {
 .##..##..
 .##..##..";
 
-    let mut sections = Pattern::parse_sections(input);
+    let sections = Pattern::parse_sections(input).unwrap();
 
     assert_eq!(sections[0].fix_smudge(), (0, 6));
 }
@@ -87,7 +80,7 @@ fn real_example() {
 #....#..####....#
 .####.##.#.######";
 
-    let mut sections = Pattern::parse_sections(input);
+    let sections = Pattern::parse_sections(input).unwrap();
 
     assert_eq!(sections[0].fix_smudge(), (0, 14));
 
@@ -107,7 +100,7 @@ fn real_example() {
 ..##..##......##.
 #........####....";
 
-    let mut sections = Pattern::parse_sections(input);
+    let sections = Pattern::parse_sections(input).unwrap();
 
     assert_eq!(sections[0].fix_smudge(), (0, 11));
 }
@@ -129,7 +122,7 @@ fn finds_smudge() {
 #####.##.
 ..##..###
 #....#..#";
-    let mut sections = Pattern::parse_sections(input);
+    let sections = Pattern::parse_sections(input).unwrap();
 
     assert_eq!(sections[0].fix_smudge(), (3, 0));
     assert_eq!(sections[1].fix_smudge(), (1, 0));
@@ -144,7 +137,7 @@ fn finds_both_lines() {
 ..#.##.#.
 ..##..##.
 #.#.##.#.";
-    let sections = Pattern::parse_sections(input);
+    let sections = Pattern::parse_sections(input).unwrap();
 
     assert_eq!(sections[0].find_both_lines((0, 0)), (0, 5));
     let input = ".......####..##..
@@ -162,7 +155,7 @@ fn finds_both_lines() {
 ######..#....##..
 #....#..####....#
 .####.##.#.######";
-    let sections = Pattern::parse_sections(input);
+    let sections = Pattern::parse_sections(input).unwrap();
 
     assert_eq!(sections[0].find_both_lines((0, 0)), (0, 3));
 }
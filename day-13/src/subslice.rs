@@ -1,4 +1,6 @@
-use crate::{GroundType, Pattern, Slice};
+#[cfg(test)]
+use crate::GroundType;
+use crate::{Pattern, Slice};
 
 pub trait CreatesSubslice {
     fn create_subslice(&self, cut_position: &usize) -> Slice;
@@ -8,7 +10,13 @@ impl CreatesSubslice for Pattern {
     fn create_subslice(&self, cut_position: &usize) -> Slice {
         let nearest_edge = self.find_nearest_edge(cut_position);
 
-        let subslice = self.data[cut_position - nearest_edge..cut_position + nearest_edge].to_vec();
+        let subslice = self
+            .data
+            .rows()
+            .skip(cut_position - nearest_edge)
+            .take(nearest_edge * 2)
+            .map(|row| row.to_vec())
+            .collect();
         Slice { data: subslice }
     }
 }
@@ -16,7 +24,7 @@ impl CreatesSubslice for Pattern {
 impl Pattern {
     fn find_nearest_edge(&self, cut_position: &usize) -> usize {
         let top_distance = cut_position;
-        let bottom_distance = self.data.len() - cut_position;
+        let bottom_distance = self.data.height() - cut_position;
         *top_distance.min(&bottom_distance)
     }
 }
@@ -28,7 +36,8 @@ fn finds_nearest_edges() {
             vec![GroundType::Rocks],
             vec![GroundType::Ash],
             vec![GroundType::Ash],
-        ],
+        ]
+        .into(),
     };
     assert_eq!(input.find_nearest_edge(&0), 0);
     assert_eq!(input.find_nearest_edge(&1), 1);
@@ -43,7 +52,8 @@ fn finds_subslice() {
             vec![GroundType::Rocks],
             vec![GroundType::Ash],
             vec![GroundType::Ash],
-        ],
+        ]
+        .into(),
     };
 
     assert_eq!(
@@ -1,4 +1,6 @@
-use crate::{GroundType, Pattern};
+#[cfg(test)]
+use crate::GroundType;
+use crate::Pattern;
 
 pub trait Transposes {
     fn transpose(&self) -> Self;
@@ -6,20 +8,16 @@ pub trait Transposes {
 
 impl Transposes for Pattern {
     fn transpose(&self) -> Self {
-        let len = self.data[0].len();
-
-        let data = (0..len)
-            .map(|i| self.data.iter().map(|row| row[i].clone()).collect())
-            .collect();
-
-        Self { data }
+        Self {
+            data: self.data.transpose(),
+        }
     }
 }
 
 #[test]
 fn transposes() {
     let input = Pattern {
-        data: vec![vec![GroundType::Rocks], vec![GroundType::Ash]],
+        data: vec![vec![GroundType::Rocks], vec![GroundType::Ash]].into(),
     };
 
     assert_eq!(
@@ -28,7 +26,7 @@ fn transposes() {
     );
 
     let input = Pattern {
-        data: vec![vec![GroundType::Rocks, GroundType::Ash]],
+        data: vec![vec![GroundType::Rocks, GroundType::Ash]].into(),
     };
 
     assert_eq!(
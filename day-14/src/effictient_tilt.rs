@@ -3,23 +3,23 @@ use crate::{Direction, Dish, Space, Span};
 impl Dish {
     pub fn tilt_efficiently(&mut self, direction: &Direction) {
         if *direction == Direction::North || *direction == Direction::South {
-            for x in 0..self.data[0].len() {
+            for x in 0..self.data.width() {
                 // element number x from each row
-                let column = &self.data.iter().map(|row| row[x]).collect::<Vec<_>>();
+                let column = &self.data.column(x).copied().collect::<Vec<_>>();
                 let current_spans = &self.col_spans[x];
                 let new_vector = create_new_vector(current_spans, column, direction);
 
                 // take each
                 self.data
-                    .iter_mut()
+                    .rows_mut()
                     .enumerate()
                     .for_each(|(index, row)| row[x] = new_vector[index]);
             }
         } else {
-            for y in 0..self.data.len() {
+            for y in 0..self.data.height() {
                 let current_spans = &self.row_spans[y];
                 let new_vector = create_new_vector(current_spans, &self.data[y], direction);
-                self.data[y] = new_vector;
+                self.data[y].copy_from_slice(&new_vector);
             }
         }
     }
@@ -27,7 +27,7 @@ impl Dish {
 
 fn create_new_vector(
     current_spans: &Vec<Span>,
-    vector: &Vec<Space>,
+    vector: &[Space],
     direction: &Direction,
 ) -> Vec<Space> {
     let mut new_vector = Vec::new();
@@ -64,7 +64,7 @@ impl Span {
             .collect()
     }
 
-    fn count_round(&self, vector: &Vec<Space>) -> usize {
+    fn count_round(&self, vector: &[Space]) -> usize {
         vector[self.start..self.start + self.len]
             .iter()
             .filter_map(|space| {
@@ -89,7 +89,7 @@ fn creates_new_vector() {
 
 #[test]
 fn counts_round_in_span() {
-    let vector = &vec![
+    let vector = &[
         Space::Round,
         Space::Empty,
         Space::Empty,
@@ -108,7 +108,7 @@ fn counts_round_in_span() {
 fn tilts_north_efficently() {
     let input = "O.#..#
 O.OO#.";
-    let mut dish = Dish::parse(input);
+    let mut dish = Dish::parse(input).unwrap();
     dish.tilt_efficiently(&Direction::North);
     let expected_dish = Dish {
         data: vec![
@@ -128,7 +128,8 @@ O.OO#.";
                 Space::Cube,
                 Space::Empty,
             ],
-        ],
+        ]
+        .into(),
         col_spans: Vec::new(),
         row_spans: Vec::new(),
     };
@@ -139,7 +140,7 @@ O.OO#.";
 fn tilts_west_efficently() {
     let input = "O.#..#
 O.OO#.";
-    let mut dish = Dish::parse(input);
+    let mut dish = Dish::parse(input).unwrap();
     dish.tilt_efficiently(&Direction::West);
     let expected_dish = Dish {
         data: vec![
@@ -159,7 +160,8 @@ O.OO#.";
                 Space::Cube,
                 Space::Empty,
             ],
-        ],
+        ]
+        .into(),
         col_spans: Vec::new(),
         row_spans: Vec::new(),
     };
@@ -0,0 +1,277 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use aoc_common::{Answer, ParseError, Solver};
+use aoc_grid::Grid;
+
+mod effictient_tilt;
+mod parsing;
+mod score;
+mod tilting;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+    type Parsed<'a> = Dish;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Dish, ParseError> {
+    Dish::parse(input)
+}
+
+pub fn render(dish: &Dish) -> String {
+    dish.data.to_string()
+}
+
+pub fn part1(dish: &Dish) -> usize {
+    let mut dish = dish.clone();
+    dish.tilt(&Direction::North);
+    dish.calculate_score()
+}
+
+pub fn part2(dish: &Dish) -> usize {
+    let mut dish = dish.clone();
+    dish.cycle(1000000000);
+    dish.calculate_score()
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Hash, Eq)]
+enum Space {
+    Round,
+    Cube,
+    Empty,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone)]
+struct Span {
+    start: usize,
+    len: usize,
+}
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone)]
+pub struct Dish {
+    data: Grid<Space>,
+    col_spans: Vec<Vec<Span>>,
+    row_spans: Vec<Vec<Span>>,
+}
+
+impl Dish {
+    #[allow(unused)]
+    fn print(&self) {
+        aoc_common::diagnostic!("{}\n", self.data);
+    }
+
+    pub fn cycle(&mut self, repeats: u32) {
+        let mut set = HashSet::new();
+        let mut rep = 0;
+        let mut cycles = 0;
+        let mut prev_cycles = 0;
+        while rep < repeats {
+            aoc_common::diagnostic!("{rep}");
+            self.tilt(&Direction::North);
+            // println!("  North");
+            // self.print();
+
+            //west
+            self.tilt(&Direction::West);
+            // println!("  West");
+            // self.print();
+
+            //south
+            self.tilt(&Direction::South);
+            // println!("  South");
+            // self.print();
+
+            //east
+            self.tilt(&Direction::East);
+            // println!("  East");
+            // self.print();
+
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            let hash = hasher.finish();
+
+            // println!("  North");
+            // self.print();
+
+            rep += 1;
+
+            let is_new = set.insert(hash);
+            if !is_new {
+                aoc_common::diagnostic!("Repeat! {rep} {cycles}");
+                set.clear();
+                set.insert(hash);
+                if prev_cycles == cycles && prev_cycles > 0 {
+                    let skippable = (repeats - rep) / cycles;
+                    rep += skippable * cycles;
+                }
+                prev_cycles = cycles;
+                cycles = 1;
+            } else {
+                cycles += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn platform() -> impl Strategy<Value = String> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            let space = prop_oneof![2 => Just('.'), 1 => Just('O'), 1 => Just('#')];
+            let row = proptest::collection::vec(space, width);
+            proptest::collection::vec(row, height).prop_map(|rows| {
+                let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+                rows.join("\n")
+            })
+        })
+    }
+
+    /// Rolls the rocks one row at a time, like the puzzle describes it.
+    fn tilt_north(rows: &mut [Vec<char>]) {
+        for y in 1..rows.len() {
+            for x in 0..rows[y].len() {
+                let mut rock = y;
+                while rock > 0 && rows[rock][x] == 'O' && rows[rock - 1][x] == '.' {
+                    rows[rock - 1][x] = 'O';
+                    rows[rock][x] = '.';
+                    rock -= 1;
+                }
+            }
+        }
+    }
+
+    fn load(rows: &[Vec<char>]) -> usize {
+        rows.iter()
+            .enumerate()
+            .map(|(y, row)| row.iter().filter(|&&space| space == 'O').count() * (rows.len() - y))
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in platform()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn rolls_rocks_north(input in platform()) {
+            let mut rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            tilt_north(&mut rows);
+            prop_assert_eq!(part1(&parse(&input).unwrap()), load(&rows));
+        }
+    }
+}
+
+#[test]
+fn cycles() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let expected_1_cycle = Dish::parse(
+        ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+    )
+    .unwrap();
+
+    let mut dish = Dish::parse(input).unwrap();
+
+    dish.cycle(1);
+
+    assert_eq!(dish, expected_1_cycle);
+
+    let expected_3_cycle = Dish::parse(
+        ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O",
+    )
+    .unwrap();
+    let mut dish = Dish::parse(input).unwrap();
+
+    dish.cycle(3);
+
+    assert_eq!(dish, expected_3_cycle);
+}
+
+#[test]
+fn integration() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let mut dish = Dish::parse(input).unwrap();
+    dish.tilt(&Direction::North);
+    assert_eq!(dish.calculate_score(), 136);
+}
+
+#[test]
+fn integration_big() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let mut dish = Dish::parse(input).unwrap();
+    dish.cycle(1000000000);
+    assert_eq!(dish.calculate_score(), 64);
+}
@@ -1,12 +1,17 @@
+use std::fmt;
+
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
 use crate::{Dish, Space, Span};
 
 impl Space {
-    fn from(char: char) -> Self {
+    fn from(char: char) -> Option<Self> {
         match char {
-            'O' => Self::Round,
-            '.' => Self::Empty,
-            '#' => Self::Cube,
-            _ => panic!(),
+            'O' => Some(Self::Round),
+            '.' => Some(Self::Empty),
+            '#' => Some(Self::Cube),
+            _ => None,
         }
     }
 
@@ -19,31 +24,34 @@ impl Space {
     }
 }
 
+impl fmt::Display for Space {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.char())
+    }
+}
+
 impl Dish {
-    pub fn parse(input: &str) -> Self {
-        let data: Vec<Vec<Space>> = input
-            .lines()
-            .map(|line| line.chars().map(|char| Space::from(char)).collect())
-            .collect();
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let data = Grid::try_parse(input, "'O', '.' or '#'", Space::from)?;
 
-        let col_spans = (0..data[0].len())
+        let col_spans = (0..data.width())
             .map(|x| {
-                let spaces = data.iter().map(|row| row[x]).collect::<Vec<_>>();
+                let spaces = data.column(x).copied().collect::<Vec<_>>();
                 get_spans(&spaces)
             })
             .collect();
 
-        let row_spans = data.iter().map(|row| get_spans(&row)).collect();
+        let row_spans = data.rows().map(get_spans).collect();
 
-        Self {
+        Ok(Self {
             data,
             col_spans,
             row_spans,
-        }
+        })
     }
 }
 
-fn get_spans(spaces: &Vec<Space>) -> Vec<Span> {
+fn get_spans(spaces: &[Space]) -> Vec<Span> {
     let mut spans = Vec::new();
     let mut span_start = 0;
     for (index, space) in spaces.iter().enumerate() {
@@ -65,7 +73,7 @@ fn get_spans(spaces: &Vec<Space>) -> Vec<Span> {
 fn parses_input() {
     let input = "O....#
 O.OO#.";
-    let dish = Dish::parse(&input);
+    let dish = Dish::parse(input).unwrap();
     assert_eq!(
         dish.data,
         vec![
@@ -107,3 +115,12 @@ O.OO#.";
         ]
     );
 }
+
+#[test]
+fn rejects_unknown_spaces() {
+    let error = Dish::parse("O....#\nO.0O#.").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 3: expected 'O', '.' or '#', found '0'"
+    );
+}
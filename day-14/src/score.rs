@@ -2,9 +2,9 @@ use crate::{Dish, Space};
 
 impl Dish {
     pub fn calculate_score(&self) -> usize {
-        let len = self.data.len();
+        let len = self.data.height();
         self.data
-            .iter()
+            .rows()
             .enumerate()
             .map(|(index, line)| {
                 (len - index) * line.iter().filter(|space| **space == Space::Round).count()
@@ -25,6 +25,6 @@ O..#.OO...
 ..O.......
 #....###..
 #....#....";
-    let dish = Dish::parse(&input);
+    let dish = Dish::parse(input).unwrap();
     assert_eq!(dish.calculate_score(), 136);
 }
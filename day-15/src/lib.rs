@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse_number, Answer, ParseError, Solver};
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// Keeps the steps as they are for hashing, but makes sure every one of them
+/// is a label followed by `-` or `=` and a focal length.
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    let line = input.trim_end();
+    line.split(',')
+        .map(|step| check_step(line, step).map(|_| step))
+        .collect::<Result<_, _>>()
+        .map_err(|error| error.on_line(1))
+}
+
+pub fn render(steps: &[&str]) -> String {
+    steps.join(",")
+}
+
+fn check_step(line: &str, step: &str) -> Result<(), ParseError> {
+    let command_index = step
+        .find(['-', '='])
+        .ok_or_else(|| ParseError::at(line, step, "a label followed by '-' or '='"))?;
+    let label = &step[..command_index];
+    if label.is_empty() || !label.chars().all(|char| char.is_ascii_lowercase()) {
+        return Err(ParseError::at(line, label, "a label of lowercase letters"));
+    }
+    let rest = &step[command_index + 1..];
+    if step[command_index..].starts_with('=') {
+        parse_number::<u8>(line, rest)?;
+    } else if !rest.is_empty() {
+        return Err(ParseError::at(line, rest, "',' after '-'"));
+    }
+    Ok(())
+}
+
+pub fn part1(steps: &[&str]) -> u32 {
+    steps.iter().map(|step| ascii_hash(step) as u32).sum()
+}
+
+pub fn part2(steps: &[&str]) -> u64 {
+    let boxes = arrange_lenses(steps);
+    boxes
+        .iter()
+        .map(|(k, lens_box)| -> u64 {
+            lens_box
+                .lenses
+                .iter()
+                .enumerate()
+                .map(|(index, lens)| (*k as u64 + 1) * (1 + index as u64) * lens.strenght as u64)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+#[derive(Debug, PartialEq)]
+struct LensBox {
+    lenses: Vec<Lens>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Lens {
+    id: String,
+    strenght: u8,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Upsert,
+    Delete,
+}
+
+#[cfg(test)]
+fn order_lenses_into_boxes(input: &str) -> HashMap<u8, LensBox> {
+    arrange_lenses(&parse(input).unwrap())
+}
+
+fn arrange_lenses(steps: &[&str]) -> HashMap<u8, LensBox> {
+    let mut boxes: HashMap<u8, LensBox> = HashMap::new();
+    steps.iter().for_each(|step| {
+        if let Some(command_index) = step.find(['-', '=']) {
+            let id = step[..command_index].to_string();
+            let box_hash = ascii_hash(&id);
+
+            let command = if step.chars().nth(command_index).unwrap() == '=' {
+                Command::Upsert
+            } else {
+                Command::Delete
+            };
+
+            match boxes.get_mut(&box_hash) {
+                Some(lens_box) => {
+                    if command == Command::Delete {
+                        if let Some(lens_index) =
+                            lens_box.lenses.iter().position(|lens| lens.id == id)
+                        {
+                            lens_box.lenses.remove(lens_index);
+                        };
+                    }
+                    if command == Command::Upsert {
+                        let strenght = step[command_index + 1..].parse().unwrap();
+
+                        if let Some(lens) = lens_box.lenses.iter_mut().find(|lens| lens.id == id) {
+                            // update
+                            lens.strenght = strenght;
+                        } else {
+                            // insert
+                            lens_box.lenses.push(Lens { id, strenght });
+                        };
+                    }
+                }
+                None => {
+                    if command == Command::Upsert {
+                        let strenght = step[command_index + 1..].parse().unwrap();
+                        boxes.insert(
+                            box_hash,
+                            LensBox {
+                                lenses: vec![Lens { id, strenght }],
+                            },
+                        );
+                    }
+                }
+            }
+        };
+    });
+    boxes
+}
+
+pub fn sum_focusing_powers(input: &str) -> Result<u64, ParseError> {
+    Ok(part2(&parse(input)?))
+}
+
+pub fn sum_comma_separated(input: &str) -> Result<u32, ParseError> {
+    Ok(part1(&parse(input)?))
+}
+
+fn ascii_hash(input: &str) -> u8 {
+    input
+        .chars()
+        .fold(0, |acc, char| (acc + (char as u16)) * 17 % 256) as u8
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn initialization_sequence() -> impl Strategy<Value = String> {
+        let step = "[a-z]{1,6}(-|=[1-9])";
+        proptest::collection::vec(step, 1..30).prop_map(|steps| steps.join(","))
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in initialization_sequence()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn ignores_trailing_newline(input in initialization_sequence()) {
+            let steps = parse(&input).unwrap();
+            let with_newline = format!("{input}\n");
+            let steps_with_newline = parse(&with_newline).unwrap();
+            prop_assert_eq!(part1(&steps_with_newline), part1(&steps));
+            prop_assert_eq!(part2(&steps_with_newline), part2(&steps));
+        }
+    }
+}
+
+#[test]
+fn integration() {
+    let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    assert_eq!(sum_focusing_powers(input), Ok(145));
+}
+
+#[test]
+fn removes_box() {
+    let input = "rn=1,cm-,qp=3,cm=2,qp-";
+    assert_eq!(
+        order_lenses_into_boxes(input),
+        HashMap::from([
+            (
+                0,
+                LensBox {
+                    lenses: vec![
+                        Lens {
+                            id: String::from("rn"),
+                            strenght: 1
+                        },
+                        Lens {
+                            id: String::from("cm"),
+                            strenght: 2
+                        }
+                    ],
+                }
+            ),
+            (1, LensBox { lenses: Vec::new() })
+        ])
+    );
+}
+
+#[test]
+fn creates_box() {
+    let input = "rn=1";
+    assert_eq!(
+        order_lenses_into_boxes(input),
+        HashMap::from([(
+            0,
+            LensBox {
+                lenses: vec![Lens {
+                    id: String::from("rn"),
+                    strenght: 1
+                }]
+            }
+        )])
+    );
+}
+
+#[test]
+fn hashes() {
+    let input = "HASH";
+
+    assert_eq!(ascii_hash(input), 52);
+    assert_eq!(ascii_hash("rn"), 0);
+    assert_eq!(ascii_hash("cm"), 0);
+}
+
+#[test]
+fn part1_example() {
+    let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    assert_eq!(sum_comma_separated(input), Ok(1320));
+}
+
+#[test]
+fn reports_malformed_steps() {
+    let error = parse("rn=1,cm-,qp=x\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 13: expected a number, found \"x\""
+    );
+
+    let error = parse("rn=1,cm").unwrap_err();
+    assert_eq!(error.column, 6);
+    let error = parse("rn=1,=4").unwrap_err();
+    assert_eq!(error.found, "nothing");
+}
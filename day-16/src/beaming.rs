@@ -1,11 +1,15 @@
-use crate::{Beam, Grid, Location, Mirror};
+use aoc_grid::ORTHOGONAL;
+
+use crate::{Beam, Grid};
+#[cfg(test)]
+use crate::{Location, Mirror};
 
 impl Beam {
     pub fn project(&self, grid: &mut Grid) -> Vec<Beam> {
         let mut current_x = self.start_x;
         let mut current_y = self.start_y;
         {
-            let loc = &mut grid.data[current_y][current_x];
+            let loc = &mut grid.data[(current_x, current_y)];
             loc.is_energized = true;
         }
 
@@ -15,31 +19,14 @@ impl Beam {
             }
         }
 
-        let (y_offset, x_offset) = match self.angle {
-            180 => (1, 0),
-            0 => (-1, 0),
-            90 => (0, 1),
-            270 => (0, -1),
-            _ => panic!(),
-        };
-
-        if x_offset != 0 {
-            while let Some(new_x) = get_coords(current_x, x_offset, grid.data[0].len()) {
-                current_x = new_x;
-                if let Some(beams) = self.resolve_location(grid, current_y, current_x) {
-                    return beams;
-                }
-            }
-        };
-
-        if y_offset != 0 {
-            while let Some(new_y) = get_coords(current_y, y_offset, grid.data.len()) {
-                current_y = new_y;
-                if let Some(beams) = self.resolve_location(grid, current_y, current_x) {
-                    return beams;
-                }
+        // Beams only travel up, right, down or left, in steps of 90 degrees from up
+        let step = ORTHOGONAL[usize::from(self.angle / 90)];
+        while let Some((x, y)) = grid.data.step((current_x, current_y), step) {
+            (current_x, current_y) = (x, y);
+            if let Some(beams) = self.resolve_location(grid, current_y, current_x) {
+                return beams;
             }
-        };
+        }
         vec![]
     }
 
@@ -51,7 +38,7 @@ impl Beam {
     ) -> Option<Vec<Beam>> {
         // println!("");
         // grid.print();
-        let loc = &mut grid.data[current_y][current_x];
+        let loc = &mut grid.data[(current_x, current_y)];
         loc.is_energized = true;
         if let Some(mirror) = &loc.mirror {
             if self.angle != mirror.angle && self.angle != mirror.angle + 180 {
@@ -87,17 +74,6 @@ impl Beam {
 fn calculate_angle(beam: u16, mirror: u16) -> u16 {
     (360 + mirror * 2 - beam) % 360
 }
-fn get_coords(index: usize, offset: i32, len: usize) -> Option<usize> {
-    let adjusted_index = if offset.is_negative() {
-        index.checked_sub(offset.wrapping_abs() as usize)
-    } else {
-        index.checked_add(offset as usize)
-    };
-    match adjusted_index {
-        Some(new_index) if new_index < len => Some(new_index),
-        _ => None,
-    }
-}
 
 #[test]
 fn calculates_angles() {
@@ -139,7 +115,8 @@ fn starts_on_mirror() {
                     is_energized: false,
                 },
             ],
-        ],
+        ]
+        .into(),
     };
 
     let beams = beam.project(&mut grid);
@@ -207,7 +184,8 @@ fn energises_and_splits() {
                 mirror: None,
                 is_energized: false,
             },
-        ]],
+        ]]
+        .into(),
     };
     let beams = beam.project(&mut grid);
     assert_eq!(
@@ -23,9 +23,9 @@ impl Grid {
         // println!("");
         // self.print();
         self.data
-            .iter()
-            .map(|row| row.iter().filter(|loc| loc.is_energized).count())
-            .sum()
+            .cells()
+            .filter(|(_, loc)| loc.is_energized)
+            .count()
     }
 }
 
@@ -41,6 +41,6 @@ fn integration() {
 .-.-/..|..
 .|....-|.\\
 ..//.|....";
-    let mut grid = Grid::from(input);
+    let mut grid = Grid::from(input).unwrap();
     assert_eq!(grid.calculate(0, 0, 90), 46);
 }
@@ -0,0 +1,190 @@
+use aoc_common::{Answer, ParseError, Solver};
+
+mod beaming;
+mod calculation;
+mod parsing;
+mod printing;
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+    type Parsed<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    Grid::from(input)
+}
+
+pub fn render(grid: &Grid) -> String {
+    grid.data.to_string()
+}
+
+pub fn part1(grid: &Grid) -> usize {
+    grid.clone().calculate(0, 0, 90)
+}
+
+pub fn part2(grid: &Grid) -> usize {
+    grid.clone().calculate_brute_force()
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Beam {
+    start_x: usize,
+    start_y: usize,
+    angle: u16,
+    is_starting: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Mirror {
+    angle: u16,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Location {
+    mirror: Option<Mirror>,
+    is_energized: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Grid {
+    data: aoc_grid::Grid<Location>,
+}
+
+impl Grid {
+    pub fn calculate_brute_force(&mut self) -> usize {
+        let mut max = 0;
+
+        let y_len = self.data.height();
+        let x_len = self.data.width();
+
+        for y in 0..y_len {
+            let result = self.clone().calculate(0, y, 90);
+            update_max(result, &mut max);
+        }
+
+        for x in 0..x_len {
+            let result = self.clone().calculate(x, y_len - 1, 0);
+            update_max(result, &mut max);
+        }
+
+        for y in 0..y_len {
+            let result = self.clone().calculate(x_len - 1, y, 270);
+            update_max(result, &mut max);
+        }
+
+        for x in 0..x_len {
+            let result = self.clone().calculate(x, 0, 180);
+            update_max(result, &mut max);
+        }
+
+        max
+    }
+}
+
+fn update_max(result: usize, max: &mut usize) {
+    if result > *max {
+        *max = result;
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn contraption() -> impl Strategy<Value = String> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            let tile = prop_oneof![
+                6 => Just('.'),
+                1 => Just('|'),
+                1 => Just('-'),
+                1 => Just('/'),
+                1 => Just('\\'),
+            ];
+            let row = proptest::collection::vec(tile, width);
+            proptest::collection::vec(row, height).prop_map(|rows| {
+                let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+                rows.join("\n")
+            })
+        })
+    }
+
+    /// Follows the beam one tile at a time, from the top left heading right.
+    fn energized(input: &str) -> usize {
+        let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let mut seen = HashSet::new();
+        let mut beams = vec![((0isize, 0isize), (1isize, 0isize))];
+        while let Some(((x, y), (dx, dy))) = beams.pop() {
+            let Some(tile) = usize::try_from(y)
+                .ok()
+                .and_then(|y| rows.get(y))
+                .and_then(|row| row.get(usize::try_from(x).ok()?))
+            else {
+                continue;
+            };
+            if !seen.insert(((x, y), (dx, dy))) {
+                continue;
+            }
+            let directions = match (tile, dx) {
+                ('/', _) => vec![(-dy, -dx)],
+                ('\\', _) => vec![(dy, dx)],
+                ('|', 0) | ('-', 1 | -1) | ('.', _) => vec![(dx, dy)],
+                ('|', _) => vec![(0, -1), (0, 1)],
+                _ => vec![(-1, 0), (1, 0)],
+            };
+            for (dx, dy) in directions {
+                beams.push(((x + dx, y + dy), (dx, dy)));
+            }
+        }
+        seen.iter()
+            .map(|(position, _)| position)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in contraption()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn energizes_tiles_on_the_beam(input in contraption()) {
+            prop_assert_eq!(part1(&parse(&input).unwrap()), energized(&input));
+        }
+    }
+}
+
+#[test]
+fn integration_part2() {
+    let input = ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....";
+    let mut grid = Grid::from(input).unwrap();
+
+    assert_eq!(grid.calculate_brute_force(), 51);
+}
@@ -1,38 +1,27 @@
+use aoc_common::ParseError;
+
 use crate::{Grid, Location, Mirror};
 
 impl Grid {
-    pub fn from(input: &str) -> Self {
-        let data = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|char| match char {
-                        '.' => Location {
-                            mirror: None,
-                            is_energized: false,
-                        },
-                        '|' => Location {
-                            mirror: Some(Mirror { angle: 0 }),
-                            is_energized: false,
-                        },
-                        '-' => Location {
-                            mirror: Some(Mirror { angle: 90 }),
-                            is_energized: false,
-                        },
-                        '/' => Location {
-                            mirror: Some(Mirror { angle: 45 }),
-                            is_energized: false,
-                        },
-                        '\\' => Location {
-                            mirror: Some(Mirror { angle: 135 }),
-                            is_energized: false,
-                        },
-                        _ => panic!("{char}"),
-                    })
-                    .collect()
+    pub fn from(input: &str) -> Result<Self, ParseError> {
+        let data = aoc_grid::Grid::try_parse(input, "'.', '|', '-', '/' or '\\'", |char| {
+            let angle = match char {
+                '.' => None,
+                '|' => Some(0),
+                '-' => Some(90),
+                '/' => Some(45),
+                '\\' => Some(135),
+                _ => return None,
+            };
+            Some(Location {
+                mirror: angle.map(|angle| Mirror { angle }),
+                is_energized: false,
             })
-            .collect();
-        Self { data }
+        })?;
+        if data.width() == 0 {
+            return Err(ParseError::new(1, "a tile", "end of input").on_line(1));
+        }
+        Ok(Self { data })
     }
 }
 
@@ -42,7 +31,7 @@ fn parses_grid() {
 |.-.\\.";
 
     assert_eq!(
-        Grid::from(input),
+        Grid::from(input).unwrap(),
         Grid {
             data: vec![
                 vec![
@@ -98,6 +87,26 @@ fn parses_grid() {
                     }
                 ]
             ]
+            .into()
         }
     )
 }
+
+#[test]
+fn rejects_unknown_tiles() {
+    let error = Grid::from(".|./.\\\n|.+.\\.").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 3: expected '.', '|', '-', '/' or '\\', found '+'"
+    );
+}
+
+#[test]
+fn rejects_empty_grid() {
+    let error = Grid::from("").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 1: expected a tile, found end of input"
+    );
+    assert!(Grid::from("\n").is_err());
+}
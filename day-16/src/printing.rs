@@ -1,4 +1,19 @@
-use crate::Grid;
+use std::fmt;
+
+use crate::{Grid, Location};
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tile = match self.mirror.as_ref().map(|mirror| mirror.angle) {
+            None => '.',
+            Some(0) => '|',
+            Some(90) => '-',
+            Some(45) => '/',
+            Some(_) => '\\',
+        };
+        write!(f, "{tile}")
+    }
+}
 
 impl Grid {
     // fn print(&self) -> Result<()> {
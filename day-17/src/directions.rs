@@ -17,27 +17,11 @@ impl Direction {
             Direction::Up,
         ])
     }
-
-    pub fn n_full_sets(steps: &u8) -> HashMap<u8, HashSet<Self>> {
-        let mut map = HashMap::new();
-        for i in 1..*steps + 1 {
-            map.insert(
-                i,
-                HashSet::from([
-                    Direction::Right,
-                    Direction::Down,
-                    Direction::Left,
-                    Direction::Up,
-                ]),
-            );
-        }
-        map
-    }
 }
 
 impl Node {
-    pub fn sort_by_score(nodes: &mut Vec<Node>) {
-        nodes.sort_by(|b, a| a.heuristic_current_score.cmp(&b.heuristic_current_score));
+    pub fn sort_by_score(nodes: &mut [Node]) {
+        nodes.sort_by_key(|node| std::cmp::Reverse(node.heuristic_current_score));
     }
 
     pub fn find_directions(&self, max_in_direction: &u8, min_in_direction: &u8) -> Vec<Direction> {
@@ -137,7 +121,7 @@ fn finds_valid_directions() {
 }
 
 pub fn get_dir_count(
-    prev_directions: &Vec<Direction>,
+    prev_directions: &[Direction],
     direction: &Direction,
     max_repeat: &usize,
 ) -> u8 {
@@ -147,7 +131,7 @@ pub fn get_dir_count(
         .take_while(|dir| *dir == direction);
     let mut current = 0;
     for i in 1..*max_repeat + 1 {
-        if let Some(dir) = iter.next() {
+        if let Some(_dir) = iter.next() {
             current = i as u8;
         }
     }
@@ -156,10 +140,10 @@ pub fn get_dir_count(
 
 #[test]
 fn get_right_dir_counts() {
-    assert_eq!(get_dir_count(&vec![Direction::Up], &Direction::Up, &3), 1);
+    assert_eq!(get_dir_count(&[Direction::Up], &Direction::Up, &3), 1);
     assert_eq!(
         get_dir_count(
-            &vec![Direction::Up, Direction::Up, Direction::Up, Direction::Up],
+            &[Direction::Up, Direction::Up, Direction::Up, Direction::Up],
             &Direction::Up,
             &3
         ),
@@ -0,0 +1,250 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, ParseError, Solver};
+use aoc_grid::ORTHOGONAL;
+use parsing::parse_heat_losses;
+
+mod directions;
+mod neighbors;
+mod parsing;
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u8 = 17;
+    type Parsed<'a> = aoc_grid::Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// Reads the heat loss of every city block, the grid itself depends on the crucible.
+pub fn parse(input: &str) -> Result<aoc_grid::Grid<u8>, ParseError> {
+    parse_heat_losses(input)
+}
+
+pub fn render(heat_losses: &aoc_grid::Grid<u8>) -> String {
+    heat_losses.to_string()
+}
+
+pub fn part1(heat_losses: &aoc_grid::Grid<u8>) -> u64 {
+    Grid::build(heat_losses, &1, &3).find_path()
+}
+
+pub fn part2(heat_losses: &aoc_grid::Grid<u8>) -> u64 {
+    Grid::build(heat_losses, &4, &10).find_path()
+}
+
+pub struct Grid {
+    data: HashMap<(usize, Direction), aoc_grid::Grid<Node>>,
+    min_repeat: u8,
+    max_repeat: u8,
+}
+
+#[derive(Eq, Hash, Debug, PartialEq, Clone)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy, Default)]
+enum Direction {
+    #[default]
+    Right,
+    Down,
+    Up,
+    Left,
+}
+
+impl Direction {
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => ORTHOGONAL[0],
+            Direction::Right => ORTHOGONAL[1],
+            Direction::Down => ORTHOGONAL[2],
+            Direction::Left => ORTHOGONAL[3],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Node {
+    current_score: Option<u64>,
+    heuristic_current_score: Option<u64>,
+    heat_loss: u8,
+    is_target: bool,
+    prev_directions: Vec<Direction>,
+    coord: Coordinate,
+    is_visited: bool,
+}
+
+impl Grid {
+    pub fn find_path(&mut self) -> u64 {
+        let mut unvisited = vec![self.data.get(&(1, Direction::Right)).unwrap()[0][0].clone()];
+        let _max_x = 0;
+        let _max_y = 0;
+        while let Some(next_node) = unvisited.pop() {
+            // if next_node.coord.x > max_x {
+            //     max_x = next_node.coord.x;
+            //     println!("{:?}", next_node.coord);
+            // }
+            // if next_node.coord.y > max_y {
+            //     max_y = next_node.coord.y;
+            //     println!("{:?}", next_node.coord);
+            // }
+
+            // if next_node.coord.x == 6 && next_node.coord.y == 0 {
+            //     println!();
+            // };
+            match self.calculate_neighbors(&next_node) {
+                (_, Some(result)) => {
+                    // println!("{result}");
+                    aoc_common::diagnostic!("{:?}", next_node.prev_directions);
+                    return result;
+                }
+                (new_unvisited, None) => {
+                    // let filtered_unvisited = new_unvisited
+                    //     .iter()
+                    //     .filter(|new| {
+                    //         unvisited
+                    //             .iter()
+                    //             .any(|old| old.prev_directions == new.prev_directions)
+                    //             == false
+                    //     })
+                    //     .map(|elem| elem.clone())
+                    //     .collect::<Vec<Node>>();
+                    let mut next = [unvisited.as_slice(), &new_unvisited].concat();
+                    Node::sort_by_score(&mut next);
+                    unvisited = next;
+                }
+            }
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use std::{cmp::Reverse, collections::BinaryHeap};
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn city() -> impl Strategy<Value = String> {
+        (2..8usize, 2..8usize).prop_flat_map(|(width, height)| {
+            let row = proptest::collection::vec(1..10u8, width);
+            proptest::collection::vec(row, height).prop_map(|rows| {
+                let rows: Vec<String> = rows
+                    .iter()
+                    .map(|row| row.iter().map(u8::to_string).collect())
+                    .collect();
+                rows.join("\n")
+            })
+        })
+    }
+
+    /// Dijkstra over position, heading and how many blocks the crucible went straight.
+    fn least_heat_loss(heat_losses: &aoc_grid::Grid<u8>, max_straight: u8) -> u64 {
+        let target = (heat_losses.width() - 1, heat_losses.height() - 1);
+        let mut best = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((0, (0, 0), None, 0))]);
+        while let Some(Reverse((loss, position, heading, straight))) = queue.pop() {
+            if position == target {
+                return loss;
+            }
+            if best.insert((position, heading, straight), loss).is_some() {
+                continue;
+            }
+            for (turn, offset) in ORTHOGONAL.iter().enumerate() {
+                let straight = match heading {
+                    Some(heading) if heading == turn => straight + 1,
+                    Some(heading) if (heading + 2) % 4 == turn => continue,
+                    _ => 1,
+                };
+                if straight > max_straight {
+                    continue;
+                }
+                if let Some(next) = heat_losses.step(position, *offset) {
+                    let loss = loss + heat_losses[next] as u64;
+                    queue.push(Reverse((loss, next, Some(turn), straight)));
+                }
+            }
+        }
+        unreachable!("Every block of the city can be reached")
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in city()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn finds_least_heat_loss(input in city()) {
+            let heat_losses = parse(&input).unwrap();
+            prop_assert_eq!(part1(&heat_losses), least_heat_loss(&heat_losses, 3));
+        }
+    }
+}
+
+#[test]
+fn integration() {
+    let input = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+    let mut grid = Grid::parse(input, &1, &3).unwrap();
+
+    let result = grid.find_path();
+    assert_eq!(result, 102);
+}
+
+#[test]
+fn integration_ultra_crucible() {
+    let input = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+    let mut grid = Grid::parse(input, &4, &10).unwrap();
+
+    let result = grid.find_path();
+    assert_eq!(result, 94);
+
+    let input = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+    let mut grid = Grid::parse(input, &4, &10).unwrap();
+
+    let result = grid.find_path();
+    assert_eq!(result, 71);
+}
@@ -1,30 +1,37 @@
 use super::*;
 
 impl Grid {
-    pub fn parse(input: &str, min_repeat: &u8, max_repeat: &u8) -> Self {
-        let layer: Vec<Vec<Node>> = input
-            .lines()
-            .enumerate()
-            .map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, char)| {
-                        let heat_loss = char.to_digit(10).unwrap() as u8;
-                        Node {
+    pub fn parse(input: &str, min_repeat: &u8, max_repeat: &u8) -> Result<Self, ParseError> {
+        Ok(Self::build(
+            &parse_heat_losses(input)?,
+            min_repeat,
+            max_repeat,
+        ))
+    }
+
+    pub fn build(heat_losses: &aoc_grid::Grid<u8>, min_repeat: &u8, max_repeat: &u8) -> Self {
+        let layer = aoc_grid::Grid::from_rows(
+            heat_losses
+                .rows()
+                .enumerate()
+                .map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(x, heat_loss)| Node {
                             current_score: None,
                             heuristic_current_score: None,
-                            heat_loss,
+                            heat_loss: *heat_loss,
                             is_target: false,
                             prev_directions: Vec::new(),
                             coord: Coordinate { x, y },
                             is_visited: false,
-                        }
-                    })
-                    .collect()
-            })
-            .collect();
+                        })
+                        .collect()
+                })
+                .collect(),
+        );
 
-        let (x_len, y_len) = { (layer[0].len(), layer.len()) };
+        let (x_len, y_len) = { (layer.width(), layer.height()) };
 
         let mut data = HashMap::new();
 
@@ -50,6 +57,16 @@ impl Grid {
     }
 }
 
+pub fn parse_heat_losses(input: &str) -> Result<aoc_grid::Grid<u8>, ParseError> {
+    let heat_losses = aoc_grid::Grid::try_parse(input, "a digit", |char| {
+        char.to_digit(10).map(|digit| digit as u8)
+    })?;
+    if heat_losses.width() == 0 {
+        return Err(ParseError::new(1, "a digit", "end of input").on_line(1));
+    }
+    Ok(heat_losses)
+}
+
 #[test]
 fn parses_grid() {
     let input = "2413432311323
@@ -65,10 +82,29 @@ fn parses_grid() {
 1224686865563
 2546548887735
 4322674655533";
-    let grid = Grid::parse(input, &1, &3);
+    let grid = Grid::parse(input, &1, &3).unwrap();
     assert_eq!(grid.data.len(), 12);
-    assert_eq!(grid.data[&(1, Direction::Left)].len(), 13);
-    assert_eq!(grid.data[&(1, Direction::Left)][0].len(), 13);
+    assert_eq!(grid.data[&(1, Direction::Left)].height(), 13);
+    assert_eq!(grid.data[&(1, Direction::Left)].width(), 13);
     assert_eq!(grid.data[&(1, Direction::Left)][12][12].heat_loss, 3);
-    assert_eq!(grid.data[&(1, Direction::Left)][12][12].is_target, true);
+    assert!(grid.data[&(1, Direction::Left)][12][12].is_target);
+}
+
+#[test]
+fn rejects_non_digits() {
+    let error = parse_heat_losses("241\n3x5\n325").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 2: expected a digit, found 'x'"
+    );
+}
+
+#[test]
+fn rejects_empty_grid() {
+    let error = Grid::parse("", &1, &3).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 1: expected a digit, found end of input"
+    );
+    assert!(Grid::parse("\n", &1, &3).is_err());
 }
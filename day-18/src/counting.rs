@@ -1,27 +1,5 @@
 use super::*;
 
-impl Grid {
-    pub fn count(&self) -> usize {
-        self.data
-            .iter()
-            .map(|row| row.iter().filter(|node| node.is_dug).count())
-            .sum()
-    }
-}
-
-#[test]
-fn counts_nodes() {
-    let grid = Grid {
-        data: vec![
-            vec![Node { is_dug: true }, Node { is_dug: false }],
-            vec![Node { is_dug: true }, Node { is_dug: true }],
-        ],
-        current_coord: Coordinate { x: 0, y: 0 },
-    };
-
-    assert_eq!(grid.count(), 3);
-}
-
 impl Vertices {
     pub fn count(&self) -> usize {
         let mut iter = self.data.iter().peekable();
@@ -29,7 +7,7 @@ impl Vertices {
         let mut sum: isize = 0;
         while let Some(vertex) = iter.next() {
             if let Some(next) = iter.peek() {
-                sum += (next.y * vertex.x) as isize - (next.x * vertex.y) as isize;
+                sum += (next.y * vertex.x) - (next.x * vertex.y);
             };
         }
         sum as usize / 2
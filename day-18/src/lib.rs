@@ -0,0 +1,189 @@
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Solver};
+use aoc_grid::ORTHOGONAL;
+
+mod counting;
+mod parsing;
+mod vertices;
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY: u8 = 18;
+    type Parsed<'a> = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// The dig plan read both ways, from the written commands and from the hex colors.
+pub struct DigPlan {
+    pub commands: Vec<Command>,
+    pub hex_commands: Vec<Command>,
+}
+
+pub fn parse(input: &str) -> Result<DigPlan, ParseError> {
+    Ok(DigPlan {
+        commands: parse_lines(input, Command::parse)?,
+        hex_commands: parse_lines(input, Command::parse_hex)?,
+    })
+}
+
+pub fn render(plan: &DigPlan) -> String {
+    plan.commands
+        .iter()
+        .zip(&plan.hex_commands)
+        .map(|(command, hex_command)| {
+            let (dir, hex_dir) = (command.dir.char(), hex_command.dir.hex_char());
+            format!("{dir} {} (#{:05x}{hex_dir})", command.len, hex_command.len)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(plan: &DigPlan) -> usize {
+    dig(&plan.commands)
+}
+
+pub fn part2(plan: &DigPlan) -> usize {
+    dig(&plan.hex_commands)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => ORTHOGONAL[0],
+            Direction::Right => ORTHOGONAL[1],
+            Direction::Down => ORTHOGONAL[2],
+            Direction::Left => ORTHOGONAL[3],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Command {
+    dir: Direction,
+    len: usize,
+}
+
+struct Vertices {
+    data: Vec<BigCoordinate>,
+    horizontal: Direction,
+    vertical: Direction,
+}
+
+#[derive(Debug, PartialEq)]
+struct BigCoordinate {
+    x: isize,
+    y: isize,
+}
+
+pub fn fill_with_lava(
+    input: &str,
+    parse_strategy: fn(&str) -> Result<Command, ParseError>,
+) -> Result<usize, ParseError> {
+    let commands = parse_lines(input, parse_strategy)?;
+    Ok(dig(&commands))
+}
+
+fn dig(commands: &[Command]) -> usize {
+    let mut vert = Vertices::new();
+
+    let mut iter = commands.iter().peekable();
+
+    while let Some(current) = iter.next() {
+        if let Some(next) = iter.peek() {
+            vert.execute(current, next);
+        }
+    }
+
+    vert.count()
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn command() -> impl Strategy<Value = String> {
+        ("[UDLR]", 1..20usize, 1..0x100000usize, "[0-3]").prop_map(
+            |(dir, len, hex_len, hex_dir)| format!("{dir} {len} (#{hex_len:05x}{hex_dir})"),
+        )
+    }
+
+    /// A rectangular trench, dug clockwise by both readings of the plan.
+    fn rectangle() -> impl Strategy<Value = (String, usize, usize)> {
+        (1..20usize, 1..20usize, 1..0x100000usize, 1..0x100000usize).prop_map(
+            |(width, height, hex_width, hex_height)| {
+                let sides = [
+                    ('R', width, hex_width, 0),
+                    ('D', height, hex_height, 1),
+                    ('L', width, hex_width, 2),
+                    ('U', height, hex_height, 3),
+                ];
+                let plan: Vec<String> = sides
+                    .iter()
+                    .map(|(dir, len, hex_len, hex_dir)| {
+                        format!("{dir} {len} (#{hex_len:05x}{hex_dir})")
+                    })
+                    .collect();
+                (
+                    plan.join("\n"),
+                    (width + 1) * (height + 1),
+                    (hex_width + 1) * (hex_height + 1),
+                )
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(plan in proptest::collection::vec(command(), 1..20)) {
+            let input = plan.join("\n");
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn digs_rectangles((input, area, hex_area) in rectangle()) {
+            let plan = parse(&input).unwrap();
+            prop_assert_eq!(part1(&plan), area);
+            prop_assert_eq!(part2(&plan), hex_area);
+        }
+    }
+}
+
+#[test]
+fn integration() {
+    let input = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+    assert_eq!(fill_with_lava(input, Command::parse), Ok(62));
+}
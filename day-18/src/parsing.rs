@@ -1,43 +1,75 @@
 use super::*;
 
 impl Direction {
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> Option<Self> {
         match input {
-            "U" => Self::Up,
-            "D" => Self::Down,
-            "L" => Self::Left,
-            "R" => Self::Right,
-            _ => panic!("{input}"),
+            "U" => Some(Self::Up),
+            "D" => Some(Self::Down),
+            "L" => Some(Self::Left),
+            "R" => Some(Self::Right),
+            _ => None,
         }
     }
-    fn parse_char(input: &char) -> Self {
+    fn parse_char(input: &str) -> Option<Self> {
         match input {
-            '3' => Self::Up,
-            '1' => Self::Down,
-            '2' => Self::Left,
-            '0' => Self::Right,
-            _ => panic!("{input}"),
+            "3" => Some(Self::Up),
+            "1" => Some(Self::Down),
+            "2" => Some(Self::Left),
+            "0" => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    pub fn char(&self) -> char {
+        match self {
+            Self::Up => 'U',
+            Self::Down => 'D',
+            Self::Left => 'L',
+            Self::Right => 'R',
+        }
+    }
+
+    pub fn hex_char(&self) -> char {
+        match self {
+            Self::Up => '3',
+            Self::Down => '1',
+            Self::Left => '2',
+            Self::Right => '0',
         }
     }
 }
 
 impl Command {
-    pub fn parse(input: &str) -> Self {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
         let mut parts = input.split_whitespace();
-        Self {
-            dir: Direction::parse(parts.next().unwrap()),
-            len: parts.next().unwrap().parse().unwrap(),
-        }
+        let dir = parts
+            .next()
+            .ok_or_else(|| ParseError::end_of(input, "a direction"))?;
+        let len = parts
+            .next()
+            .ok_or_else(|| ParseError::end_of(input, "a length"))?;
+        Ok(Self {
+            dir: Direction::parse(dir)
+                .ok_or_else(|| ParseError::at(input, dir, "'U', 'D', 'L' or 'R'"))?,
+            len: parse_number(input, len)?,
+        })
     }
 
-    pub fn parse_hex(input: &str) -> Self {
-        let mut parts = input.split_whitespace();
-        let hex_str = parts.nth(2).unwrap();
-        let hex_digit = &hex_str[2..7];
-        let len = usize::from_str_radix(hex_digit, 16).unwrap();
-        let ch = &hex_str.chars().nth(7).unwrap();
-        let dir = Direction::parse_char(ch);
-        Self { dir, len }
+    pub fn parse_hex(input: &str) -> Result<Self, ParseError> {
+        let hex_str = input
+            .split_whitespace()
+            .nth(2)
+            .ok_or_else(|| ParseError::end_of(input, "a color"))?;
+        let hex_digits = hex_str
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.is_char_boundary(5))
+            .ok_or_else(|| ParseError::at(input, hex_str, "a color like (#70c710)"))?;
+        let len = usize::from_str_radix(&hex_digits[..5], 16)
+            .map_err(|_| ParseError::at(input, &hex_digits[..5], "a hex number"))?;
+        let dir = Direction::parse_char(&hex_digits[5..])
+            .ok_or_else(|| ParseError::at(input, &hex_digits[5..], "a direction from 0 to 3"))?;
+        Ok(Self { dir, len })
     }
 }
 
@@ -46,10 +78,10 @@ fn parses_command() {
     let input = "R 6 (#70c710)";
     assert_eq!(
         Command::parse(input),
-        Command {
+        Ok(Command {
             dir: Direction::Right,
             len: 6
-        }
+        })
     );
 }
 
@@ -58,36 +90,23 @@ fn parses_hex_command() {
     let input = "R 6 (#70c710)";
     assert_eq!(
         Command::parse_hex(input),
-        Command {
+        Ok(Command {
             dir: Direction::Right,
             len: 461937
-        }
+        })
     );
 }
 
-impl Grid {
-    pub fn new() -> Self {
-        let grid_size = 1000;
-        let current_coord = Coordinate {
-            x: grid_size / 2,
-            y: grid_size / 2,
-        };
-        let data = (0..grid_size)
-            .map(|y| {
-                (0..grid_size)
-                    .map(|x| Node {
-                        is_dug: if y == current_coord.y && x == current_coord.x {
-                            true
-                        } else {
-                            false
-                        },
-                    })
-                    .collect()
-            })
-            .collect();
-        Self {
-            data,
-            current_coord,
-        }
-    }
+#[test]
+fn reports_malformed_commands() {
+    let error = parse("R 6 (#70c710)\nX 5 (#0dc571)").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: expected 'U', 'D', 'L' or 'R', found \"X\""
+    );
+
+    let error = Command::parse_hex("R 6 (#70c714)").unwrap_err();
+    assert_eq!(error.column, 12);
+    let error = Command::parse_hex("R 6 #70c710").unwrap_err();
+    assert_eq!(error.column, 5);
 }
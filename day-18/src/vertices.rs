@@ -32,7 +32,7 @@ impl Direction {
 impl Vertices {
     pub fn execute(&mut self, current: &Command, next: &Command) {
         let current_coord = self.data.iter().last().unwrap();
-        let (y_offset, x_offset) = get_offset(&current.dir);
+        let (x_offset, y_offset) = current.dir.offset();
 
         // add len in direction
         let mut new_coord = BigCoordinate {
@@ -85,17 +85,6 @@ fn get_new_corner(current: &Direction, next: &Direction) -> Direction {
     }
 }
 
-fn get_offset(direction: &Direction) -> (isize, isize) {
-    let (y_offset, x_offset) = match direction {
-        Direction::Down => (1, 0),
-        Direction::Up => (-1, 0),
-        Direction::Right => (0, 1),
-        Direction::Left => (0, -1),
-        _ => panic!(),
-    };
-    (y_offset, x_offset)
-}
-
 #[test]
 fn checks_clockwiseness() {
     assert_eq!(
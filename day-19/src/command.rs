@@ -38,7 +38,7 @@ impl Command {
         } else {
             if result != CommandResult::Rejected {
                 // create positive class
-                let mut no_condition = (*class).clone();
+                let no_condition = (*class).clone();
                 output.push((result.clone(), no_condition));
             }
         }
@@ -55,10 +55,14 @@ enum Case {
 impl Bounds {
     fn update_from_condition(&mut self, condition: &Condition, case: &Case) {
         match (&condition.operation, case) {
-            (Operation::LessThan, Case::Positive) => self.max = condition.value - 1,
-            (Operation::LessThan, Case::Negative) => self.min = condition.value,
-            (Operation::GreaterThan, Case::Positive) => self.min = condition.value + 1,
-            (Operation::GreaterThan, Case::Negative) => self.max = condition.value,
+            (Operation::LessThan, Case::Positive) => {
+                self.max = self.max.min(condition.value.saturating_sub(1))
+            }
+            (Operation::LessThan, Case::Negative) => self.min = self.min.max(condition.value),
+            (Operation::GreaterThan, Case::Positive) => {
+                self.min = self.min.max(condition.value + 1)
+            }
+            (Operation::GreaterThan, Case::Negative) => self.max = self.max.min(condition.value),
         }
     }
 }
@@ -170,10 +174,10 @@ fn analyzes_command() {
 }
 
 impl Part {
-    pub fn execute_commands(&mut self, commands: &Vec<Command>) -> CommandResult {
-        let mut iter = commands.iter();
-        while let Some(cmd) = iter.next() {
-            match execute(cmd, &self) {
+    pub fn execute_commands(&mut self, commands: &[Command]) -> CommandResult {
+        let iter = commands.iter();
+        for cmd in iter {
+            match execute(cmd, self) {
                 CommandResult::Next => (),
                 other => {
                     self.result = Some(other.clone());
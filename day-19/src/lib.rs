@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, ParseError, Solver};
+
+pub use crate::{manual_processing::process_parts, range_processing::analyze_ranges};
+use crate::{manual_processing::sum_accepted, range_processing::count_combinations};
+
+mod command;
+mod manual_processing;
+mod parsing;
+mod range_processing;
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY: u8 = 19;
+    type Parsed<'a> = System;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<System, ParseError> {
+    System::parse(input)
+}
+
+/// Writes the workflows sorted by name, followed by the parts.
+pub fn render(system: &System) -> String {
+    let mut names: Vec<&String> = system.workflows.keys().collect();
+    names.sort();
+    let workflows: Vec<String> = names
+        .into_iter()
+        .map(|name| {
+            let commands: Vec<String> = system.workflows[name]
+                .iter()
+                .map(|command| match &command.condition {
+                    Some(condition) => {
+                        let operation = match condition.operation {
+                            Operation::LessThan => '<',
+                            Operation::GreaterThan => '>',
+                        };
+                        let Condition { field, value, .. } = condition;
+                        format!("{field}{operation}{value}:{}", command.target)
+                    }
+                    None => command.target.clone(),
+                })
+                .collect();
+            format!("{name}{{{}}}", commands.join(","))
+        })
+        .collect();
+    let parts: Vec<String> = system
+        .parts
+        .iter()
+        .map(|Part { x, m, a, s, .. }| format!("{{x={x},m={m},a={a},s={s}}}"))
+        .collect();
+    format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+}
+
+pub fn part1(system: &System) -> u64 {
+    sum_accepted(system.clone())
+}
+
+pub fn part2(system: &System) -> u64 {
+    count_combinations(system)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+    result: Option<CommandResult>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Operation {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Condition {
+    field: char,
+    operation: Operation,
+    value: u64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum CommandResult {
+    WorkflowSwitch(String),
+    Next,
+    Rejected,
+    Accepted,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Command {
+    condition: Option<Condition>,
+    target: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct System {
+    workflows: HashMap<String, Vec<Command>>,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Class {
+    x: Bounds,
+    m: Bounds,
+    a: Bounds,
+    s: Bounds,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Bounds {
+    min: u64,
+    max: u64,
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn workflow_name(index: usize) -> String {
+        match index {
+            0 => "in".to_string(),
+            index => format!("w{}", char::from(b'a' + index as u8)),
+        }
+    }
+
+    /// Workflows that only ever send parts to later workflows, so every part ends up
+    /// accepted or rejected.
+    fn workflows() -> impl Strategy<Value = Vec<String>> {
+        (1..6usize).prop_flat_map(|count| {
+            let workflows: Vec<_> = (0..count)
+                .map(|index| {
+                    let mut targets = vec!["A".to_string(), "R".to_string()];
+                    targets.extend((index + 1..count).map(workflow_name));
+                    let target = proptest::sample::select(targets);
+                    let rule = ("[xmas]", "[<>]", 1..=4000u64, target.clone());
+                    let rules = proptest::collection::vec(rule, 0..3);
+                    (rules, target).prop_map(move |(rules, fallback)| {
+                        let mut commands: Vec<String> = rules
+                            .into_iter()
+                            .map(|(field, operation, value, target)| {
+                                format!("{field}{operation}{value}:{target}")
+                            })
+                            .collect();
+                        commands.push(fallback);
+                        format!("{}{{{}}}", workflow_name(index), commands.join(","))
+                    })
+                })
+                .collect();
+            workflows
+        })
+    }
+
+    fn parts() -> impl Strategy<Value = Vec<String>> {
+        let part = [1..=4000u64, 1..=4000u64, 1..=4000u64, 1..=4000u64];
+        let part = part.prop_map(|[x, m, a, s]| format!("{{x={x},m={m},a={a},s={s}}}"));
+        proptest::collection::vec(part, 1..10)
+    }
+
+    fn system() -> impl Strategy<Value = String> {
+        (workflows(), parts()).prop_map(|(workflows, parts)| {
+            format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+        })
+    }
+
+    fn accepts(system: &System, ratings: [u64; 4]) -> bool {
+        let mut name = "in";
+        loop {
+            let command = system.workflows[name]
+                .iter()
+                .find(|command| {
+                    command.condition.as_ref().is_none_or(|condition| {
+                        let rating = ratings["xmas".find(condition.field).unwrap()];
+                        match condition.operation {
+                            Operation::LessThan => rating < condition.value,
+                            Operation::GreaterThan => rating > condition.value,
+                        }
+                    })
+                })
+                .unwrap();
+            match command.target.as_str() {
+                "A" => return true,
+                "R" => return false,
+                target => name = target,
+            }
+        }
+    }
+
+    /// Splits every rating where some condition changes its outcome, and checks one
+    /// combination of each resulting block.
+    fn count_accepted(system: &System) -> u64 {
+        let splits: Vec<Vec<u64>> = "xmas"
+            .chars()
+            .map(|field| {
+                let mut splits = vec![1, 4001];
+                for command in system.workflows.values().flatten() {
+                    match &command.condition {
+                        Some(condition) if condition.field == field => {
+                            splits.push(match condition.operation {
+                                Operation::LessThan => condition.value,
+                                Operation::GreaterThan => condition.value + 1,
+                            })
+                        }
+                        _ => {}
+                    }
+                }
+                splits.sort();
+                splits.dedup();
+                splits
+            })
+            .collect();
+        let blocks = |field: usize| {
+            splits[field]
+                .windows(2)
+                .map(|pair| (pair[0], pair[1] - pair[0]))
+        };
+        let mut count = 0;
+        for (x, x_len) in blocks(0) {
+            for (m, m_len) in blocks(1) {
+                for (a, a_len) in blocks(2) {
+                    for (s, s_len) in blocks(3) {
+                        if accepts(system, [x, m, a, s]) {
+                            count += x_len * m_len * a_len * s_len;
+                        }
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in system()) {
+            let system = parse(&input).unwrap();
+            prop_assert_eq!(parse(&render(&system)).unwrap(), system);
+        }
+
+        #[test]
+        fn sums_accepted_parts(input in system()) {
+            let system = parse(&input).unwrap();
+            let expected: u64 = system
+                .parts
+                .iter()
+                .filter(|part| accepts(&system, [part.x, part.m, part.a, part.s]))
+                .map(|part| part.x + part.m + part.a + part.s)
+                .sum();
+            prop_assert_eq!(part1(&system), expected);
+        }
+
+        #[test]
+        fn counts_accepted_combinations(input in system()) {
+            let system = parse(&input).unwrap();
+            prop_assert_eq!(part2(&system), count_accepted(&system));
+        }
+    }
+}
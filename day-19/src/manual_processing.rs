@@ -1,11 +1,14 @@
 use super::*;
 
-pub(super) fn process_parts(input: &str) -> u64 {
-    let mut system = System::parse(input);
+pub fn process_parts(input: &str) -> Result<u64, ParseError> {
+    Ok(sum_accepted(System::parse(input)?))
+}
+
+pub(super) fn sum_accepted(mut system: System) -> u64 {
     for part in system.parts.iter_mut() {
         let mut current = "in".to_string();
         while let CommandResult::WorkflowSwitch(next) =
-            part.execute_commands(&system.workflows.get(&current).unwrap())
+            part.execute_commands(system.workflows.get(&current).unwrap())
         {
             current = next;
         }
@@ -38,5 +41,5 @@ hdj{m>838:A,pv}
 {x=2036,m=264,a=79,s=2244}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
-    assert_eq!(process_parts(input), 19114);
+    assert_eq!(process_parts(input), Ok(19114));
 }
@@ -1,3 +1,5 @@
+use aoc_common::ParseError;
+
 use self::{part::parse_parts, workflows::parse_workflows};
 
 use super::*;
@@ -6,12 +8,25 @@ mod part;
 mod workflows;
 
 impl System {
-    pub fn parse(input: &str) -> Self {
-        let mut sections = input.split("\n\n");
-        Self {
-            workflows: parse_workflows(sections.next().unwrap()),
-            parts: parse_parts(sections.next().unwrap()),
-        }
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let workflows_lines = input
+            .split("\n\n")
+            .next()
+            .unwrap_or_default()
+            .lines()
+            .count();
+        let (workflows, parts) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(1, "an empty line after the workflows", "end of input")
+                .on_line(workflows_lines + 1)
+        })?;
+
+        Ok(Self {
+            workflows: parse_workflows(workflows)?,
+            parts: parse_parts(parts).map_err(|error| {
+                let line = error.line + workflows_lines + 1;
+                error.on_line(line)
+            })?,
+        })
     }
 }
 
@@ -32,7 +47,27 @@ fn parses_full_input() {
 
 {x=787,m=2655,a=1222,s=2876}";
 
-    let system = System::parse(input);
+    let system = System::parse(input).unwrap();
     assert_eq!(system.workflows.len(), 1);
     assert_eq!(system.parts.len(), 1);
 }
+
+#[test]
+fn reports_lines_across_sections() {
+    let input = "hdj{m>838:A,pv}
+in{s<1351:px,qqz}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,y=2067,s=496}";
+
+    let error = System::parse(input).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 5, column 14: expected a=, found \"y=2067\""
+    );
+
+    let error = System::parse("in{s<1351:px,q?2:R}\n\n{x=1,m=2,a=3,s=4}")
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (1, 14));
+}
@@ -1,23 +1,37 @@
+use aoc_common::{parse_lines, parse_number, ParseError};
+
 use super::*;
 
 impl Part {
-    fn parse(input: &str) -> Self {
-        //.replace("{", "").replace("}", "")
-        let mut params = input[1..input.len() - 1]
-            .split(",")
-            .map(|param| &param[2..]);
-        Self {
-            x: params.next().unwrap().parse().unwrap(),
-            m: params.next().unwrap().parse().unwrap(),
-            a: params.next().unwrap().parse().unwrap(),
-            s: params.next().unwrap().parse().unwrap(),
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut params = input
+            .strip_prefix('{')
+            .and_then(|params| params.strip_suffix('}'))
+            .ok_or_else(|| ParseError::at(input, input, "ratings in braces"))?
+            .split(',');
+        let mut rating = |name: &str| -> Result<u64, ParseError> {
+            let param = params
+                .next()
+                .ok_or_else(|| ParseError::at(input, &input[input.len() - 1..], "','"))?;
+            let value = param
+                .strip_prefix(name)
+                .and_then(|value| value.strip_prefix('='))
+                .ok_or_else(|| ParseError::at(input, param, format!("{name}=")))?;
+            parse_number(input, value)
+        };
+
+        Ok(Self {
+            x: rating("x")?,
+            m: rating("m")?,
+            a: rating("a")?,
+            s: rating("s")?,
             result: None,
-        }
+        })
     }
 }
 
-pub(super) fn parse_parts(input: &str) -> Vec<Part> {
-    input.lines().map(|line| Part::parse(line)).collect()
+pub(super) fn parse_parts(input: &str) -> Result<Vec<Part>, ParseError> {
+    parse_lines(input, Part::parse)
 }
 
 #[test]
@@ -25,12 +39,12 @@ fn parses_part() {
     let input = "{x=787,m=2655,a=1222,s=2876}";
     assert_eq!(
         Part::parse(input),
-        Part {
+        Ok(Part {
             x: 787,
             m: 2655,
             a: 1222,
             s: 2876,
             result: None
-        }
+        })
     );
 }
@@ -1,61 +1,75 @@
+use aoc_common::{column_of, parse_lines, parse_number, ParseError};
+
 use super::*;
 
 impl Command {
-    fn parse(input: &str) -> Self {
-        let mut parts = input.split(":");
-        let (condition, target) = match parts.clone().count() {
-            1 => (None, parts.next().unwrap().to_string()),
-            2 => (
-                Some(Condition::parse(parts.next().unwrap())),
-                parts.next().unwrap().to_string(),
-            ),
-            _ => panic!(),
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (condition, target) = match input.split_once(':') {
+            None => (None, input),
+            Some((condition, target)) => (Some(Condition::parse(condition)?), target),
         };
-        Self {
-            condition: condition,
-            target: target,
+        if target.is_empty() || target.contains(':') {
+            return Err(ParseError::at(input, target, "a workflow name"));
         }
+        Ok(Self {
+            condition,
+            target: target.to_string(),
+        })
     }
 }
 
 impl Condition {
-    fn parse(input: &str) -> Self {
-        let mut chars = input.chars();
-
-        Self {
-            field: chars.nth(0).unwrap(),
-            operation: Operation::parse(chars.nth(0).unwrap()),
-            value: chars.collect::<String>().parse().unwrap(),
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let field = first_char(input);
+        if !matches!(field, "x" | "m" | "a" | "s") {
+            return Err(ParseError::at(input, field, "'x', 'm', 'a' or 's'"));
         }
+        let operation = first_char(&input[1..]);
+
+        Ok(Self {
+            field: field.chars().next().unwrap(),
+            operation: Operation::parse(operation)
+                .ok_or_else(|| ParseError::at(input, operation, "'<' or '>'"))?,
+            value: parse_number(input, &input[1 + operation.len()..])?,
+        })
     }
 }
 
 impl Operation {
-    fn parse(ch: char) -> Self {
-        match ch {
-            '>' => Self::GreaterThan,
-            '<' => Self::LessThan,
-            _ => panic!(),
+    fn parse(token: &str) -> Option<Self> {
+        match token {
+            ">" => Some(Self::GreaterThan),
+            "<" => Some(Self::LessThan),
+            _ => None,
         }
     }
 }
 
-pub(super) fn parse_workflows(commands: &str) -> HashMap<String, Vec<Command>> {
+fn first_char(input: &str) -> &str {
+    &input[..input.chars().next().map_or(0, char::len_utf8)]
+}
+
+pub(super) fn parse_workflows(commands: &str) -> Result<HashMap<String, Vec<Command>>, ParseError> {
     //"px{a<2006:qkq,m>2090:A,rfg}"
-    let tuples: HashMap<String, Vec<Command>> = commands
-        .lines()
-        .map(|line| {
-            let mut parts = line.split("{");
-            let name = parts.next().unwrap();
-            let command_str = parts.next().unwrap().replace("}", "");
-            let commands = command_str
-                .split(",")
-                .map(|command| Command::parse(command))
-                .collect();
-            (name.to_string(), commands)
-        })
-        .collect();
-    tuples
+    let tuples = parse_lines(commands, |line| {
+        let (name, command_str) = line
+            .split_once('{')
+            .ok_or_else(|| ParseError::end_of(line, "'{'"))?;
+        let command_str = command_str
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::end_of(line, "'}'"))?;
+        let commands = command_str
+            .split(',')
+            .map(|command| {
+                Command::parse(command).map_err(|mut error| {
+                    error.column += column_of(line, command) - 1;
+                    error
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((name.to_string(), commands))
+    })?;
+    Ok(tuples.into_iter().collect())
 }
 
 #[test]
@@ -63,11 +77,11 @@ fn parses_condition() {
     let condition = "a<2006";
     assert_eq!(
         Condition::parse(condition),
-        Condition {
+        Ok(Condition {
             field: 'a',
             operation: Operation::LessThan,
             value: 2006
-        }
+        })
     );
 }
 
@@ -76,23 +90,23 @@ fn parses_command() {
     let input = "a<2006:qkq";
     assert_eq!(
         Command::parse(input),
-        Command {
+        Ok(Command {
             condition: Some(Condition {
                 field: 'a',
                 operation: Operation::LessThan,
                 value: 2006
             }),
             target: String::from("qkq")
-        }
+        })
     );
 
     let input = "rfg";
     assert_eq!(
         Command::parse(input),
-        Command {
+        Ok(Command {
             condition: None,
             target: String::from("rfg")
-        }
+        })
     );
 }
 
@@ -100,7 +114,7 @@ fn parses_command() {
 fn saves_commands_to_map() {
     let input = "px{a<2006:qkq,m>2090:A,rfg}
 pv{a>1716:R,A}";
-    let result = parse_workflows(input);
+    let result = parse_workflows(input).unwrap();
 
     assert_eq!(result.len(), 2);
     assert_eq!(result.get("px").unwrap().len(), 3);
@@ -1,9 +1,10 @@
-use std::ops::Bound;
-
 use super::*;
 
-pub(super) fn analyze_ranges(input: &str) -> u64 {
-    let system = System::parse(input);
+pub fn analyze_ranges(input: &str) -> Result<u64, ParseError> {
+    Ok(count_combinations(&System::parse(input)?))
+}
+
+pub(super) fn count_combinations(system: &System) -> u64 {
     let mut buffer = vec![("in".to_string(), Class::new())];
     let mut finished = Vec::new();
 
@@ -36,8 +37,9 @@ pub(super) fn analyze_ranges(input: &str) -> u64 {
 }
 
 impl Bounds {
+    /// Bounds narrowed past each other leave no ratings at all.
     fn get_range(&self) -> u64 {
-        self.max - self.min + 1
+        (self.max + 1).saturating_sub(self.min)
     }
 }
 
@@ -60,5 +62,14 @@ hdj{m>838:A,pv}
 {x=2036,m=264,a=79,s=2244}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
-    assert_eq!(analyze_ranges(input), 167409079868000);
+    assert_eq!(analyze_ranges(input), Ok(167409079868000));
+}
+
+#[test]
+fn narrows_repeated_conditions() {
+    let input = "in{x<100:R,x>10:A,R}\n\n{x=1,m=1,a=1,s=1}";
+    assert_eq!(analyze_ranges(input), Ok(3901 * 4000 * 4000 * 4000));
+
+    let input = "in{s<2072:R,s<1:A,A}\n\n{x=1,m=1,a=1,s=1}";
+    assert_eq!(analyze_ranges(input), Ok(1929 * 4000 * 4000 * 4000));
 }
@@ -9,19 +9,37 @@ impl UpdatesPredecessors for ModuleBase {
 }
 
 impl Module for FlipFlop {
-    fn get_successors(&self) -> HashSet<String> {
-        self.module.outputs.clone()
+    fn get_base(&self) -> &ModuleBase {
+        &self.module
+    }
+
+    fn clone_module(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
     }
 }
 
 impl Module for ModuleBase {
-    fn get_successors(&self) -> HashSet<String> {
-        self.outputs.clone()
+    fn get_base(&self) -> &ModuleBase {
+        self
+    }
+
+    fn clone_module(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
     }
 }
 
 impl Module for Conjunction {
-    fn get_successors(&self) -> HashSet<String> {
-        self.module.outputs.clone()
+    fn get_base(&self) -> &ModuleBase {
+        &self.module
+    }
+
+    fn clone_module(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Module> {
+    fn clone(&self) -> Self {
+        self.clone_module()
     }
 }
@@ -1,9 +1,17 @@
 use super::*;
 
 impl Dispatcher {
+    fn module_mut(&mut self, id: &str) -> &mut Box<dyn Module> {
+        let id = self
+            .wiring
+            .id(id)
+            .expect("Messages go to modules of the wiring");
+        &mut self.modules[id]
+    }
+
     pub(super) fn process(&mut self) {
         while let Some(message) = self.queue.pop_front() {
-            let node = self.graph.get_mut(&message.to).unwrap();
+            let node = self.module_mut(&message.to);
 
             let results = node.process_signal(&message);
 
@@ -15,12 +23,12 @@ impl Dispatcher {
 
     pub(super) fn process_with_cycles(&mut self, i: u64) {
         while let Some(message) = self.queue.pop_front() {
-            if message.to == "lx".to_string() && message.signal == Signal::High {
+            if message.to == "lx" && message.signal == Signal::High {
                 self.cycles.insert(message.from.clone(), i);
                 // println!("{} {}", message.from, i);
             }
 
-            let node = self.graph.get_mut(&message.to).unwrap();
+            let node = self.module_mut(&message.to);
 
             let results = node.process_signal(&message);
 
@@ -1,5 +1,3 @@
-use super::*;
-
 fn gcd(a: u64, b: u64) -> u64 {
     if b == 0 {
         a
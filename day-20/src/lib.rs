@@ -0,0 +1,329 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{Answer, ParseError, Solver};
+use aoc_graph::Graph;
+use lcm::lowest_common_multiple;
+use parsing::parse_inputs;
+
+mod common;
+mod dispatcher;
+mod lcm;
+mod parsing;
+mod processing;
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    const DAY: u8 = 20;
+    type Parsed<'a> = Dispatcher;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Dispatcher, ParseError> {
+    let (wiring, modules) = parse_inputs(input)?;
+    Ok(Dispatcher {
+        log: Vec::new(),
+        queue: VecDeque::new(),
+        wiring,
+        modules,
+        cycles: HashMap::new(),
+    })
+}
+
+/// Writes the modules sorted by name, leaving out the outputs nothing is sent from.
+pub fn render(dispatcher: &Dispatcher) -> String {
+    let mut modules: Vec<&ModuleBase> = dispatcher
+        .modules
+        .iter()
+        .map(|module| module.get_base())
+        .filter(|module| !matches!(module.t, Type::Output))
+        .collect();
+    modules.sort_by_key(|module| &module.id);
+    modules
+        .iter()
+        .map(|module| {
+            let prefix = match module.t {
+                Type::FlipFlop => "%",
+                Type::Conjunction => "&",
+                _ => "",
+            };
+            format!("{prefix}{} -> {}", module.id, module.outputs.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(dispatcher: &Dispatcher) -> u64 {
+    let mut dispatcher = dispatcher.clone();
+    for _ in 0..1000 {
+        dispatcher.queue.extend([Message {
+            to: "broadcaster".to_string(),
+            from: "button".to_string(),
+            signal: Signal::Low,
+        }]);
+        dispatcher.process();
+    }
+    dispatcher.count()
+}
+
+pub fn part2(dispatcher: &Dispatcher) -> u64 {
+    let mut dispatcher = dispatcher.clone();
+    for i in 1..100000 {
+        dispatcher.queue.extend([Message {
+            to: "broadcaster".to_string(),
+            from: "button".to_string(),
+            signal: Signal::Low,
+        }]);
+        dispatcher.process_with_cycles(i);
+        if dispatcher.cycles.len() == 4 {
+            break;
+        }
+    }
+    dispatcher
+        .cycles
+        .iter()
+        .fold(1, |acc, current| lowest_common_multiple(acc, *current.1))
+}
+
+#[derive(Clone)]
+enum Type {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    Output,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Signal {
+    Low,
+    High,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum State {
+    On,
+    Off,
+}
+
+trait Communicate {
+    fn process_signal(&mut self, message: &Message) -> Vec<Message>;
+}
+
+trait UpdatesPredecessors {
+    fn add_predecessor(&mut self, id: String);
+}
+
+trait Module: Communicate + UpdatesPredecessors {
+    fn get_base(&self) -> &ModuleBase;
+
+    fn clone_module(&self) -> Box<dyn Module>;
+}
+
+#[derive(Clone)]
+struct ModuleBase {
+    id: String,
+    t: Type,
+    outputs: Vec<String>,
+}
+#[derive(Clone)]
+struct FlipFlop {
+    module: ModuleBase,
+    state: State,
+}
+#[derive(Clone)]
+struct Conjunction {
+    module: ModuleBase,
+    recent_inputs: HashMap<String, Signal>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Message {
+    from: String,
+    to: String,
+    signal: Signal,
+}
+
+/// The modules are indexed by their node in the wiring.
+#[derive(Clone)]
+pub struct Dispatcher {
+    log: Vec<Message>,
+    queue: VecDeque<Message>,
+    wiring: Graph<String>,
+    modules: Vec<Box<dyn Module>>,
+    cycles: HashMap<String, u64>,
+}
+
+pub fn integrate(input: &str) -> Result<u64, ParseError> {
+    Ok(part1(&parse(input)?))
+}
+
+pub fn integrate_for_rx(input: &str) -> Result<u64, ParseError> {
+    Ok(part2(&parse(input)?))
+}
+
+#[cfg(test)]
+mod properties {
+    use std::collections::{BTreeMap, HashSet};
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const NAMES: [&str; 6] = ["aa", "bb", "cc", "dd", "ee", "ff"];
+
+    /// Modules by name with their kind, `'b'` for the broadcaster, and their outputs.
+    type Configuration = BTreeMap<String, (char, Vec<String>)>;
+
+    /// Outputs of the modules listed after `index`, so pulses can't go around in circles.
+    fn outputs_after(index: usize) -> impl Strategy<Value = Vec<String>> {
+        let names: Vec<String> = NAMES[index..]
+            .iter()
+            .chain(&["rx"])
+            .map(|name| name.to_string())
+            .collect();
+        let most = names.len().min(3);
+        proptest::sample::subsequence(names, 1..=most)
+    }
+
+    fn configuration() -> impl Strategy<Value = Configuration> {
+        let modules: Vec<_> = (1..=NAMES.len())
+            .map(|index| (prop_oneof![Just('%'), Just('&')], outputs_after(index)))
+            .collect();
+        (outputs_after(0), modules).prop_map(|(broadcast, modules)| {
+            let mut configuration: Configuration = NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .zip(modules)
+                .collect();
+            configuration.insert("broadcaster".to_string(), ('b', broadcast));
+            configuration
+        })
+    }
+
+    fn write(configuration: &Configuration) -> String {
+        configuration
+            .iter()
+            .map(|(name, (kind, outputs))| {
+                let prefix = if *kind == 'b' {
+                    String::new()
+                } else {
+                    kind.to_string()
+                };
+                format!("{prefix}{name} -> {}", outputs.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Pushes the button a thousand times, sending pulses in the order the outputs are listed.
+    fn count_pulses(configuration: &Configuration) -> u64 {
+        let mut on = HashSet::new();
+        let mut memory: HashMap<(&str, &str), bool> = HashMap::new();
+        for (name, (_, outputs)) in configuration {
+            for output in outputs {
+                memory.insert((output, name), false);
+            }
+        }
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..1000 {
+            let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+            while let Some((from, to, pulse)) = queue.pop_front() {
+                if pulse {
+                    high += 1;
+                } else {
+                    low += 1;
+                }
+                let Some((kind, outputs)) = configuration.get(to) else {
+                    continue;
+                };
+                let pulse = match kind {
+                    'b' => pulse,
+                    '%' if pulse => continue,
+                    '%' if on.remove(to) => false,
+                    '%' => on.insert(to),
+                    _ => {
+                        memory.insert((to, from), pulse);
+                        !memory
+                            .iter()
+                            .all(|((module, _), high)| *module != to || *high)
+                    }
+                };
+                queue.extend(outputs.iter().map(|output| (to, output.as_str(), pulse)));
+            }
+        }
+        low * high
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(configuration in configuration()) {
+            let input = write(&configuration);
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn counts_pulses(configuration in configuration()) {
+            let input = write(&configuration);
+            prop_assert_eq!(part1(&parse(&input).unwrap()), count_pulses(&configuration));
+        }
+    }
+}
+
+#[test]
+fn processes_queue() {
+    let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    let (wiring, modules) = parse_inputs(input).unwrap();
+    let mut dispatcher = Dispatcher {
+        log: Vec::new(),
+        queue: VecDeque::from([Message {
+            to: "broadcaster".to_string(),
+            from: "button".to_string(),
+            signal: Signal::Low,
+        }]),
+        wiring,
+        modules,
+        cycles: HashMap::new(),
+    };
+
+    dispatcher.process();
+
+    assert_eq!(dispatcher.log.len(), 12)
+}
+
+#[test]
+fn integrate_first() {
+    let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    assert_eq!(integrate(input), Ok(32000000))
+}
+
+#[test]
+fn integrate_second() {
+    let input = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    assert_eq!(integrate(input), Ok(11687500))
+}
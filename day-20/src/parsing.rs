@@ -1,3 +1,5 @@
+use aoc_common::{parse_lines, ParseError};
+
 use self::{
     conjunction::prepare_conjuctions,
     module::{add_outputs, parse_module},
@@ -8,14 +10,18 @@ use super::*;
 mod conjunction;
 mod module;
 
-pub fn parse_inputs(input: &str) -> HashMap<String, Box<dyn Module>> {
-    let mut modules = input
-        .lines()
-        .map(|line| parse_module(&line).expect("Module needs to be OK"))
-        .collect();
-    add_outputs(&mut modules);
-    prepare_conjuctions(&mut modules);
-    modules
+/// The wiring, and the modules indexed by their node in it.
+type Circuit = (Graph<String>, Vec<Box<dyn Module>>);
+
+pub fn parse_inputs(input: &str) -> Result<Circuit, ParseError> {
+    let modules = parse_lines(input, parse_module)?;
+    if !modules.iter().any(|(id, _)| id == "broadcaster") {
+        return Err(ParseError::new(1, "a broadcaster module", "end of input")
+            .on_line(input.lines().count() + 1));
+    }
+    let (wiring, mut modules) = add_outputs(modules);
+    prepare_conjuctions(&wiring, &mut modules);
+    Ok((wiring, modules))
 }
 
 #[test]
@@ -26,7 +32,15 @@ fn parses_lines() {
 %b -> con
 &con -> output";
 
-    let result = parse_inputs(input);
+    let (wiring, modules) = parse_inputs(input).unwrap();
+
+    assert_eq!(wiring.len(), 6);
+    assert_eq!(modules.len(), 6);
+    assert_eq!(wiring.edge_count(), 6);
 
-    assert_eq!(result.len(), 6);
+    let error = parse_inputs("%a -> b\n&b -> a").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 3, column 1: expected a broadcaster module, found end of input"
+    );
 }
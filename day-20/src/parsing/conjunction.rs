@@ -6,56 +6,50 @@ impl UpdatesPredecessors for Conjunction {
     }
 }
 
-pub(super) fn prepare_conjuctions(graph: &mut HashMap<String, Box<dyn Module>>) {
-    let modules = graph.keys().map(|k| k.clone()).collect::<Vec<_>>();
-
-    for module in modules {
-        let outputs = graph.get(&module).unwrap().get_successors();
-        for successor in outputs {
-            graph
-                .get_mut(&successor)
-                .expect("Didn't find module")
-                .add_predecessor(module.clone());
+pub(super) fn prepare_conjuctions(wiring: &Graph<String>, modules: &mut [Box<dyn Module>]) {
+    for (id, module) in modules.iter_mut().enumerate() {
+        for &predecessor in wiring.predecessors(id) {
+            module.add_predecessor(wiring.key(predecessor).clone());
         }
     }
 }
 
 #[test]
 fn conjunction_has_inputs_prepared() {
-    //TODO: Mocks with Automock
-
     let cj = Conjunction {
         module: ModuleBase {
             id: "cj".to_string(),
             t: Type::Conjunction,
-            outputs: HashSet::new(),
+            outputs: Vec::from(["a".to_string()]),
         },
         recent_inputs: HashMap::new(),
     };
-
     let br = ModuleBase {
         id: "broadcaster".to_string(),
         t: Type::Broadcaster,
-        outputs: HashSet::from(["cj".to_string(), "ff".to_string()]),
+        outputs: Vec::from(["cj".to_string(), "ff".to_string()]),
     };
-
     let ff = FlipFlop {
         module: ModuleBase {
             id: "ff".to_string(),
             t: Type::FlipFlop,
-            outputs: HashSet::from(["cj".to_string()]),
+            outputs: Vec::from(["cj".to_string()]),
         },
         state: State::Off,
     };
-
-    let b_id = cj.module.id.clone();
-    let b: Box<dyn Module> = Box::new(cj);
-
-    let mut graph: HashMap<String, Box<dyn Module>> = HashMap::from([
-        (b_id, b),
-        (br.id.clone(), Box::new(br)),
-        (ff.module.id.clone(), Box::new(ff)),
-    ]);
-
-    prepare_conjuctions(&mut graph);
+    let mut wiring = Graph::directed();
+    wiring.add_edge("broadcaster".to_string(), "cj".to_string(), ());
+    wiring.add_edge("broadcaster".to_string(), "ff".to_string(), ());
+    wiring.add_edge("ff".to_string(), "cj".to_string(), ());
+    let mut modules: Vec<Box<dyn Module>> = vec![Box::new(br), Box::new(cj), Box::new(ff)];
+
+    prepare_conjuctions(&wiring, &mut modules);
+
+    // ff still counts as low, so a high pulse from the broadcaster alone isn't enough
+    let result = modules[1].process_signal(&Message {
+        to: "cj".to_string(),
+        from: "broadcaster".to_string(),
+        signal: Signal::High,
+    });
+    assert_eq!(result[0].signal, Signal::High);
 }
@@ -1,23 +1,34 @@
+use aoc_common::ParseError;
+
 use super::*;
 
-pub(super) fn parse_module(input: &str) -> Result<(String, Box<dyn Module>), String> {
-    let parts: Vec<&str> = input.split("->").collect();
-    if parts.len() != 2 {
-        return Err("Invalid format".to_string());
-    }
+pub(super) fn parse_module(input: &str) -> Result<(String, Box<dyn Module>), ParseError> {
+    let (name, outputs) = input
+        .split_once("->")
+        .ok_or_else(|| ParseError::end_of(input, "'->'"))?;
+    let name = name.trim();
 
-    let module_type = parts[0].trim().chars().nth(0).unwrap();
-    let is_broadcaster = parts[0].trim() == "broadcaster";
+    let module_type = name
+        .chars()
+        .next()
+        .ok_or_else(|| ParseError::at(input, name, "a module name"))?;
+    let is_broadcaster = name == "broadcaster";
     let id = if is_broadcaster {
-        parts[0].trim().to_string()
+        name
     } else {
-        parts[0].trim()[1..].to_string()
+        &name[module_type.len_utf8()..]
     };
-    let outputs: HashSet<String> = parts[1]
-        .trim()
+    if id.is_empty() {
+        return Err(ParseError::at(input, id, "a module name"));
+    }
+    let id = id.to_string();
+    let outputs = outputs
         .split(',')
-        .map(|s| s.trim().to_string())
-        .collect();
+        .map(|output| match output.trim() {
+            "" => Err(ParseError::at(input, output, "a module name")),
+            output => Ok(output.to_string()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
 
     let boxed: Box<dyn Module> = match module_type {
         '%' => Box::new(FlipFlop {
@@ -36,41 +47,56 @@ pub(super) fn parse_module(input: &str) -> Result<(String, Box<dyn Module>), Str
             },
             recent_inputs: HashMap::new(),
         }),
-        _ => Box::new(ModuleBase {
+        _ if is_broadcaster => Box::new(ModuleBase {
             id: id.clone(),
-            t: if is_broadcaster {
-                Type::Broadcaster
-            } else {
-                Type::Button
-            },
+            t: Type::Broadcaster,
             outputs,
         }),
+        _ => {
+            return Err(ParseError::at(
+                input,
+                name,
+                "'%' or '&' before the module name, or broadcaster",
+            ))
+        }
     };
 
     Ok((id, boxed))
 }
 
-pub(super) fn add_outputs(graph: &mut HashMap<String, Box<dyn Module>>) {
-    let modules = graph.keys().map(|k| k.clone()).collect::<Vec<_>>();
+/// Interns the modules in the order they are listed, adding an output module for every name
+/// that is only sent to.
+pub(super) fn add_outputs(modules: Vec<(String, Box<dyn Module>)>) -> Circuit {
+    let mut wiring = Graph::directed();
+    let mut listed: Vec<Option<Box<dyn Module>>> = Vec::new();
+    for (id, module) in modules {
+        let id = wiring.intern(id);
+        listed.resize_with(wiring.len(), || None);
+        listed[id] = Some(module);
+    }
 
-    for module in modules {
-        let outputs = graph.get(&module).unwrap().get_successors();
-        for successor in outputs {
-            match graph.contains_key(&successor) {
-                true => {}
-                false => {
-                    graph.insert(
-                        successor.clone(),
-                        Box::new(ModuleBase {
-                            id: successor.clone(),
-                            t: Type::Output,
-                            outputs: HashSet::new(),
-                        }),
-                    );
-                }
-            }
+    for (id, module) in listed.iter().enumerate() {
+        for output in &module.as_ref().unwrap().get_base().outputs {
+            let output = wiring.intern(output.clone());
+            wiring.connect(id, output, ());
         }
     }
+
+    listed.resize_with(wiring.len(), || None);
+    let modules = listed
+        .into_iter()
+        .enumerate()
+        .map(|(id, module)| {
+            module.unwrap_or_else(|| {
+                Box::new(ModuleBase {
+                    id: wiring.key(id).clone(),
+                    t: Type::Output,
+                    outputs: Vec::new(),
+                })
+            })
+        })
+        .collect();
+    (wiring, modules)
 }
 
 #[test]
@@ -84,4 +110,19 @@ fn parse_line() {
     let input = "%hd -> ms, qb";
     let module = parse_module(input);
     assert!(module.is_ok());
+
+    let error = parse_module("%hd => ms, qb").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 0, column 14: expected '->', found end of line"
+    );
+    let error = parse_module("%hd -> ms,, qb").err().unwrap();
+    assert_eq!(error.column, 11);
+    let error = parse_module("& -> ms").err().unwrap();
+    assert_eq!(error.column, 2);
+    let error = parse_module("hd -> ms").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 0, column 1: expected '%' or '&' before the module name, or broadcaster, found \"hd\""
+    );
 }
@@ -29,7 +29,7 @@ fn processes_message() {
         module: ModuleBase {
             id: id.clone(),
             t: Type::Conjunction,
-            outputs: HashSet::from(["a".to_string()]),
+            outputs: Vec::from(["a".to_string()]),
         },
         recent_inputs: HashMap::from([
             ("x".to_string(), Signal::Low),
@@ -42,7 +42,7 @@ fn processes_message() {
         module: ModuleBase {
             id: "ff".to_string(),
             t: Type::FlipFlop,
-            outputs: HashSet::from(["a".to_string()]),
+            outputs: Vec::from(["a".to_string()]),
         },
         state: State::Off,
     };
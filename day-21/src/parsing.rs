@@ -1,36 +1,34 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
 use super::*;
 
 impl Garden {
-    pub fn parse(input: &str) -> Self {
-        let mut rocks = HashSet::new();
-        let mut steps = HashSet::new();
-
-        for (y, line) in input.lines().enumerate() {
-            for (x, ch) in line.chars().enumerate() {
-                match ch {
-                    '#' => rocks.insert(Coordinate {
-                        x: x as isize,
-                        y: y as isize,
-                    }),
-                    'S' => steps.insert(Coordinate {
-                        x: x as isize,
-                        y: y as isize,
-                    }),
-                    _ => false,
-                };
-            }
-        }
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::try_parse(input, "'.', '#' or 'S'", |ch| {
+            matches!(ch, '.' | '#' | 'S').then_some(ch)
+        })?;
+        let at = |(x, y): (usize, usize)| Coordinate {
+            x: x as isize,
+            y: y as isize,
+        };
 
-        let y_size = input.lines().count() as isize;
-        let x_size = input.lines().nth(0).unwrap().chars().count() as isize;
+        let steps = grid
+            .cells()
+            .filter(|(_, ch)| **ch == 'S')
+            .map(|(position, _)| at(position))
+            .collect::<HashSet<_>>();
+        if steps.is_empty() {
+            return Err(
+                ParseError::new(1, "a starting position 'S'", "end of input")
+                    .on_line(grid.height() + 1),
+            );
+        }
 
-        Self {
-            rocks,
+        Ok(Self {
+            rocks: grid.map(|ch| *ch == '#'),
             steps,
-            y_size,
-            x_size,
-            tiles: HashMap::new(),
-        }
+        })
     }
 }
 
@@ -46,13 +44,17 @@ mod t {
 
         assert_eq!(
             garden,
-            Garden {
-                rocks: HashSet::from([Coordinate { x: 1, y: 0 }]),
-                steps: HashSet::from([Coordinate { x: 1, y: 1 }]),
-                y_size: 2,
-                x_size: 2,
-                tiles: HashMap::new()
-            }
+            Ok(Garden {
+                rocks: Grid::from_rows(vec![vec![false, true], vec![false, false]]),
+                steps: HashSet::from([Coordinate { x: 1, y: 1 }])
+            })
+        );
+
+        let error = Garden::parse(".#\nO.").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected '.', '#' or 'S', found 'O'"
         );
+        assert_eq!(Garden::parse(".#\n..").unwrap_err().line, 3);
     }
 }
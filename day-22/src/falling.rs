@@ -1,7 +1,7 @@
 use super::*;
 
 impl Stack {
-    pub(super) fn fall_next_brick(&mut self) -> Result<(), String> {
+    pub(super) fn fall_next_brick(&mut self) -> Result<(), ParseError> {
         if let Some(mut brick) = self.snapshot.pop() {
             let mut highest_point = 0;
             for x in brick.min.x..=brick.max.x {
@@ -25,7 +25,8 @@ impl Stack {
             self.landed.push(brick.clone());
             Ok(())
         } else {
-            Err("No more bricks to fall".to_string())
+            Err(ParseError::new(1, "a brick to fall", "end of input")
+                .on_line(self.landed.len() + 1))
         }
     }
 
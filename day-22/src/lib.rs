@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, ParseError, Solver};
+
+mod chain_reaction;
+mod counting;
+mod falling;
+mod parsing;
+mod relations;
+mod sorting;
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    const DAY: u8 = 22;
+    type Parsed<'a> = Stack;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// Parses the snapshot and lets all the bricks fall into place.
+pub fn parse(input: &str) -> Result<Stack, ParseError> {
+    let mut stack = Stack::parse(input)?;
+    stack.sort_bricks();
+
+    while !stack.snapshot.is_empty() {
+        stack.fall_next_brick()?;
+    }
+
+    stack.find_relations();
+    Ok(stack)
+}
+
+/// Writes the bricks where they came to rest, from the ground up.
+pub fn render(stack: &Stack) -> String {
+    let mut landed: Vec<&Brick> = stack.landed.iter().collect();
+    landed.sort_by_key(|brick| (brick.min.z, brick.min.y, brick.min.x));
+    landed
+        .into_iter()
+        .map(|Brick { min, max, .. }| {
+            format!(
+                "{},{},{}~{},{},{}",
+                min.x, min.y, min.z, max.x, max.y, max.z
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(stack: &Stack) -> usize {
+    stack.count_safe_bricks()
+}
+
+pub fn part2(stack: &Stack) -> usize {
+    stack.get_chain_lengths().iter().sum()
+}
+
+pub struct Stack {
+    terrain: Vec<Vec<usize>>,
+    snapshot: Vec<Brick>,
+    landed: Vec<Brick>,
+    relations: HashMap<usize, Relation>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Relation {
+    supports: Vec<usize>,
+    stands_on: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Brick {
+    min: Coordinate,
+    max: Coordinate,
+    id: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+#[test]
+fn computes_chains_with_memoization() {}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    type Cuboid = ([usize; 3], [usize; 3]);
+
+    /// Bricks in a snapshot, each at its own heights so none of them overlap.
+    fn snapshot() -> impl Strategy<Value = Vec<Cuboid>> {
+        let brick = (0..10usize, 0..10usize, 0..3usize, 0..4usize);
+        proptest::collection::vec(brick, 1..15)
+            .prop_map(|bricks| {
+                bricks
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (x, y, axis, len))| {
+                        let min = [x, y, 1 + 4 * index];
+                        let mut max = min;
+                        max[axis] = (max[axis] + len).min(if axis == 2 { usize::MAX } else { 9 });
+                        (min, max)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .prop_shuffle()
+    }
+
+    fn write(bricks: &[Cuboid]) -> String {
+        bricks
+            .iter()
+            .map(|([x, y, z], [x2, y2, z2])| format!("{x},{y},{z}~{x2},{y2},{z2}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Lets the bricks fall, lowest first, and tells how many of them moved.
+    fn settle(bricks: &mut [Cuboid]) -> usize {
+        bricks.sort_by_key(|(min, _)| min[2]);
+        let mut heights = [[0; 10]; 10];
+        let mut moved = 0;
+        for (min, max) in bricks.iter_mut() {
+            let footprint =
+                || (min[0]..=max[0]).flat_map(|x| (min[1]..=max[1]).map(move |y| (x, y)));
+            let ground = footprint().map(|(x, y)| heights[y][x]).max().unwrap();
+            let drop = min[2] - ground - 1;
+            if drop > 0 {
+                moved += 1;
+            }
+            let (min_z, max_z) = (min[2] - drop, max[2] - drop);
+            for (x, y) in footprint() {
+                heights[y][x] = max_z;
+            }
+            min[2] = min_z;
+            max[2] = max_z;
+        }
+        moved
+    }
+
+    /// How many bricks fall for each brick taken out of the settled stack.
+    fn falls_without_each(input: &str) -> Vec<usize> {
+        let mut bricks: Vec<Cuboid> = input
+            .lines()
+            .map(|line| {
+                let numbers: Vec<usize> = line
+                    .split([',', '~'])
+                    .map(|number| number.parse().unwrap())
+                    .collect();
+                (
+                    [numbers[0], numbers[1], numbers[2]],
+                    [numbers[3], numbers[4], numbers[5]],
+                )
+            })
+            .collect();
+        settle(&mut bricks);
+        (0..bricks.len())
+            .map(|index| {
+                let mut rest = bricks.clone();
+                rest.remove(index);
+                settle(&mut rest)
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn settles_for_good(bricks in snapshot()) {
+            let settled = render(&parse(&write(&bricks)).unwrap());
+            prop_assert_eq!(render(&parse(&settled).unwrap()), settled);
+        }
+
+        #[test]
+        fn finds_bricks_that_make_others_fall(bricks in snapshot()) {
+            let input = write(&bricks);
+            let stack = parse(&input).unwrap();
+            let falls = falls_without_each(&input);
+            prop_assert_eq!(part1(&stack), falls.iter().filter(|&&fall| fall == 0).count());
+            prop_assert_eq!(part2(&stack), falls.iter().sum::<usize>());
+        }
+    }
+}
+
+pub fn integrate(input: &str) -> Result<(usize, usize), ParseError> {
+    let stack = parse(input)?;
+    Ok((part1(&stack), part2(&stack)))
+}
+
+#[test]
+fn integrates() {
+    let input = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+    let result = integrate(input).unwrap();
+    assert_eq!(result.0, 5);
+    assert_eq!(result.1, 7);
+}
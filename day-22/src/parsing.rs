@@ -1,39 +1,73 @@
+use aoc_common::{parse_lines, parse_number, ParseError};
+
 use super::*;
 
+/// Bricks have to fit onto the terrain, which is this many cells wide in x and y.
+const TERRAIN_SIZE: usize = 10;
+
 impl Stack {
-    pub(super) fn parse(input: &str) -> Self {
-        Self {
-            terrain: vec![vec![0; 10]; 10],
-            snapshot: input
-                .lines()
-                .enumerate() // Provides index (for id) and line
-                .map(|(index, line)| Brick::from(line, index + 1)) // Convert index to 1-based id
-                .collect(),
+    pub(super) fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut id = 0;
+        Ok(Self {
+            terrain: vec![vec![0; TERRAIN_SIZE]; TERRAIN_SIZE],
+            snapshot: parse_lines(input, |line| {
+                id += 1; // Ids are 1-based
+                Brick::from(line, id)
+            })?,
             landed: vec![],
             relations: HashMap::new(),
-        }
+        })
     }
 }
 
 impl Brick {
-    fn from(line: &str, id: usize) -> Self {
-        let parts: Vec<&str> = line.split('~').collect();
-        let min_coords: Vec<usize> = parts[0].split(',').map(|s| s.parse().unwrap()).collect();
-        let max_coords: Vec<usize> = parts[1].split(',').map(|s| s.parse().unwrap()).collect();
+    fn from(line: &str, id: usize) -> Result<Self, ParseError> {
+        let (min, max) = line
+            .split_once('~')
+            .ok_or_else(|| ParseError::end_of(line, "'~'"))?;
 
-        Brick {
-            min: Coordinate {
-                x: min_coords[0],
-                y: min_coords[1],
-                z: min_coords[2],
-            },
-            max: Coordinate {
-                x: max_coords[0],
-                y: max_coords[1],
-                z: max_coords[2],
-            },
+        let brick = Brick {
+            min: Coordinate::parse(line, min)?,
+            max: Coordinate::parse(line, max)?,
             id,
+        };
+        if brick.min.z == 0 {
+            return Err(ParseError::at(line, min, "a brick above the ground at z 0"));
+        }
+        let (start, end) = (&brick.min, &brick.max);
+        if end.x < start.x || end.y < start.y || end.z < start.z {
+            return Err(ParseError::at(line, max, "an end at or after the start"));
         }
+        Ok(brick)
+    }
+}
+
+impl Coordinate {
+    fn parse(line: &str, input: &str) -> Result<Self, ParseError> {
+        let mut coords = input.split(',');
+        let mut next = |name: &str| {
+            coords
+                .next()
+                .ok_or_else(|| ParseError::at(line, &input[input.len()..], name))
+        };
+        let (x, y, z) = (next("x")?, next("y")?, next("z")?);
+
+        Ok(Coordinate {
+            x: parse_on_terrain(line, x, "x")?,
+            y: parse_on_terrain(line, y, "y")?,
+            z: parse_number(line, z)?,
+        })
+    }
+}
+
+fn parse_on_terrain(line: &str, token: &str, name: &str) -> Result<usize, ParseError> {
+    match parse_number(line, token)? {
+        value if value < TERRAIN_SIZE => Ok(value),
+        _ => Err(ParseError::at(
+            line,
+            token,
+            format!("{name} below {TERRAIN_SIZE}"),
+        )),
     }
 }
 
@@ -46,7 +80,7 @@ fn parses_bricks() {
 2,0,5~2,2,5
 0,1,6~2,1,6
 1,1,8~1,1,9";
-    let stack = Stack::parse(input);
+    let stack = Stack::parse(input).unwrap();
     assert_eq!(stack.terrain.len(), 10);
     assert!(stack
         .terrain
@@ -93,3 +127,25 @@ fn parses_bricks() {
         ])
     );
 }
+
+#[test]
+fn reports_malformed_bricks() {
+    let error = Stack::parse("1,0,1~1,2,1\n0,0,2~2,0").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 10: expected z, found nothing"
+    );
+
+    let error = Stack::parse("1,0,1~1,12,1").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 9: expected y below 10, found \"12\""
+    );
+
+    let error = Stack::parse("1,1,18~1,1,9").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 8: expected an end at or after the start, found \"1,1,9\""
+    );
+    assert_eq!(Stack::parse("1,1,0~1,1,2").err().unwrap().column, 1);
+}
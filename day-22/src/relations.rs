@@ -57,14 +57,14 @@ impl Stack {
         relation.supports.iter().all(|supported_id| {
             self.relations
                 .get(supported_id)
-                .map_or(false, |supported_by| supported_by.stands_on.len() > 1)
+                .is_some_and(|supported_by| supported_by.stands_on.len() > 1)
         })
     }
 }
 
 #[test]
 fn check_floor_above() {
-    let mut stack = Stack {
+    let stack = Stack {
         terrain: vec![vec![]],
         snapshot: vec![],
         landed: vec![
@@ -2,8 +2,10 @@ use super::*;
 
 impl Stack {
     pub(super) fn sort_bricks(&mut self) {
-        self.snapshot.sort_by(|a, b| b.min.z.cmp(&a.min.z));
-        self.landed.sort_by(|a, b| b.min.z.cmp(&a.min.z));
+        self.snapshot
+            .sort_by_key(|brick| std::cmp::Reverse(brick.min.z));
+        self.landed
+            .sort_by_key(|brick| std::cmp::Reverse(brick.min.z));
     }
 }
 
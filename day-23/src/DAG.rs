@@ -3,4 +3,3 @@
 use super::*;
 
 mod longest_path;
-mod topological_sort;
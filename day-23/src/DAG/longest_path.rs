@@ -2,49 +2,39 @@ use super::*;
 
 impl Maze {
     pub(crate) fn find_longest_path(&self) -> usize {
-        let mut distances = HashMap::new();
+        let sorted_nodes = self
+            .paths
+            .topological_sort()
+            .expect("Slippery slopes only go one way, so the paths can't go around in circles");
 
         // Initialize distances for all nodes
-        for node in self.nodes.keys() {
-            distances.insert(
-                node,
-                if node == &self.sorted_nodes[0] {
-                    0
-                } else {
-                    isize::MIN
-                },
-            );
-        }
+        let mut distances = vec![isize::MIN; self.paths.len()];
+        distances[sorted_nodes[0]] = 0;
 
         // Relax edges along the topologically sorted nodes
-        for current_node in &self.sorted_nodes {
-            for edge in &self.edges {
-                if &edge.starting_node_loc == current_node {
-                    let distance_through_current =
-                        distances[current_node].saturating_add(edge.length as isize);
-                    let distance_to_target = distances.get_mut(&edge.ending_node_loc).unwrap();
-
-                    if *distance_to_target < distance_through_current {
-                        *distance_to_target = distance_through_current;
-                    }
+        for &current_node in &sorted_nodes {
+            for &(next_node, length) in self.paths.edges(current_node) {
+                let distance_through_current =
+                    distances[current_node].saturating_add(length as isize);
+                if distances[next_node] < distance_through_current {
+                    distances[next_node] = distance_through_current;
                 }
             }
         }
 
         // Find the maximum distance
-        *distances.values().max().unwrap() as usize
+        *distances.iter().max().unwrap() as usize
     }
 }
 
 #[test]
 fn finds_longest_path() {
-    let maze = Maze {
-        grid: vec![],
-        nodes: HashMap::from([
+    let maze = Maze::new(
+        vec![].into(),
+        HashMap::from([
             (
                 Coordinate { x: 1, y: 0 },
                 Node {
-                    id: 0,
                     is_visited: false,
                     node_type: NodeType::Start,
                     exits: vec![Direction::Down],
@@ -53,7 +43,6 @@ fn finds_longest_path() {
             (
                 Coordinate { x: 2, y: 3 },
                 Node {
-                    id: 1,
                     is_visited: false,
                     node_type: NodeType::Crossroad,
                     exits: vec![Direction::Left, Direction::Right],
@@ -62,44 +51,77 @@ fn finds_longest_path() {
             (
                 Coordinate { x: 4, y: 4 },
                 Node {
-                    id: 2,
                     is_visited: false,
                     node_type: NodeType::Finish,
                     exits: vec![],
                 },
             ),
         ]),
-        edges: vec![
-            Edge {
-                starting_node_id: 0,
-                ending_node_id: 1,
-                starting_node_loc: Coordinate { x: 1, y: 0 },
-                ending_node_loc: Coordinate { x: 2, y: 3 },
-                length: 4,
-            },
-            Edge {
-                starting_node_id: 0,
-                ending_node_id: 1,
-                starting_node_loc: Coordinate { x: 2, y: 3 },
-                ending_node_loc: Coordinate { x: 4, y: 4 },
-                length: 3,
-            },
-            Edge {
-                starting_node_id: 0,
-                ending_node_id: 1,
-                starting_node_loc: Coordinate { x: 2, y: 3 },
-                ending_node_loc: Coordinate { x: 4, y: 4 },
-                length: 7,
-            },
-        ],
-        sorted_nodes: vec![
-            Coordinate { x: 1, y: 0 },
-            Coordinate { x: 2, y: 3 },
-            Coordinate { x: 4, y: 4 },
-        ],
-    };
+        &SlopesBehavior::Slippery,
+    )
+    .connected(&[
+        (Coordinate { x: 1, y: 0 }, Coordinate { x: 2, y: 3 }, 4),
+        (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 3),
+        (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 7),
+    ]);
 
     let result = maze.find_longest_path();
 
     assert_eq!(result, 4 + 7);
 }
+
+#[test]
+fn sorts_in_topological_order() {
+    let maze = Maze::new(
+        vec![].into(),
+        HashMap::from([
+            (
+                Coordinate { x: 1, y: 0 },
+                Node {
+                    is_visited: false,
+                    node_type: NodeType::Start,
+                    exits: vec![Direction::Down],
+                },
+            ),
+            (
+                Coordinate { x: 2, y: 3 },
+                Node {
+                    is_visited: false,
+                    node_type: NodeType::Crossroad,
+                    exits: vec![Direction::Left, Direction::Right],
+                },
+            ),
+            (
+                Coordinate { x: 4, y: 4 },
+                Node {
+                    is_visited: false,
+                    node_type: NodeType::Finish,
+                    exits: vec![],
+                },
+            ),
+        ]),
+        &SlopesBehavior::Slippery,
+    )
+    .connected(&[
+        (Coordinate { x: 1, y: 0 }, Coordinate { x: 2, y: 3 }, 4),
+        (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 3),
+        (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 7),
+    ]);
+
+    let sorted_nodes: Vec<Coordinate> = maze
+        .paths
+        .topological_sort()
+        .unwrap()
+        .into_iter()
+        .map(|id| *maze.paths.key(id))
+        .collect();
+
+    assert_eq!(
+        sorted_nodes,
+        vec![
+            Coordinate { x: 1, y: 0 },
+            Coordinate { x: 2, y: 3 },
+            Coordinate { x: 4, y: 4 }
+        ]
+    );
+}
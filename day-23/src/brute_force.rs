@@ -10,17 +10,8 @@ impl Maze {
             .map(|(coord, _)| coord)
             .unwrap();
 
-        let prefinish_node_coord = self
-            .edges
-            .iter()
-            .find_map(|edge| {
-                if edge.ending_node_loc == *finish_node_coord {
-                    Some(edge.starting_node_loc)
-                } else {
-                    None
-                }
-            })
-            .unwrap();
+        let finish = self.paths.id(finish_node_coord).unwrap();
+        let prefinish_node_coord = *self.paths.key(self.paths.predecessors(finish)[0]);
 
         let prefinish_node = self.nodes.get_mut(&prefinish_node_coord).unwrap();
         prefinish_node.node_type = NodeType::PreFinish;
@@ -34,12 +25,8 @@ impl Maze {
             .unwrap()
     }
 
-    fn find_edges_from_coord(&self, start: &Coordinate) -> Vec<Edge> {
-        self.edges
-            .iter()
-            .filter(|edge| edge.starting_node_loc == *start)
-            .cloned()
-            .collect()
+    fn find_edges_from_coord(&self, start: &Coordinate) -> &[(NodeId, usize)] {
+        self.paths.edges(self.paths.id(start).unwrap())
     }
 }
 
@@ -62,23 +49,25 @@ impl Pathfinder {
 
     fn process_node(&mut self, path: &Path) {
         let coord = path.next_node.unwrap();
-        let edges = self.maze.find_edges_from_coord(&coord);
-        for edge in edges {
-            let next_node = self.maze.nodes.get(&edge.ending_node_loc).unwrap();
-            if path.visited_nodes.contains(&edge.ending_node_loc) {
+        for &(ending_node, length) in self.maze.find_edges_from_coord(&coord) {
+            let ending_node_loc = *self.maze.paths.key(ending_node);
+            let next_node = self.maze.nodes.get(&ending_node_loc).unwrap();
+            if path.visited_nodes.contains(&ending_node_loc) {
                 continue;
             }
             // number 6 is selected to have a margin of some nodes before reaching the prefinish node
             // it should be among the last, but maybe not the precisely last but one
+            // small mazes can't afford the full margin, so it's capped to about half of the nodes
+            let prefinish_margin = 6.min(self.maze.nodes.len() / 2 + 1);
             if next_node.node_type == NodeType::PreFinish
-                && path.visited_nodes.len() < self.maze.nodes.len() - 6
+                && path.visited_nodes.len() < self.maze.nodes.len() - prefinish_margin
             {
                 continue;
             }
             let mut new_path = path.clone();
             new_path.visited_nodes.push(coord);
-            new_path.length += edge.length;
-            new_path.next_node = Some(edge.ending_node_loc);
+            new_path.length += length;
+            new_path.next_node = Some(ending_node_loc);
 
             if next_node.node_type == NodeType::Finish
                 && (self.longest_path.is_none()
@@ -100,8 +89,7 @@ impl Pathfinder {
         };
         self.queue.push(path);
 
-        while !self.queue.is_empty() {
-            let path = self.queue.pop().unwrap();
+        while let Some(path) = self.queue.pop() {
             self.process_node(&path);
         }
 
@@ -144,13 +132,12 @@ mod tests {
     }
 
     fn create_test_maze() -> Maze {
-        let maze = Maze {
-            grid: vec![],
-            nodes: HashMap::from([
+        Maze::new(
+            vec![].into(),
+            HashMap::from([
                 (
                     Coordinate { x: 1, y: 0 },
                     Node {
-                        id: 0,
                         is_visited: false,
                         node_type: NodeType::Start,
                         exits: vec![Direction::Down],
@@ -159,7 +146,6 @@ mod tests {
                 (
                     Coordinate { x: 2, y: 3 },
                     Node {
-                        id: 1,
                         is_visited: false,
                         node_type: NodeType::Crossroad,
                         exits: vec![Direction::Left, Direction::Right],
@@ -168,7 +154,6 @@ mod tests {
                 (
                     Coordinate { x: 1, y: 3 },
                     Node {
-                        id: 2,
                         is_visited: false,
                         node_type: NodeType::Crossroad,
                         exits: vec![Direction::Left, Direction::Right],
@@ -177,7 +162,6 @@ mod tests {
                 (
                     Coordinate { x: 4, y: 4 },
                     Node {
-                        id: 3,
                         is_visited: false,
                         node_type: NodeType::Finish,
                         exits: vec![],
@@ -186,58 +170,21 @@ mod tests {
                 (
                     Coordinate { x: 0, y: 1 },
                     Node {
-                        id: 4,
                         is_visited: false,
                         node_type: NodeType::Crossroad,
                         exits: vec![],
                     },
                 ),
             ]),
-            edges: vec![
-                Edge {
-                    starting_node_id: 0,
-                    ending_node_id: 1,
-                    starting_node_loc: Coordinate { x: 1, y: 0 },
-                    ending_node_loc: Coordinate { x: 2, y: 3 },
-                    length: 4,
-                },
-                Edge {
-                    starting_node_id: 0,
-                    ending_node_id: 2,
-                    starting_node_loc: Coordinate { x: 1, y: 0 },
-                    ending_node_loc: Coordinate { x: 1, y: 3 },
-                    length: 3,
-                },
-                Edge {
-                    starting_node_id: 2,
-                    ending_node_id: 1,
-                    starting_node_loc: Coordinate { x: 1, y: 3 },
-                    ending_node_loc: Coordinate { x: 2, y: 3 },
-                    length: 4,
-                },
-                Edge {
-                    starting_node_id: 1,
-                    ending_node_id: 3,
-                    starting_node_loc: Coordinate { x: 2, y: 3 },
-                    ending_node_loc: Coordinate { x: 4, y: 4 },
-                    length: 3,
-                },
-                Edge {
-                    starting_node_id: 0,
-                    ending_node_id: 4,
-                    starting_node_loc: Coordinate { x: 1, y: 0 },
-                    ending_node_loc: Coordinate { x: 0, y: 1 },
-                    length: 1,
-                },
-            ],
-            sorted_nodes: vec![
-                Coordinate { x: 1, y: 0 },
-                Coordinate { x: 1, y: 3 },
-                Coordinate { x: 2, y: 3 },
-                Coordinate { x: 4, y: 4 },
-            ],
-        };
-        maze
+            &SlopesBehavior::Slippery,
+        )
+        .connected(&[
+            (Coordinate { x: 1, y: 0 }, Coordinate { x: 2, y: 3 }, 4),
+            (Coordinate { x: 1, y: 0 }, Coordinate { x: 1, y: 3 }, 3),
+            (Coordinate { x: 1, y: 3 }, Coordinate { x: 2, y: 3 }, 4),
+            (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 3),
+            (Coordinate { x: 1, y: 0 }, Coordinate { x: 0, y: 1 }, 1),
+        ])
     }
 
     #[test]
@@ -300,13 +247,12 @@ mod tests {
 
     #[test]
     fn finds_longest_path() {
-        let mut maze = Maze {
-            grid: vec![],
-            nodes: HashMap::from([
+        let mut maze = Maze::new(
+            vec![].into(),
+            HashMap::from([
                 (
                     Coordinate { x: 1, y: 0 },
                     Node {
-                        id: 0,
                         is_visited: false,
                         node_type: NodeType::Start,
                         exits: vec![],
@@ -315,7 +261,6 @@ mod tests {
                 (
                     Coordinate { x: 2, y: 3 },
                     Node {
-                        id: 1,
                         is_visited: false,
                         node_type: NodeType::Crossroad,
                         exits: vec![],
@@ -324,7 +269,6 @@ mod tests {
                 (
                     Coordinate { x: 1, y: 3 },
                     Node {
-                        id: 2,
                         is_visited: false,
                         node_type: NodeType::Crossroad,
                         exits: vec![],
@@ -333,45 +277,20 @@ mod tests {
                 (
                     Coordinate { x: 4, y: 4 },
                     Node {
-                        id: 3,
                         is_visited: false,
                         node_type: NodeType::Finish,
                         exits: vec![],
                     },
                 ),
             ]),
-            edges: vec![
-                Edge {
-                    starting_node_id: 0,
-                    ending_node_id: 1,
-                    starting_node_loc: Coordinate { x: 1, y: 0 },
-                    ending_node_loc: Coordinate { x: 2, y: 3 },
-                    length: 4,
-                },
-                Edge {
-                    starting_node_id: 0,
-                    ending_node_id: 2,
-                    starting_node_loc: Coordinate { x: 1, y: 0 },
-                    ending_node_loc: Coordinate { x: 1, y: 3 },
-                    length: 3,
-                },
-                Edge {
-                    starting_node_id: 2,
-                    ending_node_id: 1,
-                    starting_node_loc: Coordinate { x: 1, y: 3 },
-                    ending_node_loc: Coordinate { x: 2, y: 3 },
-                    length: 4,
-                },
-                Edge {
-                    starting_node_id: 1,
-                    ending_node_id: 3,
-                    starting_node_loc: Coordinate { x: 2, y: 3 },
-                    ending_node_loc: Coordinate { x: 4, y: 4 },
-                    length: 7,
-                },
-            ],
-            sorted_nodes: vec![],
-        };
+            &SlopesBehavior::Slippery,
+        )
+        .connected(&[
+            (Coordinate { x: 1, y: 0 }, Coordinate { x: 2, y: 3 }, 4),
+            (Coordinate { x: 1, y: 0 }, Coordinate { x: 1, y: 3 }, 3),
+            (Coordinate { x: 1, y: 3 }, Coordinate { x: 2, y: 3 }, 4),
+            (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 7),
+        ]);
         maze.find_prefinish();
         let mut pathfinder = Pathfinder::new(&maze);
         let longest_path = pathfinder.find_longest_path();
@@ -0,0 +1,260 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, ParseError, Solver};
+use aoc_graph::{Graph, NodeId};
+use aoc_grid::Grid;
+
+mod DAG;
+mod brute_force;
+mod parsing;
+mod printing;
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    const DAY: u8 = 23;
+    type Parsed<'a> = Trails;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// The same map reads as a different maze depending on whether the slopes can be climbed.
+pub struct Trails {
+    pub slippery: Maze,
+    pub grippy: Maze,
+}
+
+pub fn parse(input: &str) -> Result<Trails, ParseError> {
+    Ok(Trails {
+        slippery: Maze::parse(input, &SlopesBehavior::Slippery)?,
+        grippy: Maze::parse(input, &SlopesBehavior::Grippy)?,
+    })
+}
+
+pub fn render(trails: &Trails) -> String {
+    trails.slippery.grid.to_string()
+}
+
+pub fn part1(trails: &Trails) -> usize {
+    find_longest_hike(&trails.slippery, &SlopesBehavior::Slippery)
+}
+
+pub fn part2(trails: &Trails) -> usize {
+    find_longest_hike(&trails.grippy, &SlopesBehavior::Grippy)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Tile {
+    Wall,
+    Start,
+    Path,
+    Finish,
+    Arrow(Direction),
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum NodeType {
+    Start,
+    Finish,
+    Crossroad,
+    PreFinish,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Node {
+    is_visited: bool,
+    node_type: NodeType,
+    exits: Vec<Direction>,
+}
+
+/// Paths between the nodes are weighted by their length, and only go downhill when the slopes are slippery.
+#[derive(Clone)]
+pub struct Maze {
+    grid: Grid<Tile>,
+    nodes: HashMap<Coordinate, Node>,
+    paths: Graph<Coordinate, usize>,
+}
+
+impl Maze {
+    /// A maze without paths yet, its nodes interned from the top row down.
+    fn new(grid: Grid<Tile>, nodes: HashMap<Coordinate, Node>, slopes: &SlopesBehavior) -> Self {
+        let mut paths = match slopes {
+            SlopesBehavior::Slippery => Graph::directed(),
+            SlopesBehavior::Grippy => Graph::undirected(),
+        };
+        let mut coordinates: Vec<Coordinate> = nodes.keys().copied().collect();
+        coordinates.sort_by_key(|coordinate| (coordinate.y, coordinate.x));
+        for coordinate in coordinates {
+            paths.intern(coordinate);
+        }
+        Maze { grid, nodes, paths }
+    }
+
+    #[cfg(test)]
+    fn connected(mut self, paths: &[(Coordinate, Coordinate, usize)]) -> Self {
+        for &(from, to, length) in paths {
+            self.paths.add_edge(from, to, length);
+        }
+        self
+    }
+
+    /// Every path as `(from, to, length)`, those of grippy mazes in both directions.
+    #[cfg(test)]
+    fn path_list(&self) -> Vec<(Coordinate, Coordinate, usize)> {
+        let mut paths: Vec<_> = self
+            .paths
+            .nodes()
+            .flat_map(|(id, from)| {
+                self.paths
+                    .edges(id)
+                    .iter()
+                    .map(|&(to, length)| (*from, *self.paths.key(to), length))
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum SlopesBehavior {
+    Slippery,
+    Grippy,
+}
+
+#[cfg(test)]
+fn integrate(input: &str, slopes: SlopesBehavior) -> usize {
+    find_longest_hike(&Maze::parse(input, &slopes).unwrap(), &slopes)
+}
+
+fn find_longest_hike(maze: &Maze, slopes: &SlopesBehavior) -> usize {
+    if *slopes == SlopesBehavior::Slippery {
+        maze.find_longest_path()
+    } else {
+        let mut pathfinder = brute_force::Pathfinder::new(maze);
+        pathfinder.find_longest_path()
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// A walled map with the entrance top left and the exit bottom right, anything inside.
+    fn hiking_map() -> impl Strategy<Value = String> {
+        (3..12usize, 3..12usize).prop_flat_map(|(width, height)| {
+            let tile = prop_oneof![
+                4 => Just('.'),
+                4 => Just('#'),
+                1 => Just('>'),
+                1 => Just('v'),
+                1 => Just('<'),
+                1 => Just('^'),
+            ];
+            let inside = proptest::collection::vec(tile, (width - 2) * (height - 2));
+            inside.prop_map(move |inside| {
+                let mut rows = vec![format!("#.{}", "#".repeat(width - 2))];
+                rows.extend(
+                    inside
+                        .chunks(width - 2)
+                        .map(|row| format!("#{}#", row.iter().collect::<String>())),
+                );
+                rows.push(format!("{}.#", "#".repeat(width - 2)));
+                rows.join("\n")
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in hiking_map()) {
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+    }
+}
+
+#[test]
+fn integrates() {
+    let input = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    assert_eq!(integrate(input, SlopesBehavior::Slippery), 94);
+}
+
+#[test]
+fn integrates_grippy_slopes() {
+    let input = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    assert_eq!(integrate(input, SlopesBehavior::Grippy), 154);
+}
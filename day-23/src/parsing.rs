@@ -1,3 +1,5 @@
+use aoc_common::ParseError;
+
 use self::nodes::get_nodes_from_grid;
 
 use super::*;
@@ -6,47 +8,28 @@ mod edges;
 mod nodes;
 
 impl Maze {
-    pub(super) fn parse(input: &str, slopes: &SlopesBehavior) -> Self {
-        let lines: Vec<&str> = input.split('\n').collect();
-        let y_len = lines.len();
-        let mut grid = Vec::new();
-
-        for (y, line) in lines.iter().enumerate() {
-            if line.is_empty() {
-                continue;
-            }
-            let mut row = Vec::new();
-            let x_len = line.len();
-            for (x, ch) in line.chars().enumerate() {
-                let tile = match ch {
-                    'v' => Tile::Arrow(Direction::Down),
-                    '^' => Tile::Arrow(Direction::Up),
-                    '<' => Tile::Arrow(Direction::Left),
-                    '>' => Tile::Arrow(Direction::Right),
-                    '#' => Tile::Wall,
-                    '.' => Tile::Path,
-                    _ => panic!("Unexpected character"),
-                };
-
-                if x == 1 && y == 0 {
-                    row.push(Tile::Start);
-                } else if x == x_len - 2 && y == y_len - 2 {
-                    row.push(Tile::Finish);
-                } else {
-                    row.push(tile);
-                }
-            }
-            grid.push(row);
+    pub(super) fn parse(input: &str, slopes: &SlopesBehavior) -> Result<Self, ParseError> {
+        let mut grid = Grid::try_parse(input, "'#', '.' or a slope", |ch| match ch {
+            'v' => Some(Tile::Arrow(Direction::Down)),
+            '^' => Some(Tile::Arrow(Direction::Up)),
+            '<' => Some(Tile::Arrow(Direction::Left)),
+            '>' => Some(Tile::Arrow(Direction::Right)),
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Path),
+            _ => None,
+        })?;
+        if grid.width() < 2 {
+            let row = input.lines().next().unwrap_or_default();
+            return Err(ParseError::end_of(row, "a row of at least 2 tiles").on_line(1));
         }
+        grid[(1, 0)] = Tile::Start;
+        let finish = (grid.width() - 2, grid.height() - 1);
+        grid[finish] = Tile::Finish;
 
-        let mut maze = Maze {
-            grid: grid.clone(),
-            nodes: get_nodes_from_grid(&grid, slopes),
-            edges: vec![],
-            sorted_nodes: vec![],
-        };
-        maze.find_edges(slopes);
-        maze
+        let nodes = get_nodes_from_grid(&grid, slopes);
+        let mut maze = Maze::new(grid, nodes, slopes);
+        maze.find_edges();
+        Ok(maze)
     }
 }
 
@@ -77,8 +60,21 @@ mod tests {
             ],
         ];
 
-        let maze = Maze::parse(input, &SlopesBehavior::Slippery);
+        let maze = Maze::parse(input, &SlopesBehavior::Slippery).unwrap();
 
         assert_eq!(maze.grid, expected);
     }
+
+    #[test]
+    fn rejects_unknown_tiles() {
+        let error = Maze::parse("#.#\n#o#\n#.#", &SlopesBehavior::Slippery)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected '#', '.' or a slope, found 'o'"
+        );
+        let error = Maze::parse("#", &SlopesBehavior::Slippery).err().unwrap();
+        assert_eq!((error.line, error.column), (1, 2));
+    }
 }
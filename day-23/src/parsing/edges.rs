@@ -1,35 +1,34 @@
 use super::*;
 
 impl Maze {
-    pub(super) fn find_edges(&mut self, slopes: &SlopesBehavior) {
-        self.edges.clear();
-
-        for (start_coord, start_node) in self.nodes.clone() {
-            for exit_dir in &start_node.exits {
+    pub(super) fn find_edges(&mut self) {
+        let mut starts: Vec<(Coordinate, Vec<Direction>)> = self
+            .nodes
+            .iter()
+            .map(|(coord, node)| (*coord, node.exits.clone()))
+            .collect();
+        starts.sort_by_key(|(coord, _)| *coord);
+
+        let mut found = HashSet::new();
+        for (start_coord, exits) in starts {
+            for exit_dir in &exits {
                 let mut visited = HashSet::new();
                 visited.insert(start_coord);
 
-                self.explore_path(&start_coord, &start_coord, exit_dir, &mut visited, 0);
+                self.explore_path(
+                    &start_coord,
+                    &start_coord,
+                    exit_dir,
+                    &mut visited,
+                    &mut found,
+                    0,
+                );
             }
         }
-
-        if slopes == &SlopesBehavior::Grippy {
-            for edge in self.edges.clone() {
-                self.edges.push(Edge {
-                    starting_node_id: edge.ending_node_id,
-                    ending_node_id: edge.starting_node_id,
-                    starting_node_loc: edge.ending_node_loc,
-                    ending_node_loc: edge.starting_node_loc,
-                    length: edge.length,
-                });
-            }
-        }
-
-        self.edges.sort();
     }
 
     fn is_within_bounds(&self, coord: &Coordinate) -> bool {
-        coord.x < self.grid[0].len() && coord.y < self.grid.len()
+        coord.x < self.grid.width() && coord.y < self.grid.height()
     }
 
     fn explore_path(
@@ -38,6 +37,7 @@ impl Maze {
         started_from: &Coordinate,
         dir: &Direction,
         visited: &mut HashSet<Coordinate>,
+        found: &mut HashSet<(NodeId, NodeId)>,
         length: usize,
     ) {
         // Calculate next coordinate based on direction
@@ -71,24 +71,14 @@ impl Maze {
         visited.insert(next_coord);
 
         // Check if the next coordinate is another node
-        if self.nodes.get(&next_coord).is_some() {
-            // Found another node, create an edge
-            #[allow(clippy::bool_comparison)]
-            if self.edges.iter().any(|edge| {
-                edge.starting_node_loc == next_coord && edge.ending_node_loc == *started_from
-            }) == false
-            {
-                self.edges.push(Edge {
-                    starting_node_id: self.nodes.get(started_from).unwrap().id,
-                    ending_node_id: self.nodes.get(&next_coord).unwrap().id,
-                    starting_node_loc: *started_from,
-                    ending_node_loc: next_coord,
-                    length: length + 1,
-                });
-                return;
-            } else {
-                return;
-            };
+        if let Some(end) = self.paths.id(&next_coord) {
+            // Found another node, connect it unless the path was already walked the other way around
+            let start = self.paths.id(started_from).unwrap();
+            if !found.contains(&(end, start)) {
+                found.insert((start, end));
+                self.paths.connect(start, end, length + 1);
+            }
+            return;
         }
 
         // Continue exploring in all directions except the opposite
@@ -106,7 +96,14 @@ impl Maze {
             Direction::Right,
         ] {
             if new_dir != opposite_dir {
-                self.explore_path(&next_coord, started_from, &new_dir, visited, length + 1);
+                self.explore_path(
+                    &next_coord,
+                    started_from,
+                    &new_dir,
+                    visited,
+                    found,
+                    length + 1,
+                );
             }
         }
     }
@@ -119,92 +116,38 @@ mod tests {
 
     #[test]
     fn creates_edges_slippery() {
-        let mut maze = init_maze();
+        let mut maze = init_maze(&SlopesBehavior::Slippery);
 
         let expected_edges = vec![
-            Edge {
-                starting_node_id: 0,
-                ending_node_id: 1,
-                starting_node_loc: Coordinate { x: 1, y: 0 },
-                ending_node_loc: Coordinate { x: 2, y: 3 },
-                length: 4,
-            },
-            Edge {
-                starting_node_id: 1,
-                ending_node_id: 2,
-                starting_node_loc: Coordinate { x: 2, y: 3 },
-                ending_node_loc: Coordinate { x: 4, y: 4 },
-                length: 3,
-            },
-            Edge {
-                starting_node_id: 1,
-                ending_node_id: 2,
-                starting_node_loc: Coordinate { x: 2, y: 3 },
-                ending_node_loc: Coordinate { x: 4, y: 4 },
-                length: 7,
-            },
+            (Coordinate { x: 1, y: 0 }, Coordinate { x: 2, y: 3 }, 4),
+            (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 3),
+            (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 7),
         ];
-        maze.find_edges(&SlopesBehavior::Slippery);
+        maze.find_edges();
 
-        assert_eq!(maze.edges, expected_edges);
+        assert_eq!(maze.path_list(), expected_edges);
     }
 
     #[test]
     fn creates_edges_grippy() {
-        let mut maze = init_maze();
+        let mut maze = init_maze(&SlopesBehavior::Grippy);
 
         let expected_edges = vec![
-            Edge {
-                starting_node_id: 0,
-                ending_node_id: 1,
-                starting_node_loc: Coordinate { x: 1, y: 0 },
-                ending_node_loc: Coordinate { x: 2, y: 3 },
-                length: 4,
-            },
-            Edge {
-                starting_node_id: 1,
-                ending_node_id: 0,
-                starting_node_loc: Coordinate { x: 2, y: 3 },
-                ending_node_loc: Coordinate { x: 1, y: 0 },
-                length: 4,
-            },
-            Edge {
-                starting_node_id: 1,
-                ending_node_id: 2,
-                starting_node_loc: Coordinate { x: 2, y: 3 },
-                ending_node_loc: Coordinate { x: 4, y: 4 },
-                length: 3,
-            },
-            Edge {
-                starting_node_id: 1,
-                ending_node_id: 2,
-                starting_node_loc: Coordinate { x: 2, y: 3 },
-                ending_node_loc: Coordinate { x: 4, y: 4 },
-                length: 7,
-            },
-            Edge {
-                starting_node_id: 2,
-                ending_node_id: 1,
-                starting_node_loc: Coordinate { x: 4, y: 4 },
-                ending_node_loc: Coordinate { x: 2, y: 3 },
-                length: 3,
-            },
-            Edge {
-                starting_node_id: 2,
-                ending_node_id: 1,
-                starting_node_loc: Coordinate { x: 4, y: 4 },
-                ending_node_loc: Coordinate { x: 2, y: 3 },
-                length: 7,
-            },
+            (Coordinate { x: 1, y: 0 }, Coordinate { x: 2, y: 3 }, 4),
+            (Coordinate { x: 2, y: 3 }, Coordinate { x: 1, y: 0 }, 4),
+            (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 3),
+            (Coordinate { x: 2, y: 3 }, Coordinate { x: 4, y: 4 }, 7),
+            (Coordinate { x: 4, y: 4 }, Coordinate { x: 2, y: 3 }, 3),
+            (Coordinate { x: 4, y: 4 }, Coordinate { x: 2, y: 3 }, 7),
         ];
-        maze.find_edges(&SlopesBehavior::Grippy);
+        maze.find_edges();
 
-        assert_eq!(maze.edges, expected_edges);
+        assert_eq!(maze.path_list(), expected_edges);
     }
 
-    fn init_maze() -> Maze {
-        let maze = Maze {
-            grid: vec![
+    fn init_maze(slopes: &SlopesBehavior) -> Maze {
+        Maze::new(
+            vec![
                 vec![
                     Tile::Wall,
                     Tile::Start,
@@ -253,12 +196,12 @@ mod tests {
                     Tile::Path,
                     Tile::Wall,
                 ],
-            ],
-            nodes: HashMap::from([
+            ]
+            .into(),
+            HashMap::from([
                 (
                     Coordinate { x: 1, y: 0 },
                     Node {
-                        id: 0,
                         is_visited: false,
                         node_type: NodeType::Start,
                         exits: vec![Direction::Down],
@@ -267,7 +210,6 @@ mod tests {
                 (
                     Coordinate { x: 2, y: 3 },
                     Node {
-                        id: 1,
                         is_visited: false,
                         node_type: NodeType::Crossroad,
                         exits: vec![Direction::Left, Direction::Right],
@@ -276,16 +218,13 @@ mod tests {
                 (
                     Coordinate { x: 4, y: 4 },
                     Node {
-                        id: 2,
                         is_visited: false,
                         node_type: NodeType::Finish,
                         exits: vec![],
                     },
                 ),
             ]),
-            edges: vec![],
-            sorted_nodes: vec![],
-        };
-        maze
+            slopes,
+        )
     }
 }
@@ -1,12 +1,12 @@
 use super::*;
 
 pub(super) fn get_nodes_from_grid(
-    grid: &[Vec<Tile>],
+    grid: &Grid<Tile>,
     slopes: &SlopesBehavior,
 ) -> HashMap<Coordinate, Node> {
     let mut nodes = HashMap::new();
 
-    for (y, row) in grid.iter().enumerate() {
+    for (y, row) in grid.rows().enumerate() {
         for (x, tile) in row.iter().enumerate() {
             let coord = Coordinate { x, y };
 
@@ -18,7 +18,7 @@ pub(super) fn get_nodes_from_grid(
                     if y > 0 {
                         surrounded &= matches!(grid[y - 1][x], Tile::Wall | Tile::Arrow(_));
                     }
-                    if y < grid.len() - 1 {
+                    if y < grid.height() - 1 {
                         surrounded &= matches!(grid[y + 1][x], Tile::Wall | Tile::Arrow(_));
                     }
                     if x > 0 {
@@ -51,7 +51,7 @@ pub(super) fn get_nodes_from_grid(
                         }
                     }
                 }
-                if y < grid.len() - 1 {
+                if y < grid.height() - 1 {
                     if let Tile::Arrow(direction) = &grid[y + 1][x] {
                         if slopes == &SlopesBehavior::Slippery && direction == &Direction::Down
                             || slopes == &SlopesBehavior::Grippy
@@ -87,7 +87,6 @@ pub(super) fn get_nodes_from_grid(
                 nodes.insert(
                     coord,
                     Node {
-                        id: nodes.len(),
                         is_visited: false,
                         node_type,
                         exits,
@@ -149,7 +148,6 @@ fn parses_grid_to_nodes() {
         (
             Coordinate { x: 1, y: 0 },
             Node {
-                id: 0,
                 is_visited: false,
                 node_type: NodeType::Start,
                 exits: vec![Direction::Down],
@@ -158,7 +156,6 @@ fn parses_grid_to_nodes() {
         (
             Coordinate { x: 2, y: 3 },
             Node {
-                id: 1,
                 is_visited: false,
                 node_type: NodeType::Crossroad,
                 exits: vec![Direction::Left, Direction::Right],
@@ -167,7 +164,6 @@ fn parses_grid_to_nodes() {
         (
             Coordinate { x: 4, y: 4 },
             Node {
-                id: 2,
                 is_visited: false,
                 node_type: NodeType::Finish,
                 exits: vec![],
@@ -175,7 +171,8 @@ fn parses_grid_to_nodes() {
         ),
     ]);
 
-    let nodes: HashMap<Coordinate, Node> = get_nodes_from_grid(&grid, &SlopesBehavior::Slippery);
+    let nodes: HashMap<Coordinate, Node> =
+        get_nodes_from_grid(&grid.into(), &SlopesBehavior::Slippery);
 
     assert_eq!(nodes, expected);
 }
@@ -229,7 +226,6 @@ fn parses_grippy_slopes_to_nodes() {
         (
             Coordinate { x: 1, y: 0 },
             Node {
-                id: 0,
                 is_visited: false,
                 node_type: NodeType::Start,
                 exits: vec![Direction::Down],
@@ -238,7 +234,6 @@ fn parses_grippy_slopes_to_nodes() {
         (
             Coordinate { x: 2, y: 3 },
             Node {
-                id: 1,
                 is_visited: false,
                 node_type: NodeType::Crossroad,
                 exits: vec![Direction::Up, Direction::Left, Direction::Right],
@@ -247,7 +242,6 @@ fn parses_grippy_slopes_to_nodes() {
         (
             Coordinate { x: 4, y: 4 },
             Node {
-                id: 2,
                 is_visited: false,
                 node_type: NodeType::Finish,
                 exits: vec![],
@@ -255,7 +249,8 @@ fn parses_grippy_slopes_to_nodes() {
         ),
     ]);
 
-    let nodes: HashMap<Coordinate, Node> = get_nodes_from_grid(&grid, &SlopesBehavior::Grippy);
+    let nodes: HashMap<Coordinate, Node> =
+        get_nodes_from_grid(&grid.into(), &SlopesBehavior::Grippy);
 
     assert_eq!(nodes, expected);
 }
@@ -64,7 +64,7 @@ mod tests {
             },
         };
 
-        let area = Area::new(7, 27);
+        let area = Area::from_str("", 7, 27).unwrap();
         let intercept = a.detect_2_d_collision(&b);
         assert_eq!(
             intercept,
@@ -74,7 +74,7 @@ mod tests {
                 z: 0.0
             })
         );
-        assert_eq!(area.contains_2_d(&intercept.unwrap()), true);
+        assert!(area.contains_2_d(&intercept.unwrap()));
     }
 
     #[test]
@@ -104,7 +104,7 @@ mod tests {
             },
         };
 
-        let area = Area::new(7, 27);
+        let area = Area::from_str("", 7, 27).unwrap();
         let intercept = a.detect_2_d_collision(&b);
         assert_eq!(
             intercept,
@@ -114,7 +114,7 @@ mod tests {
                 z: 0.0
             })
         );
-        assert_eq!(area.contains_2_d(&intercept.unwrap()), false);
+        assert!(!area.contains_2_d(&intercept.unwrap()));
     }
 
     #[test]
@@ -144,7 +144,6 @@ mod tests {
             },
         };
 
-        let area = Area::new(7, 27);
         let intercept = a.detect_2_d_collision(&b);
         assert_eq!(intercept, None);
     }
@@ -0,0 +1,258 @@
+mod collisions_2_d;
+mod parsing;
+mod planes_3_d;
+
+use aoc_common::{Answer, ParseError, Solver};
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    const DAY: u8 = 24;
+    type Parsed<'a> = Area;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part1(parsed).into()))
+    }
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(Some(part2(parsed).into()))
+    }
+}
+
+/// The rock is aimed from the first two hailstones, so there have to be at least two.
+pub fn parse(input: &str) -> Result<Area, ParseError> {
+    let area = Area::from_str(input, 200000000000000, 400000000000000)?;
+    if area.hailstones.len() < 2 {
+        return Err(
+            ParseError::new(1, "a hailstone", "end of input").on_line(area.hailstones.len() + 1)
+        );
+    }
+    Ok(area)
+}
+
+pub fn render(area: &Area) -> String {
+    area.hailstones
+        .iter()
+        .map(|Hailstone { position, velocity }| {
+            let (p, v) = (position, velocity);
+            format!("{}, {}, {} @ {}, {}, {}", p.x, p.y, p.z, v.x, v.y, v.z)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(area: &Area) -> u64 {
+    area.count_2_d_intersections("")
+}
+
+pub fn part2(area: &Area) -> u64 {
+    let mut area = area.clone();
+
+    area.sort_by_centrality();
+
+    let (t_0, t_1) = area.gradient_descent();
+
+    let origin = area.find_t0_origin(t_0, t_1);
+
+    origin.sum()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Vector {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct PointIntersection {
+    point: Vector,
+    t: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Area {
+    min: u64,
+    max: u64,
+    hailstones: Vec<Hailstone>,
+}
+
+impl Area {
+    fn count_2_d_intersections(&self, _input: &str) -> u64 {
+        self.hailstones
+            .iter()
+            .enumerate()
+            .map(|(i, hailstone)| {
+                self.hailstones
+                    .iter()
+                    .skip(i + 1)
+                    .filter(|other| {
+                        if let Some(collision) = hailstone.detect_2_d_collision(other) {
+                            self.contains_2_d(&collision)
+                        } else {
+                            false
+                        }
+                    })
+                    .count() as u64
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Hailstone {
+    position: Vector,
+    velocity: Vector,
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn velocity() -> impl Strategy<Value = i64> {
+        prop_oneof![-6..0i64, 1..7i64]
+    }
+
+    fn hailstones() -> impl Strategy<Value = Vec<[i64; 6]>> {
+        let hailstone = (
+            0..35i64,
+            0..35i64,
+            0..35i64,
+            velocity(),
+            velocity(),
+            velocity(),
+        )
+            .prop_map(|(x, y, z, vx, vy, vz)| [x, y, z, vx, vy, vz]);
+        proptest::collection::vec(hailstone, 2..12)
+    }
+
+    fn write(hailstones: &[[i64; 6]]) -> String {
+        hailstones
+            .iter()
+            .map(|[x, y, z, vx, vy, vz]| format!("{x}, {y}, {z} @ {vx}, {vy}, {vz}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Whether the future paths of `a` and `b` cross within `min..=max` in x and y,
+    /// worked out with exact fractions. Crossings right on the edge of the area or right
+    /// where a hailstone starts are `None`, as the floating point solution may land on
+    /// either side of them.
+    fn cross_within(a: &[i64; 6], b: &[i64; 6], min: i64, max: i64) -> Option<bool> {
+        let cross = |ux: i64, uy: i64, vx: i64, vy: i64| ux * vy - uy * vx;
+        let denominator = cross(a[3], a[4], b[3], b[4]);
+        if denominator == 0 {
+            return Some(false);
+        }
+        let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+        let (t, s) = (cross(dx, dy, b[3], b[4]), cross(dx, dy, a[3], a[4]));
+        let sign = denominator.signum();
+        if t == 0 || s == 0 {
+            return None;
+        }
+        if t * sign < 0 || s * sign < 0 {
+            return Some(false);
+        }
+        // The crossing is at a + t / denominator * velocity of a.
+        let scaled = |position: i64, velocity: i64| (position * denominator + t * velocity) * sign;
+        let (x, y) = (scaled(a[0], a[3]), scaled(a[1], a[4]));
+        let (min, max) = (min * denominator.abs(), max * denominator.abs());
+        if [x, y]
+            .iter()
+            .any(|&coordinate| coordinate == min || coordinate == max)
+        {
+            return None;
+        }
+        Some(
+            [x, y]
+                .iter()
+                .all(|coordinate| (min..=max).contains(coordinate)),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(hailstones in hailstones()) {
+            let input = write(&hailstones);
+            prop_assert_eq!(render(&parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn counts_crossing_paths(hailstones in hailstones()) {
+            let area = Area::from_str(&write(&hailstones), 7, 27).unwrap();
+            let crossings: Option<Vec<bool>> = (0..hailstones.len())
+                .flat_map(|i| (i + 1..hailstones.len()).map(move |j| (i, j)))
+                .map(|(i, j)| cross_within(&hailstones[i], &hailstones[j], 7, 27))
+                .collect();
+            prop_assume!(crossings.is_some());
+            let expected = crossings.unwrap().into_iter().filter(|&within| within).count();
+            prop_assert_eq!(area.count_2_d_intersections(""), expected as u64);
+        }
+    }
+
+    #[test]
+    fn counts_path_intersections() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+        let area = Area::from_str(input, 7, 27).unwrap();
+        let expected = 2;
+
+        assert_eq!(area.count_2_d_intersections(input), expected);
+    }
+
+    #[test]
+    fn needs_two_hailstones() {
+        let error = parse("").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a hailstone, found end of input"
+        );
+        assert_eq!(parse("19, 13, 30 @ -2,  1, -2").unwrap_err().line, 2);
+    }
+
+    //     #[test]
+    //     fn finds_time_0_origin() {
+    //         let input = "19, 13, 30 @ -2,  1, -2
+    // 18, 19, 22 @ -1, -1, -2
+    // 20, 25, 34 @ -2, -2, -4
+    // 12, 31, 28 @ -1, -2, -1
+    // 20, 19, 15 @  1, -5, -3
+    // ";
+    //         let area = Area::from_str(input, 7, 27).unwrap();
+    //         let expected = Vector {
+    //             x: 24.0,
+    //             y: 13.0,
+    //             z: 10.0,
+    //         };
+
+    //         assert_eq!(area.find_time_0_origin(), expected);
+    //     }
+
+    #[test]
+    fn finds_time_0_origin_bisections() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+        let area = Area::from_str(input, 7, 27).unwrap();
+        let expected = Vector {
+            x: 24.0,
+            y: 13.0,
+            z: 10.0,
+        };
+
+        assert_eq!(area.find_time_0_origin_bisections(), expected);
+    }
+}
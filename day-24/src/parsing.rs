@@ -1,45 +1,45 @@
+use aoc_common::{parse_lines, parse_number};
+
 use super::*;
 
 impl Vector {
-    fn from_str(input: &str) -> Result<Self, &str> {
-        let parts: Vec<&str> = input.split(',').collect();
-        if parts.len() != 3 {
-            return Err("Invalid input");
+    /// Reads `input`, a slice of `line`, so errors can point into the whole line.
+    fn from_str(line: &str, input: &str) -> Result<Self, ParseError> {
+        let mut parts = input.split(',');
+        let mut next = |name: &str| -> Result<f64, ParseError> {
+            let part = parts
+                .next()
+                .ok_or_else(|| ParseError::at(line, &input[input.len()..], name))?;
+            parse_number(line, part.trim())
+        };
+        let (x, y, z) = (next("x")?, next("y")?, next("z")?);
+        if let Some(rest) = parts.next() {
+            return Err(ParseError::at(line, rest, "3 coordinates only"));
         }
 
-        let x = parts[0].trim().parse::<f64>().unwrap();
-        let y = parts[1].trim().parse::<f64>().unwrap();
-        let z = parts[2].trim().parse::<f64>().unwrap();
-
         Ok(Vector { x, y, z })
     }
 }
 
 impl Hailstone {
-    fn from_str(input: &str) -> Result<Self, &str> {
-        let parts: Vec<&str> = input.split('@').collect();
-        if parts.len() != 2 {
-            return Err("Invalid input");
-        }
-
-        let position = Vector::from_str(parts[0])?;
-        let velocity = Vector::from_str(parts[1])?;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (position, velocity) = input
+            .split_once('@')
+            .ok_or_else(|| ParseError::end_of(input, "'@'"))?;
 
-        Ok(Hailstone { position, velocity })
+        Ok(Hailstone {
+            position: Vector::from_str(input, position)?,
+            velocity: Vector::from_str(input, velocity)?,
+        })
     }
 }
 
 impl Area {
-    pub fn from_str(input: &str, min: u64, max: u64) -> Result<Self, &str> {
-        let mut hailstones = Vec::new();
-        for line in input.lines() {
-            hailstones.push(Hailstone::from_str(line).unwrap());
-        }
-
+    pub fn from_str(input: &str, min: u64, max: u64) -> Result<Self, ParseError> {
         Ok(Area {
             min,
             max,
-            hailstones,
+            hailstones: parse_lines(input, Hailstone::from_str)?,
         })
     }
 }
@@ -105,4 +105,19 @@ mod tests {
 
         assert_eq!(Area::from_str(input, 7, 27), Ok(expected));
     }
+
+    #[test]
+    fn reports_malformed_hailstones() {
+        let error =
+            Area::from_str("19, 13, 30 @ -2,  1, -2\n18, 19 @ -1, -1, -2", 7, 27).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: expected z, found nothing"
+        );
+
+        let error = Hailstone::from_str("19, 13, 30 @ -2,  one, -2").unwrap_err();
+        assert_eq!(error.column, 19);
+        let error = Hailstone::from_str("19, 13, 30 -2, 1, -2").unwrap_err();
+        assert_eq!(error.found, "end of line");
+    }
 }
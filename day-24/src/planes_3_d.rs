@@ -2,29 +2,6 @@ use super::*;
 
 mod nearest_points;
 
-#[derive(Debug, PartialEq)]
-pub struct Line3D {
-    point: Vector,
-    direction: Vector,
-}
-
-impl Line3D {
-    pub fn contains(&self, point: &Vector) -> bool {
-        let x = (point.x - self.point.x) / self.direction.x;
-        let y = (point.y - self.point.y) / self.direction.y;
-        let z = (point.z - self.point.z) / self.direction.z;
-
-        x == y && y == z
-    }
-
-    pub fn from_vectors(point: &Vector, direction: &Vector) -> Line3D {
-        Line3D {
-            point: point.clone(),
-            direction: direction.clone(),
-        }
-    }
-}
-
 impl PointIntersection {
     pub fn find_t0_origin(&self, other: &PointIntersection) -> Vector {
         let time_unit_vector = Vector {
@@ -47,12 +24,6 @@ impl Vector {
     }
 }
 
-impl Hailstone {
-    fn time_to_reach(&self, point: &Vector) -> f64 {
-        (point.x - self.position.x) / self.velocity.x
-    }
-}
-
 #[derive(Debug, PartialEq)]
 pub struct Plane {
     normal: Vector,
@@ -74,7 +45,7 @@ impl Plane {
 
         if denominator == 0.0 {
             // log line
-            println!("Hailstone is parallel to the plane");
+            aoc_common::diagnostic!("Hailstone is parallel to the plane");
             return None;
         }
 
@@ -136,32 +107,10 @@ impl Plane {
             },
         )
     }
-
-    fn intersection(&self, other: &Plane) -> Option<Line3D> {
-        let direction = Vector {
-            x: self.normal.y * other.normal.z - self.normal.z * other.normal.y,
-            y: self.normal.z * other.normal.x - self.normal.x * other.normal.z,
-            z: self.normal.x * other.normal.y - self.normal.y * other.normal.x,
-        };
-
-        let denominator =
-            direction.x * direction.x + direction.y * direction.y + direction.z * direction.z;
-        if denominator == 0.0 {
-            return None;
-        }
-
-        let point = Vector {
-            x: (self.normal.y * other.distance - self.distance * other.normal.y) / denominator,
-            y: (self.distance * other.normal.x - self.normal.x * other.distance) / denominator,
-            z: (self.normal.x * other.normal.y - self.normal.y * other.normal.x) / denominator,
-        };
-
-        Some(Line3D { point, direction })
-    }
 }
 
 #[derive(Debug, PartialEq)]
-struct ConstrainedHailstone {
+pub(crate) struct ConstrainedHailstone {
     hailstone: Hailstone,
     min_t: f64,
     max_t: f64,
@@ -178,7 +127,7 @@ impl ConstrainedHailstone {
 }
 
 impl Area {
-    pub fn find_time_0_origin(
+    pub(crate) fn find_time_0_origin(
         &self,
         contrains: &ConstrainedHailstone,
         n: usize,
@@ -195,14 +144,14 @@ impl Area {
             .map(|(_, hailstone)| hailstone)
             .collect::<Vec<&Hailstone>>();
 
-        println!("min_t millions: {}", contrains.min_t / 1_000_000.0);
-        println!("max_t millions: {}", contrains.max_t / 1_000_000.0);
+        aoc_common::diagnostic!("min_t millions: {}", contrains.min_t / 1_000_000.0);
+        aoc_common::diagnostic!("max_t millions: {}", contrains.max_t / 1_000_000.0);
         let diff = contrains.max_t - contrains.min_t;
-        println!("diff millions: {}", diff / 1_000_000.0);
+        aoc_common::diagnostic!("diff millions: {}", diff / 1_000_000.0);
         let mut t = contrains.max_t.floor() + 1.0;
         loop {
             if (contrains.max_t.floor() - t) % 1_000.0 == 0.0 {
-                println!("t: {}", t);
+                aoc_common::diagnostic!("t: {}", t);
             }
             let hailstone_2_position_t = Vector {
                 x: hailstone_2.position.x + t * hailstone_2.velocity.x,
@@ -221,7 +170,6 @@ impl Area {
             );
             let mut is_line_found = true;
 
-            let mut line: Option<Line3D> = None;
             let mut found_intersection: Option<PointIntersection> = None;
             for hailstone in &subset_of_hailstones {
                 if let Some(intersection) = plane.intersect_with_hailstone(hailstone, 1.0) {
@@ -230,7 +178,7 @@ impl Area {
                         break;
                     }
 
-                    println!("intersection: {:?}", intersection.t);
+                    aoc_common::diagnostic!("intersection: {:?}", intersection.t);
                     found_intersection = Some(intersection);
                 }
             }
@@ -266,24 +214,21 @@ impl Area {
             .collect::<Vec<&Hailstone>>();
 
         for current_hailstone in subset_of_hailstones {
-            let times = vec![0.0, self.max as f64]
+            let times = [0.0, self.max as f64]
                 .iter()
                 .map(|t| {
                     let plane = Plane::from_hailstones_at_time(hailstone_m, current_hailstone, *t);
                     if let Some(intersection) =
                         plane.intersect_with_hailstone(&constrained_hailstone_n.hailstone, 1.0)
                     {
-                        // let t = constrained_hailstone_n
-                        //     .hailstone
-                        //     .time_to_reach(&intersection.point);
-                        return Some(intersection.t);
+                        Some(intersection.t)
                     } else {
-                        return None;
+                        None
                     }
                 })
                 .collect::<Vec<Option<f64>>>();
 
-            if let Some(t_a) = times.get(0).unwrap() {
+            if let Some(t_a) = times.first().unwrap() {
                 if let Some(t_b) = times.get(1).unwrap() {
                     // if t_a > t_b
                     //     && t_a < &constrained_hailstone_n.max_t
@@ -325,7 +270,6 @@ impl Area {
                 let constrained_hailstone = self.find_constrains_of_nth_hailstone_based_on_m(n, m);
 
                 if constrained_hailstone.min_t == constrained_hailstone.max_t {
-                    min_diff = 0.0;
                     result.push((constrained_hailstone, n, m));
                     break 'outer;
                 }
@@ -336,7 +280,7 @@ impl Area {
                     result.push((constrained_hailstone, n, m));
                 }
             }
-            println!("n: {}", n);
+            aoc_common::diagnostic!("n: {}", n);
         }
 
         let (contrains, n, m) = result.last().unwrap();
@@ -347,8 +291,6 @@ impl Area {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::EPSILON;
-
     use super::*;
 
     #[test]
@@ -407,68 +349,4 @@ mod tests {
             None
         );
     }
-
-    #[test]
-    fn validates_point_on_a_line() {
-        let line = Line3D {
-            point: Vector {
-                x: 0.0,
-                y: 0.0,
-                z: 0.0,
-            },
-            direction: Vector {
-                x: 1.0,
-                y: 2.0,
-                z: 3.0,
-            },
-        };
-
-        let point_on_line = Vector {
-            x: 1.0,
-            y: 2.0,
-            z: 3.0,
-        };
-
-        let point_on_line_past = Vector {
-            x: -1.0,
-            y: -2.0,
-            z: -3.0,
-        };
-
-        let point_off_line = Vector {
-            x: 2.0,
-            y: 4.0,
-            z: EPSILON,
-        };
-
-        assert_eq!(line.contains(&point_on_line), true);
-        assert_eq!(line.contains(&point_on_line_past), true);
-        assert_eq!(line.contains(&point_off_line), false);
-    }
-
-    #[test]
-    fn computes_time_at_which_hailstone_reaches_a_point() {
-        let hailstone = Hailstone {
-            position: Vector {
-                x: -1.0,
-                y: 0.0,
-                z: 0.0,
-            },
-            velocity: Vector {
-                x: 1.0,
-                y: 0.0,
-                z: 0.0,
-            },
-        };
-
-        let point = Vector {
-            x: 1.0,
-            y: 0.0,
-            z: 0.0,
-        };
-
-        let expected_time = 2.0;
-
-        assert_eq!(hailstone.time_to_reach(&point), expected_time);
-    }
 }
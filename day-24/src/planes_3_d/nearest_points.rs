@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::{fs, io::Write};
 
 use super::*;
 
@@ -34,7 +34,7 @@ impl Area {
     fn find_average_minimum_distance(&self, line: &Hailstone) -> u64 {
         let mut total = 0;
         for hailstone in &self.hailstones {
-            if let Some(distance) = hailstone.line_min_distance(&line) {
+            if let Some(distance) = hailstone.line_min_distance(line) {
                 total += distance as u64;
             };
         }
@@ -43,7 +43,6 @@ impl Area {
 
     pub fn gradient_descent(&self) -> (u64, u64) {
         let mut iterations = 0;
-        let mut previous_average = u64::MAX;
         let mut min_average = u64::MAX;
 
         let mut x = 10;
@@ -67,13 +66,11 @@ impl Area {
 
             let average = self.find_average_minimum_distance(&self.gradient_function(x, y));
 
-            previous_average = average;
-
             if average == 0 {
                 break;
             }
 
-            if (average < min_average) {
+            if average < min_average {
                 min_average = average;
             }
 
@@ -84,7 +81,7 @@ impl Area {
             iterations += 1;
         }
 
-        return (x, y);
+        (x, y)
     }
 
     pub fn sample(&self) {
@@ -97,7 +94,7 @@ impl Area {
 
         let increment = 1;
 
-        println!("Increment: {}", increment);
+        aoc_common::diagnostic!("Increment: {}", increment);
 
         fs::remove_file(path).unwrap_or_default();
         let mut file_buffer = fs::File::create(path).unwrap();
@@ -130,11 +127,10 @@ impl Area {
             .position
             .add(&self.hailstones[1].velocity.mul(y as f64));
 
-        let stone = Hailstone {
+        Hailstone {
             position: position_at_t_0.clone(),
             velocity: position_at_t_1.sub(&position_at_t_0),
-        };
-        stone
+        }
     }
 
     pub fn find_t0_origin(&self, t_0: u64, t_1: u64) -> Vector {
@@ -162,9 +158,9 @@ impl Area {
 
 fn guarantee_step_size(learning_rate: f64, grad: f64) -> f64 {
     if grad < 0.0 {
-        return (learning_rate * grad as f64).min(-1.0);
+        (learning_rate * grad).min(-1.0)
     } else {
-        return (learning_rate * grad as f64).max(1.0);
+        (learning_rate * grad).max(1.0)
     }
 }
 
@@ -177,7 +173,7 @@ impl Hailstone {
             return None;
         };
 
-        return Some(cross.dot(&self.position.sub(&line.position)).abs() / cross.magnitude());
+        Some(cross.dot(&self.position.sub(&line.position)).abs() / cross.magnitude())
     }
 }
 
@@ -1,124 +1,123 @@
-use super::{Edge, Graph, Node};
+use std::collections::BTreeMap;
+
+use aoc_common::{parse_lines, ParseError};
+
+use super::Graph;
 
 impl Graph {
-    pub fn from_input(input: &str) -> Self {
-        let mut graph = Graph {
-            edges: Default::default(),
-            nodes: Default::default(),
-            memory: Default::default(),
-        };
-
-        for line in input.lines() {
-            let mut parts = line.split(": ");
-            let node = parts.next().unwrap().trim();
-            let neighbors = parts.next().unwrap_or_default();
-
-            for neighbor in neighbors.split(' ').map(str::trim) {
-                let mut nodes = vec![node.to_string(), neighbor.to_string()];
-                nodes.sort();
-
-                let edge = Edge {
-                    nodes,
-                    weight: 1,
-                    connectivity_score: None,
-                };
-
-                if !graph.edges.contains(&edge) {
-                    graph.edges.insert(edge);
-                };
-
-                let node = Node {
-                    id: neighbor.to_string(),
-                    contains: vec![],
-                };
-
-                if !graph.nodes.contains(&node) {
-                    graph.nodes.insert(node);
-                };
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let mut wires = aoc_graph::Graph::undirected();
+
+        for (node, neighbors) in parse_lines(input, parse_line)? {
+            let node = wires.intern(node.to_string());
+            for neighbor in neighbors {
+                // Wires go both ways, so one listed twice is still the same wire
+                let neighbor = wires.intern(neighbor.to_string());
+                if !wires.has_edge(node, neighbor) {
+                    wires.connect(node, neighbor, ());
+                }
             }
+        }
 
-            let node = Node {
-                id: node.to_string(),
-                contains: vec![],
-            };
+        Ok(Graph { wires })
+    }
+}
 
-            if !graph.nodes.contains(&node) {
-                graph.nodes.insert(node);
-            };
+impl Graph {
+    /// Writes every wire once, listed under the first of its components by name.
+    pub fn to_input(&self) -> String {
+        let mut wires: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (from, to, _) in self.wires.edge_list() {
+            let mut ends = [self.wires.key(from).as_str(), self.wires.key(to).as_str()];
+            ends.sort();
+            wires.entry(ends[0]).or_default().push(ends[1]);
         }
+        wires
+            .into_iter()
+            .map(|(node, mut neighbors)| {
+                neighbors.sort();
+                format!("{node}: {}", neighbors.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
 
-        graph
+/// Reads a line like `jqt: rhn xhk nvd`.
+fn parse_line(line: &str) -> Result<(&str, Vec<&str>), ParseError> {
+    let (node, neighbors) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::end_of(line, "':'"))?;
+    let node = node.trim();
+    if node.is_empty() {
+        return Err(ParseError::at(line, node, "a component name"));
     }
+    let neighbors = neighbors.split_whitespace().collect::<Vec<_>>();
+    if neighbors.is_empty() {
+        return Err(ParseError::end_of(line, "a connected component"));
+    }
+    Ok((node, neighbors))
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
+    use proptest::prelude::*;
+
     use super::*;
 
+    fn wiring_diagram() -> impl Strategy<Value = String> {
+        let line = ("[a-z]{3}", proptest::collection::vec("[a-z]{3}", 1..5))
+            .prop_map(|(node, neighbors)| format!("{node}: {}", neighbors.join(" ")));
+        proptest::collection::vec(line, 1..20).prop_map(|lines| lines.join("\n"))
+    }
+
+    /// Every wire as its two components in name order.
+    fn wires(graph: &Graph) -> HashSet<[&str; 2]> {
+        graph
+            .wires
+            .edge_list()
+            .map(|(from, to, _)| {
+                let mut ends = [graph.wires.key(from).as_str(), graph.wires.key(to).as_str()];
+                ends.sort();
+                ends
+            })
+            .collect()
+    }
+
+    fn nodes(graph: &Graph) -> HashSet<&str> {
+        graph.wires.nodes().map(|(_, node)| node.as_str()).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(input in wiring_diagram()) {
+            let graph = Graph::from_input(&input).unwrap();
+            let written = Graph::from_input(&graph.to_input()).unwrap();
+            prop_assert_eq!(wires(&written), wires(&graph));
+            prop_assert_eq!(nodes(&written), nodes(&graph));
+        }
+    }
+
     #[test]
     fn parses_input_with_duplicate_edges() {
         // This input has particular two nodes, jqt and xhk, with an edge between them.
-        // The edge is bidirectional, so there should be only one entry in the edges set.
+        // The edge is bidirectional, so there should be only one wire between them.
         let input = "jqt: rhn xhk nvd
         xhk: jqt kqr\n";
-        let graph = Graph::from_input(input);
-
-        let expected_edges: HashSet<Edge> = [
-            Edge {
-                nodes: vec!["jqt".to_string(), "rhn".to_string()],
-                weight: 1,
-                connectivity_score: None,
-            },
-            Edge {
-                nodes: vec!["jqt".to_string(), "xhk".to_string()],
-                weight: 1,
-                connectivity_score: None,
-            },
-            Edge {
-                nodes: vec!["jqt".to_string(), "nvd".to_string()],
-                weight: 1,
-                connectivity_score: None,
-            },
-            Edge {
-                nodes: vec!["kqr".to_string(), "xhk".to_string()],
-                weight: 1,
-                connectivity_score: None,
-            },
-        ]
-        .iter()
-        .cloned()
-        .collect();
-
-        let expected_nodes: HashSet<Node> = [
-            Node {
-                id: "jqt".to_string(),
-                contains: vec![],
-            },
-            Node {
-                id: "xhk".to_string(),
-                contains: vec![],
-            },
-            Node {
-                id: "kqr".to_string(),
-                contains: vec![],
-            },
-            Node {
-                id: "nvd".to_string(),
-                contains: vec![],
-            },
-            Node {
-                id: "rhn".to_string(),
-                contains: vec![],
-            },
-        ]
-        .iter()
-        .cloned()
-        .collect();
-
-        assert_eq!(graph.edges, expected_edges);
-        assert_eq!(graph.nodes, expected_nodes);
+        let graph = Graph::from_input(input).unwrap();
+
+        let expected_wires = HashSet::from([
+            ["jqt", "rhn"],
+            ["jqt", "xhk"],
+            ["jqt", "nvd"],
+            ["kqr", "xhk"],
+        ]);
+        let expected_nodes = HashSet::from(["jqt", "xhk", "kqr", "nvd", "rhn"]);
+
+        assert_eq!(wires(&graph), expected_wires);
+        assert_eq!(nodes(&graph), expected_nodes);
     }
 
     #[test]
@@ -136,8 +135,19 @@ mod tests {
         lsr: lhk
         rzs: qnr cmg lsr rsh
         frs: qnr lhk lsr\n";
-        let graph = Graph::from_input(input);
-        assert_eq!(graph.edges.len(), 33);
-        assert_eq!(graph.nodes.len(), 15);
+        let graph = Graph::from_input(input).unwrap();
+        assert_eq!(graph.wires.edge_count(), 33);
+        assert_eq!(graph.wires.len(), 15);
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        let error = Graph::from_input("jqt: rhn xhk\nrsh frs").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: expected ':', found end of line"
+        );
+        let error = Graph::from_input("jqt: rhn xhk\n: frs").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
     }
 }
@@ -0,0 +1,75 @@
+use aoc_common::{Answer, ParseError, Solver};
+
+mod graph;
+pub use graph::Graph;
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    const DAY: u8 = 25;
+    type Parsed<'a> = Graph;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(part1(parsed).map(Answer::from))
+    }
+
+    /// Day 25 has only one puzzle, the second star is given for the other 49.
+    fn part_two(_parsed: &Self::Parsed<'_>) -> Result<Option<Answer>, ParseError> {
+        Ok(None)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    Graph::from_input(input)
+}
+
+pub fn render(graph: &Graph) -> String {
+    graph.to_input()
+}
+
+/// Product of the two group sizes after cutting three wires, `None` when no such cut is found.
+pub fn part1(graph: &Graph) -> Option<usize> {
+    graph.find_cut_product()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Two groups where every component is wired to every other, joined by three wires.
+    fn two_groups() -> impl Strategy<Value = (String, usize, usize)> {
+        (5..9usize, 5..9usize).prop_flat_map(|(left, right)| {
+            let bridges = proptest::sample::subsequence((0..left * right).collect::<Vec<_>>(), 3);
+            bridges.prop_map(move |bridges| {
+                let mut lines = Vec::new();
+                for (group, size) in [('l', left), ('r', right)] {
+                    for node in 0..size {
+                        let neighbors: Vec<String> = (node + 1..size)
+                            .map(|other| format!("{group}{other:02}"))
+                            .collect();
+                        if !neighbors.is_empty() {
+                            lines.push(format!("{group}{node:02}: {}", neighbors.join(" ")));
+                        }
+                    }
+                }
+                for bridge in &bridges {
+                    lines.push(format!("l{:02}: r{:02}", bridge / right, bridge % right));
+                }
+                (lines.join("\n"), left, right)
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn cuts_between_groups((input, left, right) in two_groups()) {
+            prop_assert_eq!(part1(&parse(&input).unwrap()), Some(left * right));
+        }
+    }
+}
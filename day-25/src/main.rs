@@ -1,14 +1,14 @@
-mod graph;
+use aoc_common::ParseError;
+use day_25::{parse, part1};
 
-fn main() {
+fn main() -> Result<(), ParseError> {
     let input = std::fs::read_to_string("input.txt").unwrap();
-    let graph = graph::Graph::from_input(&input);
 
     // Right answer for first part with my data: 603368
-    let mut g = graph.clone();
-    if let Some(result) = g.find_cut_product() {
+    if let Some(result) = part1(&parse(&input)?) {
         println!("{}", result);
     } else {
-        println!("Unable to finish {:#?}", g);
+        println!("Unable to finish");
     }
+    Ok(())
 }
@@ -0,0 +1,530 @@
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, VecDeque},
+    fmt::{self, Write},
+    hash::Hash,
+};
+
+/// Index of a node, handed out in the order the nodes were first seen.
+pub type NodeId = usize;
+
+/// Nodes keyed by `K`, interned to dense ids, with edges weighted by `E` kept as adjacency lists.
+///
+/// Edges of an undirected graph show up in the adjacency lists of both their ends.
+#[derive(Debug, Clone)]
+pub struct Graph<K, E = ()> {
+    directed: bool,
+    keys: Vec<K>,
+    ids: HashMap<K, NodeId>,
+    outgoing: Vec<Vec<(NodeId, E)>>,
+    incoming: Vec<Vec<NodeId>>,
+    edge_count: usize,
+}
+
+/// The fewest edges whose removal splits a graph, and the nodes on the side of node 0.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MinCut {
+    pub size: usize,
+    pub side: Vec<NodeId>,
+}
+
+impl<K, E> Graph<K, E> {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            keys: Vec::new(),
+            ids: HashMap::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+            edge_count: 0,
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    pub fn key(&self, id: NodeId) -> &K {
+        &self.keys[id]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &K)> {
+        self.keys.iter().enumerate()
+    }
+
+    /// Edges leaving `id` with their weights, in the order they were added.
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, E)] {
+        &self.outgoing[id]
+    }
+
+    pub fn neighbors(&self, id: NodeId) -> impl DoubleEndedIterator<Item = NodeId> + '_ {
+        self.outgoing[id].iter().map(|(to, _)| *to)
+    }
+
+    /// Nodes with an edge to `id`, the same as the neighbors when undirected.
+    pub fn predecessors(&self, id: NodeId) -> &[NodeId] {
+        &self.incoming[id]
+    }
+
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.neighbors(from).any(|neighbor| neighbor == to)
+    }
+
+    /// Every edge once as `(from, to, weight)`, undirected ones from their smaller end.
+    pub fn edge_list(&self) -> impl Iterator<Item = (NodeId, NodeId, &E)> {
+        self.outgoing
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, edges)| {
+                edges
+                    .iter()
+                    .filter(move |(to, _)| self.directed || from <= *to)
+                    .map(move |(to, weight)| (from, *to, weight))
+            })
+    }
+
+    /// Distances in edges from `start`, `None` for the nodes it can't reach.
+    pub fn bfs(&self, start: NodeId) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        distances[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(id) = queue.pop_front() {
+            let distance = distances[id].map(|distance| distance + 1);
+            for neighbor in self.neighbors(id) {
+                if distances[neighbor].is_none() {
+                    distances[neighbor] = distance;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Nodes reachable from `start` in depth first preorder, taking edges in the order they were added.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            if visited[id] {
+                continue;
+            }
+            visited[id] = true;
+            order.push(id);
+            stack.extend(self.neighbors(id).filter(|&id| !visited[id]).rev());
+        }
+        order
+    }
+
+    /// Orders the nodes so every edge points forward, `None` when there is a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<NodeId>> {
+        let mut incoming: Vec<usize> = self.incoming.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<NodeId> = (0..self.len()).filter(|&id| incoming[id] == 0).collect();
+        let mut sorted = Vec::with_capacity(self.len());
+        while let Some(id) = queue.pop_front() {
+            sorted.push(id);
+            for neighbor in self.neighbors(id) {
+                incoming[neighbor] -= 1;
+                if incoming[neighbor] == 0 {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        (sorted.len() == self.len()).then_some(sorted)
+    }
+
+    /// Groups of nodes that can all reach each other, the connected components when undirected.
+    ///
+    /// Components come out so that edges between them only point to later ones.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        // Kosaraju: finish order along the edges, then collect against them.
+        let mut visited = vec![false; self.len()];
+        let mut finished = Vec::with_capacity(self.len());
+        for root in 0..self.len() {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            let mut stack = vec![(root, 0)];
+            while let Some((id, next)) = stack.last_mut() {
+                let id = *id;
+                match self.outgoing[id].get(*next) {
+                    Some(&(neighbor, _)) => {
+                        *next += 1;
+                        if !visited[neighbor] {
+                            visited[neighbor] = true;
+                            stack.push((neighbor, 0));
+                        }
+                    }
+                    None => {
+                        finished.push(id);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        let mut assigned = vec![false; self.len()];
+        let mut components = Vec::new();
+        for &root in finished.iter().rev() {
+            if assigned[root] {
+                continue;
+            }
+            let mut component = vec![root];
+            assigned[root] = true;
+            let mut stack = vec![root];
+            while let Some(id) = stack.pop() {
+                for &predecessor in &self.incoming[id] {
+                    if !assigned[predecessor] {
+                        assigned[predecessor] = true;
+                        component.push(predecessor);
+                        stack.push(predecessor);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// Smallest set of edges splitting the graph in two, every edge counting once regardless of
+    /// weight or direction. `None` with fewer than two nodes.
+    ///
+    /// Runs a unit capacity max flow from node 0 to every other node, each one stopping as soon
+    /// as it can't beat the best cut so far.
+    pub fn min_cut(&self) -> Option<MinCut> {
+        if self.len() < 2 {
+            return None;
+        }
+        // Arcs come in pairs, the reverse of arc `i` being `i ^ 1`.
+        let mut heads = Vec::new();
+        let mut arcs: Vec<Vec<usize>> = vec![Vec::new(); self.len()];
+        for (from, to, _) in self.edge_list() {
+            if from != to {
+                arcs[from].push(heads.len());
+                heads.push(to);
+                arcs[to].push(heads.len());
+                heads.push(from);
+            }
+        }
+
+        let mut best: Option<MinCut> = None;
+        for sink in 1..self.len() {
+            let mut capacity = vec![1; heads.len()];
+            let mut flow = 0;
+            let side = loop {
+                if best.as_ref().is_some_and(|best| flow >= best.size) {
+                    break None;
+                }
+                let mut through = vec![None; self.len()];
+                let mut reached = vec![false; self.len()];
+                reached[0] = true;
+                let mut queue = VecDeque::from([0]);
+                while let Some(id) = queue.pop_front() {
+                    for &arc in &arcs[id] {
+                        let head = heads[arc];
+                        if capacity[arc] > 0 && !reached[head] {
+                            reached[head] = true;
+                            through[head] = Some(arc);
+                            queue.push_back(head);
+                        }
+                    }
+                }
+                if !reached[sink] {
+                    break Some(reached);
+                }
+                let mut id = sink;
+                while let Some(arc) = through[id] {
+                    capacity[arc] -= 1;
+                    capacity[arc ^ 1] += 1;
+                    id = heads[arc ^ 1];
+                }
+                flow += 1;
+            };
+            if let Some(reached) = side {
+                best = Some(MinCut {
+                    size: flow,
+                    side: (0..self.len()).filter(|&id| reached[id]).collect(),
+                });
+            }
+        }
+        best
+    }
+}
+
+impl<K: Clone + Eq + Hash, E> Graph<K, E> {
+    /// Id of `key`, adding it as a node without edges when it's new.
+    pub fn intern(&mut self, key: K) -> NodeId {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        id
+    }
+
+    pub fn id<Q>(&self, key: &Q) -> Option<NodeId>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(key).copied()
+    }
+}
+
+impl<K: Clone + Eq + Hash, E: Clone> Graph<K, E> {
+    /// Adds an edge between the nodes of two keys, interning them as needed.
+    pub fn add_edge(&mut self, from: K, to: K, weight: E) -> (NodeId, NodeId) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.connect(from, to, weight);
+        (from, to)
+    }
+
+    /// Adds an edge between two nodes that are already there.
+    pub fn connect(&mut self, from: NodeId, to: NodeId, weight: E) {
+        self.edge_count += 1;
+        self.incoming[to].push(from);
+        if !self.directed && from != to {
+            self.outgoing[to].push((from, weight.clone()));
+            self.incoming[from].push(to);
+        }
+        self.outgoing[from].push((to, weight));
+    }
+}
+
+impl<K: Eq + Hash, E: PartialEq> PartialEq for Graph<K, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.directed == other.directed
+            && self.keys == other.keys
+            && self.outgoing == other.outgoing
+    }
+}
+
+impl<K: fmt::Display, E> Graph<K, E> {
+    /// Writes the graph in Graphviz DOT, labeling the edges `label` gives a text for.
+    pub fn to_dot(&self, label: impl Fn(&E) -> Option<String>) -> String {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let quoted = |id: NodeId| {
+            let key = self.keys[id].to_string();
+            format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+        };
+
+        let mut dot = format!("{kind} {{\n");
+        for id in 0..self.len() {
+            writeln!(dot, "    {};", quoted(id)).unwrap();
+        }
+        for (from, to, weight) in self.edge_list() {
+            write!(dot, "    {} {arrow} {}", quoted(from), quoted(to)).unwrap();
+            if let Some(label) = label(weight) {
+                let label = label.replace('\\', "\\\\").replace('"', "\\\"");
+                write!(dot, " [label=\"{label}\"]").unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        dot.push('}');
+        dot
+    }
+
+    /// Writes the graph in GraphML, with `label` giving the text of an edge's `label` data.
+    pub fn to_graphml(&self, label: impl Fn(&E) -> Option<String>) -> String {
+        let escaped = |text: &str| {
+            text.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+        let node = |id: NodeId| escaped(&self.keys[id].to_string());
+        let direction = if self.directed {
+            "directed"
+        } else {
+            "undirected"
+        };
+
+        let mut graphml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"label\" for=\"edge\" attr.name=\"label\" attr.type=\"string\"/>\n",
+        ));
+        writeln!(graphml, "  <graph id=\"G\" edgedefault=\"{direction}\">").unwrap();
+        for id in 0..self.len() {
+            writeln!(graphml, "    <node id=\"{}\"/>", node(id)).unwrap();
+        }
+        for (from, to, weight) in self.edge_list() {
+            let edge = format!("<edge source=\"{}\" target=\"{}\"", node(from), node(to));
+            match label(weight) {
+                Some(label) => writeln!(
+                    graphml,
+                    "    {edge}><data key=\"label\">{}</data></edge>",
+                    escaped(&label)
+                ),
+                None => writeln!(graphml, "    {edge}/>"),
+            }
+            .unwrap();
+        }
+        graphml.push_str("  </graph>\n</graphml>");
+        graphml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::directed();
+        for &(from, to) in edges {
+            graph.add_edge(from, to, ());
+        }
+        graph
+    }
+
+    fn keys<'a>(graph: &'a Graph<&str>, ids: &[NodeId]) -> Vec<&'a str> {
+        ids.iter().map(|&id| *graph.key(id)).collect()
+    }
+
+    #[test]
+    fn interns_keys_in_order() {
+        let mut graph: Graph<String, u8> = Graph::undirected();
+        assert_eq!(graph.add_edge("a".into(), "b".into(), 3), (0, 1));
+        assert_eq!(graph.add_edge("c".into(), "a".into(), 5), (2, 0));
+        assert_eq!(graph.intern("b".to_string()), 1);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.id("d"), None);
+
+        assert_eq!((graph.len(), graph.edge_count()), (3, 2));
+        assert_eq!(graph.edges(0), &[(1, 3), (2, 5)]);
+        assert_eq!(graph.predecessors(0), &[1, 2]);
+        assert!(graph.has_edge(1, 0));
+        let edges: Vec<_> = graph.edge_list().collect();
+        assert_eq!(edges, vec![(0, 1, &3), (0, 2, &5)]);
+    }
+
+    #[test]
+    fn searches_from_a_node() {
+        let graph = directed(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("e", "a")]);
+        assert_eq!(graph.bfs(0), vec![Some(0), Some(1), Some(1), Some(2), None]);
+        assert_eq!(keys(&graph, &graph.dfs(0)), vec!["a", "b", "d", "c"]);
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let graph = directed(&[("a", "b"), ("c", "a"), ("b", "d"), ("c", "d")]);
+        let sorted = graph.topological_sort().unwrap();
+        assert_eq!(keys(&graph, &sorted), vec!["c", "a", "b", "d"]);
+
+        let graph = directed(&[("a", "b"), ("b", "c"), ("c", "a")]);
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn finds_strongly_connected_components() {
+        let graph = directed(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "f"),
+        ]);
+        let components: Vec<_> = graph
+            .strongly_connected_components()
+            .iter()
+            .map(|component| keys(&graph, component))
+            .collect();
+        assert_eq!(
+            components,
+            vec![vec!["f"], vec!["a", "b", "c"], vec!["d", "e"]]
+        );
+    }
+
+    #[test]
+    fn finds_min_cut() {
+        let mut graph = Graph::undirected();
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+            graph.add_edge(from, to, ());
+            graph.add_edge(from, to, ());
+        }
+        graph.add_edge(5, 1, ());
+        assert_eq!(
+            graph.min_cut(),
+            Some(MinCut {
+                size: 1,
+                side: vec![0, 1, 2]
+            })
+        );
+
+        graph.intern(6);
+        assert_eq!(graph.min_cut().unwrap().size, 0);
+        assert_eq!(Graph::<u8>::undirected().min_cut(), None);
+    }
+
+    #[test]
+    fn exports_dot() {
+        let mut graph = Graph::directed();
+        graph.add_edge("a", "b\"", 4);
+        graph.add_edge("b\"", "c", 0);
+        let label = |length: &u8| (*length > 0).then(|| length.to_string());
+        assert_eq!(
+            graph.to_dot(label),
+            "digraph {\n    \"a\";\n    \"b\\\"\";\n    \"c\";\n    \"a\" -> \"b\\\"\" [label=\"4\"];\n    \"b\\\"\" -> \"c\";\n}"
+        );
+
+        let mut graph = Graph::undirected();
+        graph.add_edge(1, 2, ());
+        assert_eq!(
+            graph.to_dot(|_| None),
+            "graph {\n    \"1\";\n    \"2\";\n    \"1\" -- \"2\";\n}"
+        );
+    }
+
+    #[test]
+    fn exports_graphml() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("a&b", "c", 7);
+        graph.add_edge("c", "d", 0);
+        assert_eq!(
+            graph.to_graphml(|length| (*length > 0).then(|| length.to_string())),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="label" for="edge" attr.name="label" attr.type="string"/>
+  <graph id="G" edgedefault="undirected">
+    <node id="a&amp;b"/>
+    <node id="c"/>
+    <node id="d"/>
+    <edge source="a&amp;b" target="c"><data key="label">7</data></edge>
+    <edge source="c" target="d"/>
+  </graph>
+</graphml>"#
+        );
+    }
+}
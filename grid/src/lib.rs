@@ -0,0 +1,405 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use aoc_common::ParseError;
+
+/// Offsets to the four orthogonal neighbors as `(x, y)`: up, right, down and left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight neighbors as `(x, y)`, clockwise starting from up.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Moves `index` by `offset`, `None` when it would leave `0..len`.
+pub fn offset(index: usize, offset: isize, len: usize) -> Option<usize> {
+    match index.checked_add_signed(offset) {
+        Some(new_index) if new_index < len => Some(new_index),
+        _ => None,
+    }
+}
+
+/// Moves `index` by `offset`, wrapping around to the other side of `0..len`.
+pub fn offset_wrapping(index: usize, offset: isize, len: usize) -> usize {
+    (index as isize + offset).rem_euclid(len as isize) as usize
+}
+
+/// Rectangular grid of cells, addressed by `(x, y)` with `y` growing downwards.
+///
+/// Indexing with a single `y` gives the whole row, so `grid[y][x]` works as with `Vec<Vec<T>>`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Reads every line of the puzzle as one row, turning each char into a cell.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut cell).collect())
+                .collect(),
+        )
+    }
+
+    /// Like `parse`, but a char without a cell or a row of a different length is
+    /// reported instead of panicking.
+    pub fn try_parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, ch)| {
+                    cell(ch).ok_or_else(|| {
+                        ParseError::new(x + 1, expected, format!("'{ch}'")).on_line(y + 1)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(
+                        first.len().min(row.len()) + 1,
+                        format!("a row of {} cells", first.len()),
+                        format!("{} cells", row.len()),
+                    )
+                    .on_line(y + 1));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All rows of a grid need to be equally long"
+        );
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// All cells together with their `(x, y)`, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        Some((offset(x, dx, self.width)?, offset(y, dy, self.height)?))
+    }
+
+    pub fn step_wrapping(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> (usize, usize) {
+        (
+            offset_wrapping(x, dx, self.width),
+            offset_wrapping(y, dy, self.height),
+        )
+    }
+
+    /// Maps a position on the infinitely repeated grid back into this one.
+    pub fn wrap(&self, x: isize, y: isize) -> (usize, usize) {
+        (
+            x.rem_euclid(self.width as isize) as usize,
+            y.rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    /// Orthogonal neighbors that are still inside of the grid.
+    pub fn neighbors(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |direction| self.step(position, *direction))
+    }
+
+    /// Orthogonal and diagonal neighbors that are still inside of the grid.
+    pub fn neighbors_diagonal(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_AROUND
+            .iter()
+            .filter_map(move |direction| self.step(position, *direction))
+    }
+
+    /// Orthogonal neighbors, the edges continue on the opposite side.
+    pub fn neighbors_wrapping(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .map(move |direction| self.step_wrapping(position, *direction))
+    }
+
+    /// Orthogonal and diagonal neighbors, the edges continue on the opposite side.
+    pub fn neighbors_diagonal_wrapping(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_AROUND
+            .iter()
+            .map(move |direction| self.step_wrapping(position, *direction))
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Columns become rows, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Self {
+        self.rearrange(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rearrange(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.rearrange(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Builds a grid where the cell at `(x, y)` comes from `source(x, y)` of this one.
+    fn rearrange(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get((x, y))
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut((x, y))
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside of the grid"))
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        Self::from_rows(rows)
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<Vec<T>>> for Grid<T> {
+    fn eq(&self, other: &Vec<Vec<T>>) -> bool {
+        self.height == other.len() && self.rows().zip(other).all(|(row, other)| row == other)
+    }
+}
+
+/// Renders the grid back the way it looks in the puzzle, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#..
+.#.
+..O
+...";
+
+    #[test]
+    fn offsets_within_bounds() {
+        assert_eq!(offset(0, -1, 1), None);
+        assert_eq!(offset(1, 1, 1), None);
+        assert_eq!(offset(0, 1, 2), Some(1));
+        assert_eq!(offset_wrapping(0, -1, 3), 2);
+        assert_eq!(offset_wrapping(2, 1, 3), 0);
+    }
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = Grid::parse(INPUT, |ch| ch);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid[(2, 2)], 'O');
+        assert_eq!(grid[1], ['.', '#', '.']);
+        assert_eq!(grid.column(0).collect::<String>(), "#...");
+        assert_eq!(grid.position(|ch| *ch == 'O'), Some((2, 2)));
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn reports_malformed_input() {
+        let cell = |ch| match ch {
+            '.' | '#' => Some(ch),
+            _ => None,
+        };
+        assert_eq!(
+            Grid::try_parse("#.\n.#", "'.' or '#'", cell).unwrap()[(1, 1)],
+            '#'
+        );
+
+        let error = Grid::try_parse("#.\n.x", "'.' or '#'", cell).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected '.' or '#', found 'x'"
+        );
+
+        let error = Grid::try_parse("#.\n.", "'.' or '#'", cell).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        let grid = Grid::parse(INPUT, |ch| ch);
+        assert_eq!(
+            grid.neighbors((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors_diagonal((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors_diagonal((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors_wrapping((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 3), (1, 0), (0, 1), (2, 0)]
+        );
+        assert_eq!(grid.neighbors_diagonal_wrapping((0, 0)).count(), 8);
+        assert_eq!(grid.wrap(-1, 4), (2, 0));
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = Grid::parse("ab\ncd\nef", |ch| ch);
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "bdf\nace");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn compares_with_rows() {
+        let grid = Grid::from(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(grid, vec![vec![1, 2], vec![3, 4]]);
+        assert_ne!(grid, vec![vec![1, 2]]);
+    }
+}